@@ -58,3 +58,30 @@ pub enum Vertical {
     /// Align bottom
     Bottom,
 }
+
+/// The distribution of the free space on the main axis of a line of
+/// elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Justify {
+    /// Pack the elements at the start of the line.
+    #[default]
+    Start,
+
+    /// Pack the elements at the center of the line.
+    Center,
+
+    /// Pack the elements at the end of the line.
+    End,
+
+    /// Distribute the free space between the elements, placing the first
+    /// and last elements at the edges of the line.
+    SpaceBetween,
+
+    /// Distribute the free space around the elements, giving each of them
+    /// the same amount on both sides.
+    SpaceAround,
+
+    /// Distribute the free space evenly before, between, and after the
+    /// elements.
+    SpaceEvenly,
+}
@@ -0,0 +1,759 @@
+//! Animate values over time with easing curves or spring physics.
+use crate::border;
+use crate::event::Event;
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{
+    Background, Border, Color, Pixels, Point, Shadow, Shell, Size, Vector,
+};
+
+use std::cell::RefCell;
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Interpolates between `self` and `other`.
+    ///
+    /// A `t` of `0.0` produces `self` and a `t` of `1.0` produces `other`.
+    /// Springs may overshoot, so `t` can fall outside of that range.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+            a: self.a.interpolate(&other.a, t).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+impl Interpolate for Pixels {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Pixels(self.0.interpolate(&other.0, t))
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, t))
+            }
+            _ if t < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl Interpolate for border::Radius {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let a: [f32; 4] = (*self).into();
+        let b: [f32; 4] = (*other).into();
+
+        [
+            a[0].interpolate(&b[0], t),
+            a[1].interpolate(&b[1], t),
+            a[2].interpolate(&b[2], t),
+            a[3].interpolate(&b[3], t),
+        ]
+        .into()
+    }
+}
+
+impl Interpolate for Border {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Border {
+            color: self.color.interpolate(&other.color, t),
+            width: self.width.interpolate(&other.width, t).max(0.0),
+            radius: self.radius.interpolate(&other.radius, t),
+        }
+    }
+}
+
+impl Interpolate for Shadow {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, t),
+            offset: self.offset.interpolate(&other.offset, t),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, t)
+                .max(0.0),
+        }
+    }
+}
+
+impl<T> Interpolate for Option<T>
+where
+    T: Interpolate + Clone,
+{
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, t)),
+            _ if t < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+/// A curve that maps the elapsed fraction of an animation to its progress.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    #[default]
+    EaseOut,
+    /// Starts slow, accelerates and then decelerates.
+    EaseInOut,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the given
+    /// control points, like in CSS.
+    CubicBezier {
+        /// The horizontal coordinate of the first control point.
+        x1: f32,
+        /// The vertical coordinate of the first control point.
+        y1: f32,
+        /// The horizontal coordinate of the second control point.
+        x2: f32,
+        /// The vertical coordinate of the second control point.
+        y2: f32,
+    },
+}
+
+impl Easing {
+    /// Returns the progress of the curve at the given fraction of time,
+    /// between `0.0` and `1.0`.
+    pub fn value(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::CubicBezier { x1, y1, x2, y2 } => {
+                let bezier = |a: f32, b: f32, s: f32| {
+                    3.0 * a * s * (1.0 - s).powi(2)
+                        + 3.0 * b * s * s * (1.0 - s)
+                        + s * s * s
+                };
+
+                let x1 = x1.clamp(0.0, 1.0);
+                let x2 = x2.clamp(0.0, 1.0);
+
+                // The curve is monotonic in x, so we can bisect for the
+                // parameter that produces the given time.
+                let (mut low, mut high) = (0.0, 1.0);
+
+                for _ in 0..24 {
+                    let middle = (low + high) / 2.0;
+
+                    if bezier(x1, x2, middle) < t {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+
+                bezier(y1, y2, (low + high) / 2.0)
+            }
+        }
+    }
+}
+
+/// The physical properties of a spring.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls towards its target.
+    pub stiffness: f32,
+    /// How strongly the motion of the spring is slowed down.
+    pub damping: f32,
+    /// The mass attached to the spring.
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A balanced spring without any bounce.
+    pub const DEFAULT: Self = Self {
+        stiffness: 170.0,
+        damping: 26.0,
+        mass: 1.0,
+    };
+
+    /// A slow spring with a little bounce.
+    pub const GENTLE: Self = Self {
+        stiffness: 120.0,
+        damping: 14.0,
+        mass: 1.0,
+    };
+
+    /// A spring that bounces noticeably.
+    pub const WOBBLY: Self = Self {
+        stiffness: 180.0,
+        damping: 12.0,
+        mass: 1.0,
+    };
+
+    /// A fast spring with a little bounce.
+    pub const STIFF: Self = Self {
+        stiffness: 210.0,
+        damping: 20.0,
+        mass: 1.0,
+    };
+
+    /// The threshold of the displacement under which a [`Spring`] is at rest.
+    const REST: f32 = 0.001;
+
+    /// The longest time a [`Spring`] is allowed to move.
+    const MAX_DURATION: f32 = 10.0;
+
+    fn frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the position of the [`Spring`] after the given amount of
+    /// seconds, when it starts at rest at `0.0` and is pulled towards `1.0`.
+    pub fn position(&self, seconds: f32) -> f32 {
+        if !(self.stiffness > 0.0 && self.mass > 0.0) {
+            return 1.0;
+        }
+
+        let omega = self.frequency();
+        let zeta = self.damping_ratio().max(0.0);
+
+        if zeta < 0.999 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let envelope = (-zeta * omega * seconds).exp();
+
+            1.0 - envelope
+                * ((omega_d * seconds).cos()
+                    + zeta * omega / omega_d * (omega_d * seconds).sin())
+        } else if zeta <= 1.001 {
+            1.0 - (-omega * seconds).exp() * (1.0 + omega * seconds)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            1.0 - (r2 * (r1 * seconds).exp() - r1 * (r2 * seconds).exp())
+                / (r2 - r1)
+        }
+    }
+
+    /// Returns the time the [`Spring`] needs to come to rest.
+    pub fn duration(&self) -> Duration {
+        if !(self.stiffness > 0.0 && self.mass > 0.0) {
+            return Duration::ZERO;
+        }
+
+        let omega = self.frequency();
+        let zeta = self.damping_ratio().max(0.0);
+
+        let decay = if zeta < 1.0 {
+            zeta * omega
+        } else {
+            omega * (zeta - (zeta * zeta - 1.0).sqrt())
+        };
+
+        // The envelope of the motion has to decay under the rest threshold;
+        // the extra factor accounts for the polynomial term of a critically
+        // damped spring.
+        let seconds = if decay > 0.0 {
+            (1.0 / Self::REST).ln() * 1.2 / decay
+        } else {
+            Self::MAX_DURATION
+        };
+
+        Duration::from_secs_f32(seconds.min(Self::MAX_DURATION))
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How an animated value moves towards its target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    /// Follows an [`Easing`] curve for a fixed [`Duration`].
+    Easing {
+        /// The [`Easing`] curve of the [`Transition`].
+        easing: Easing,
+        /// The [`Duration`] of the [`Transition`].
+        duration: Duration,
+    },
+    /// Follows the motion of a [`Spring`] until it comes to rest.
+    Spring(Spring),
+}
+
+impl Transition {
+    /// Creates a [`Transition`] that follows the given [`Easing`] curve for
+    /// the given [`Duration`].
+    pub fn easing(easing: Easing, duration: Duration) -> Self {
+        Self::Easing { easing, duration }
+    }
+
+    /// Creates a [`Transition`] that follows the given [`Spring`].
+    pub fn spring(spring: Spring) -> Self {
+        Self::Spring(spring)
+    }
+
+    /// Returns the total [`Duration`] of the [`Transition`].
+    pub fn duration(&self) -> Duration {
+        match self {
+            Transition::Easing { duration, .. } => *duration,
+            Transition::Spring(spring) => spring.duration(),
+        }
+    }
+
+    /// Returns the progress of the [`Transition`] after the given amount of
+    /// time has passed.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration() {
+            return 1.0;
+        }
+
+        match self {
+            Transition::Easing { easing, duration } => {
+                easing.value(elapsed.as_secs_f32() / duration.as_secs_f32())
+            }
+            Transition::Spring(spring) => {
+                spring.position(elapsed.as_secs_f32())
+            }
+        }
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::Easing {
+            easing: Easing::default(),
+            duration: Duration::from_millis(200),
+        }
+    }
+}
+
+/// A value that moves towards its target over time.
+///
+/// An [`Animated`] value does not keep track of time on its own; every query
+/// takes the current [`Instant`], which is usually the one provided by a
+/// `window::Event::RedrawRequested` event or by `window::frames`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animated<T> {
+    source: T,
+    target: T,
+    started_at: Option<Instant>,
+    transition: Transition,
+}
+
+impl<T> Animated<T>
+where
+    T: Clone,
+{
+    /// Creates a new [`Animated`] value at rest with the default
+    /// [`Transition`].
+    pub fn new(value: T) -> Self {
+        Self {
+            source: value.clone(),
+            target: value,
+            started_at: None,
+            transition: Transition::default(),
+        }
+    }
+
+    /// Sets the [`Transition`] of the [`Animated`] value.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Makes the [`Animated`] value follow the given [`Easing`] curve for the
+    /// given [`Duration`].
+    pub fn easing(self, easing: Easing, duration: Duration) -> Self {
+        self.transition(Transition::easing(easing, duration))
+    }
+
+    /// Makes the [`Animated`] value follow the given [`Spring`].
+    pub fn spring(self, spring: Spring) -> Self {
+        self.transition(Transition::spring(spring))
+    }
+
+    /// Changes the [`Transition`] of the [`Animated`] value.
+    ///
+    /// The change applies to the next animation.
+    pub fn set_transition(&mut self, transition: Transition) {
+        self.transition = transition;
+    }
+
+    /// Returns the value the current animation started from.
+    pub fn source(&self) -> &T {
+        &self.source
+    }
+
+    /// Returns the value the [`Animated`] value is moving towards.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns the progress of the current animation at the given
+    /// [`Instant`].
+    pub fn progress(&self, now: Instant) -> f32 {
+        match self.started_at {
+            Some(started_at) => self
+                .transition
+                .progress(now.saturating_duration_since(started_at)),
+            None => 1.0,
+        }
+    }
+
+    /// Returns whether the [`Animated`] value is still moving at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.started_at.is_some_and(|started_at| {
+            now.saturating_duration_since(started_at)
+                < self.transition.duration()
+        })
+    }
+
+    /// Sets the value immediately, stopping any running animation.
+    pub fn snap_to(&mut self, value: T) {
+        self.source = value.clone();
+        self.target = value;
+        self.started_at = None;
+    }
+
+    /// Starts an animation from the current target to a new one.
+    ///
+    /// This is meant for values that cannot be interpolated, like the status
+    /// of a widget. Going back to the source of a running animation reverses
+    /// it instead of starting over.
+    pub fn transition_to(&mut self, target: T, now: Instant)
+    where
+        T: PartialEq,
+    {
+        if target == self.target {
+            return;
+        }
+
+        let duration = self.transition.duration();
+        let elapsed = self
+            .started_at
+            .map(|started_at| now.saturating_duration_since(started_at));
+
+        match elapsed {
+            Some(elapsed) if target == self.source && elapsed < duration => {
+                std::mem::swap(&mut self.source, &mut self.target);
+
+                self.started_at =
+                    Some(now.checked_sub(duration - elapsed).unwrap_or(now));
+            }
+            _ => {
+                self.source = std::mem::replace(&mut self.target, target);
+                self.started_at = Some(now);
+            }
+        }
+    }
+
+    /// Starts an animation from the current value to the given target.
+    pub fn go_to(&mut self, target: T, now: Instant)
+    where
+        T: Interpolate,
+    {
+        self.source = self.value(now);
+        self.target = target;
+        self.started_at = Some(now);
+    }
+
+    /// Returns the value at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T
+    where
+        T: Interpolate,
+    {
+        if self.is_animating(now) {
+            self.source.interpolate(&self.target, self.progress(now))
+        } else {
+            self.target.clone()
+        }
+    }
+}
+
+/// Animates the appearance of a widget between its statuses.
+///
+/// Widgets keep a [`StatusTransition`] in their state, [`update`] it on every
+/// event with their current status and obtain their appearance through it
+/// when drawing. Redraws are only requested while the status changes or an
+/// animation is running.
+///
+/// An animation starts when a new status is drawn for the first time, driven
+/// by the [`Transition`] of its appearance and timed with the [`Instant`] of
+/// the last redraw.
+///
+/// [`update`]: Self::update
+#[derive(Debug)]
+pub struct StatusTransition<Status> {
+    status: RefCell<Option<Animated<Status>>>,
+    /// A new status waiting for its first draw, with a redraw requested.
+    pending: Option<Status>,
+    now: Instant,
+}
+
+impl<Status> StatusTransition<Status>
+where
+    Status: Copy + PartialEq,
+{
+    /// Creates a new [`StatusTransition`].
+    pub fn new() -> Self {
+        Self {
+            status: RefCell::new(None),
+            pending: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Processes an [`Event`] with the current status of the widget,
+    /// requesting a redraw if the status has changed or an animation is
+    /// running.
+    pub fn update<Message>(
+        &mut self,
+        event: &Event,
+        status: Status,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_redraw = if let Event::Window(
+            _,
+            window::Event::RedrawRequested(now),
+        ) = event
+        {
+            self.now = *now;
+            true
+        } else {
+            false
+        };
+
+        let animated = self
+            .status
+            .get_mut()
+            .get_or_insert_with(|| Animated::new(status));
+
+        let is_changing = if *animated.target() == status {
+            false
+        } else if is_redraw {
+            // The animation starts with the upcoming draw, so the next frame
+            // is needed; widgets that are not drawn stop asking for frames
+            self.pending.take() == Some(status)
+        } else if self.pending == Some(status) {
+            false
+        } else {
+            self.pending = Some(status);
+            true
+        };
+
+        if is_changing || animated.is_animating(self.now) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    /// Returns the appearance of the widget for the given status, blending
+    /// it with the appearance of the previous status while animating.
+    ///
+    /// When the status has changed, the [`Transition`] returned by
+    /// `transition` for its appearance starts a new animation. Without a
+    /// [`Transition`], the appearance changes at once.
+    pub fn appearance<Appearance>(
+        &self,
+        status: Status,
+        style: impl Fn(Status) -> Appearance,
+        transition: impl Fn(&Appearance) -> Option<Transition>,
+    ) -> Appearance
+    where
+        Appearance: Interpolate,
+    {
+        let target = style(status);
+
+        let mut animated = self.status.borrow_mut();
+        let animated = animated.get_or_insert_with(|| Animated::new(status));
+
+        if *animated.target() != status {
+            if let Some(transition) = transition(&target) {
+                animated.set_transition(transition);
+                animated.transition_to(status, self.now);
+            } else {
+                animated.snap_to(status);
+            }
+        }
+
+        if animated.is_animating(self.now) {
+            style(*animated.source())
+                .interpolate(&target, animated.progress(self.now))
+        } else {
+            target
+        }
+    }
+}
+
+impl<Status> Default for StatusTransition<Status>
+where
+    Status: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_start_and_end_at_the_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::CubicBezier {
+                x1: 0.25,
+                y1: 0.1,
+                x2: 0.25,
+                y2: 1.0,
+            },
+        ] {
+            assert!(easing.value(0.0).abs() < 1e-3);
+            assert!((easing.value(1.0) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn springs_come_to_rest_at_the_target() {
+        for spring in [
+            Spring::DEFAULT,
+            Spring::GENTLE,
+            Spring::WOBBLY,
+            Spring::STIFF,
+        ] {
+            let duration = spring.duration().as_secs_f32();
+
+            assert!(spring.position(0.0).abs() < 1e-3);
+            assert!((spring.position(duration) - 1.0).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn animated_values_reach_their_target() {
+        let start = Instant::now();
+
+        let mut animated = Animated::new(0.0)
+            .easing(Easing::Linear, Duration::from_millis(100));
+
+        animated.go_to(10.0, start);
+
+        let halfway = animated.value(start + Duration::from_millis(50));
+
+        assert!((halfway - 5.0).abs() < 1e-3);
+        assert!(animated.is_animating(start + Duration::from_millis(50)));
+        assert!(!animated.is_animating(start + Duration::from_millis(100)));
+        assert_eq!(animated.value(start + Duration::from_millis(150)), 10.0);
+    }
+
+    #[test]
+    fn status_transitions_start_with_their_first_draw() {
+        let start = Instant::now();
+        let redraw = |elapsed| {
+            Event::Window(
+                window::Id::MAIN,
+                window::Event::RedrawRequested(
+                    start + Duration::from_millis(elapsed),
+                ),
+            )
+        };
+
+        let style = |status: u8| f32::from(status) * 10.0;
+        let transition = |_: &f32| {
+            Some(Transition::easing(
+                Easing::Linear,
+                Duration::from_millis(100),
+            ))
+        };
+
+        let mut status = StatusTransition::new();
+        let mut messages: Vec<()> = Vec::new();
+
+        let mut update = |status: &mut StatusTransition<u8>, event, value| {
+            let mut shell = Shell::new(&mut messages);
+            status.update(&event, value, &mut shell);
+
+            shell.redraw_request()
+        };
+
+        assert_eq!(update(&mut status, redraw(0), 0), None);
+        assert_eq!(status.appearance(0, style, transition), 0.0);
+
+        let hover = Event::Mouse(crate::mouse::Event::CursorEntered);
+
+        assert_eq!(
+            update(&mut status, hover, 1),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        assert_eq!(
+            update(&mut status, redraw(1000), 1),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        assert_eq!(status.appearance(1, style, transition), 0.0);
+
+        let _ = update(&mut status, redraw(1050), 1);
+        assert!((status.appearance(1, style, transition) - 5.0).abs() < 1e-3);
+
+        let _ = update(&mut status, redraw(1100), 1);
+        assert_eq!(status.appearance(1, style, transition), 10.0);
+        assert_eq!(update(&mut status, redraw(1150), 1), None);
+    }
+}
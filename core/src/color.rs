@@ -108,6 +108,53 @@ impl Color {
         }
     }
 
+    /// Parses a [`Color`] in `#rgb`, `#rrggbb` or `#rrggbbaa` notation, with
+    /// an optional `#`.
+    pub fn parse(text: &str) -> Option<Color> {
+        let hex = text.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize, len: usize| {
+            let value =
+                u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok()?;
+
+            Some(if len == 1 { value * 17 } else { value })
+        };
+
+        let [r, g, b, a] = match hex.len() {
+            3 => [channel(0, 1)?, channel(1, 1)?, channel(2, 1)?, u8::MAX],
+            6 | 8 => [
+                channel(0, 2)?,
+                channel(1, 2)?,
+                channel(2, 2)?,
+                if hex.len() == 8 {
+                    channel(3, 2)?
+                } else {
+                    u8::MAX
+                },
+            ],
+            _ => return None,
+        };
+
+        Some(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+    }
+
+    /// Formats the [`Color`] as `#rrggbb`, or `#rrggbbaa` if it is
+    /// translucent.
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.into_rgba8();
+
+        if a == u8::MAX {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+
     /// Converts the [`Color`] into its RGBA8 equivalent.
     #[must_use]
     pub fn into_rgba8(self) -> [u8; 4] {
@@ -237,6 +284,30 @@ impl From<Color> for Srgb {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+
+        Color::parse(&text).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid hex color: {text:?}"))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +325,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -0,0 +1,432 @@
+//! Translate the text of an application and format numbers and dates.
+//!
+//! The translations of an application live in a [`Catalog`]: a [`Bundle`]
+//! for every [`Locale`] it supports and the current [`Locale`]. The runtime
+//! owns the [`Catalog`] of an application, changes it with the commands of
+//! its `i18n` module and makes it [`current`] while the application builds,
+//! lays out and draws its views. The functions of this module read the
+//! current [`Catalog`], so views can look up their messages directly:
+//!
+//! ```
+//! # #[cfg(feature = "fluent")]
+//! # {
+//! use iced_core::i18n::{self, Args, Bundle, Catalog};
+//!
+//! let mut catalog = Catalog::new("es-MX");
+//!
+//! catalog.add_bundle(Bundle::parse("en", "greeting = Hello, { $name }!")?);
+//! catalog.add_bundle(Bundle::parse("es", "greeting = ¡Hola, { $name }!")?);
+//!
+//! let greeting = i18n::scope(&catalog, || {
+//!     i18n::translate_with("greeting", &Args::new().with("name", "Iced"))
+//! });
+//!
+//! assert_eq!(greeting, "¡Hola, Iced!");
+//! # }
+//! # Ok::<(), iced_core::i18n::Error>(())
+//! ```
+//!
+//! Built-in widgets look up their own strings in the current [`Catalog`]
+//! too, falling back to English. Their ids are prefixed with `iced-`; for
+//! instance, the date picker uses `iced-month-1` to `iced-month-12` and
+//! `iced-weekday-1` (Monday) to `iced-weekday-7`.
+//!
+//! Messages are written in the [Fluent] syntax and need the `fluent`
+//! feature. Numbers and dates are formatted with a small table of
+//! conventions that covers the most common languages; any other language
+//! uses the notation of [`Locale::ROOT`].
+//!
+//! [Fluent]: https://projectfluent.org/
+mod bundle;
+mod locale;
+
+pub use bundle::{Bundle, Error};
+pub use locale::Locale;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+thread_local! {
+    static CURRENT: RefCell<Catalog> = RefCell::new(Catalog::default());
+}
+
+/// The translations of an application and its current [`Locale`].
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    locale: Locale,
+    bundles: Vec<Bundle>,
+}
+
+impl Catalog {
+    /// Creates an empty [`Catalog`] with the given [`Locale`].
+    pub fn new(locale: impl Into<Locale>) -> Self {
+        Self {
+            locale: locale.into(),
+            bundles: Vec::new(),
+        }
+    }
+
+    /// Returns the current [`Locale`] of the [`Catalog`].
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Sets the current [`Locale`] of the [`Catalog`].
+    pub fn set_locale(&mut self, locale: impl Into<Locale>) {
+        self.locale = locale.into();
+    }
+
+    /// Adds a [`Bundle`] of translations to the [`Catalog`].
+    ///
+    /// A [`Bundle`] replaces any previous one for the same [`Locale`]. The
+    /// first [`Bundle`] added is used as the fallback for messages missing
+    /// from the current [`Locale`].
+    pub fn add_bundle(&mut self, bundle: Bundle) {
+        match self
+            .bundles
+            .iter_mut()
+            .find(|existing| existing.locale() == bundle.locale())
+        {
+            Some(existing) => *existing = bundle,
+            None => self.bundles.push(bundle),
+        }
+    }
+
+    /// Formats the message with the given id and [`Args`] in the current
+    /// [`Locale`], if any [`Bundle`] has it.
+    ///
+    /// The bundles are tried in order: the one of the current [`Locale`],
+    /// then the one of its language without a region, then any other of the
+    /// same language and, finally, the first one added.
+    pub fn try_translate(&self, id: &str, args: &Args) -> Option<String> {
+        let locale = &self.locale;
+        let language = locale.without_region();
+
+        let exact = self.bundles.iter().filter(|b| b.locale() == locale);
+        let base = self.bundles.iter().filter(|b| *b.locale() == language);
+        let related = self
+            .bundles
+            .iter()
+            .filter(|b| b.locale().language() == locale.language());
+
+        exact
+            .chain(base)
+            .chain(related)
+            .chain(self.bundles.first())
+            .find_map(|bundle| bundle.format(id, args))
+    }
+}
+
+/// Runs the given closure with the given [`Catalog`] as the [`current`] one.
+///
+/// The runtime calls this while building the views of an application.
+pub fn scope<T>(catalog: &Catalog, f: impl FnOnce() -> T) -> T {
+    let _entered = enter(catalog);
+
+    f()
+}
+
+/// Makes the given [`Catalog`] the [`current`] one until the returned
+/// [`Entered`] guard is dropped.
+pub fn enter(catalog: &Catalog) -> Entered {
+    Entered {
+        previous: Some(
+            CURRENT.with(|current| current.replace(catalog.clone())),
+        ),
+    }
+}
+
+/// A guard that restores the previous [`current`] [`Catalog`] when dropped.
+///
+/// It is returned by [`enter`].
+#[derive(Debug)]
+#[must_use = "the catalog is only current until the guard is dropped"]
+pub struct Entered {
+    previous: Option<Catalog>,
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Returns a copy of the current [`Catalog`].
+///
+/// It is empty, with [`Locale::ROOT`], outside of a [`scope`].
+pub fn current() -> Catalog {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+fn with_current<T>(f: impl FnOnce(&Catalog) -> T) -> T {
+    CURRENT.with(|current| f(&current.borrow()))
+}
+
+/// Returns the [`Locale`] of the [`current`] [`Catalog`].
+pub fn locale() -> Locale {
+    with_current(|catalog| catalog.locale.clone())
+}
+
+/// Formats the message with the given id and [`Args`] with the [`current`]
+/// [`Catalog`], if any of its bundles has it.
+///
+/// See [`Catalog::try_translate`].
+pub fn try_translate(id: &str, args: &Args) -> Option<String> {
+    with_current(|catalog| catalog.try_translate(id, args))
+}
+
+/// Formats the message with the given id in the current [`Locale`].
+///
+/// The id itself is returned when no [`Bundle`] has the message.
+pub fn translate(id: &str) -> String {
+    translate_with(id, &Args::new())
+}
+
+/// Formats the message with the given id and [`Args`] in the current
+/// [`Locale`].
+///
+/// The id itself is returned when no [`Bundle`] has the message.
+pub fn translate_with(id: &str, args: &Args) -> String {
+    try_translate(id, args).unwrap_or_else(|| id.to_owned())
+}
+
+/// Formats the message with the given id in the current [`Locale`], or
+/// returns the given fallback when no [`Bundle`] has it.
+///
+/// This is used by widgets for their built-in strings.
+pub fn translate_or<'a>(id: &str, fallback: &'a str) -> Cow<'a, str> {
+    try_translate(id, &Args::new()).map_or(Cow::Borrowed(fallback), Cow::Owned)
+}
+
+/// Formats a number in the current [`Locale`] with the given amount of
+/// fractional digits.
+///
+/// This is handy to build the labels of widgets like sliders and progress
+/// bars.
+pub fn format_number(number: impl Into<f64>, fraction_digits: usize) -> String {
+    with_current(|catalog| {
+        catalog.locale.format_number(number.into(), fraction_digits)
+    })
+}
+
+/// Formats a ratio between `0.0` and `1.0` as a percentage in the current
+/// [`Locale`] with the given amount of fractional digits.
+pub fn format_percent(ratio: impl Into<f64>, fraction_digits: usize) -> String {
+    with_current(|catalog| {
+        catalog.locale.format_percent(ratio.into(), fraction_digits)
+    })
+}
+
+/// Formats a date of the Gregorian calendar in the current [`Locale`].
+pub fn format_date(year: i32, month: u32, day: u32) -> String {
+    with_current(|catalog| catalog.locale.format_date(year, month, day))
+}
+
+/// The arguments of a translated message.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Args(Vec<(Cow<'static, str>, Value)>);
+
+impl Args {
+    /// Creates an empty set of [`Args`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named argument.
+    pub fn with(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.0.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns the value of the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(argument, _)| argument == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The value of an argument of a translated message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A string, inserted as is.
+    String(String),
+
+    /// A number, matched against the plural categories of the [`Locale`] of
+    /// the message in select expressions.
+    Number(f64),
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+macro_rules! number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn plurals_follow_the_locale() {
+        let source = "\
+files = { $count ->
+    [0] No files
+    [one] One file
+    [few] { $count } files (few)
+   *[other] { $count } files
+}
+    .title = Files";
+
+        let english = Bundle::parse("en", source).unwrap();
+        let polish = Bundle::parse("pl", source).unwrap();
+        let lithuanian = Bundle::parse("lt", source).unwrap();
+
+        let count = |n: u32| Args::new().with("count", n);
+
+        assert_eq!(english.format("files", &count(0)).unwrap(), "No files");
+        assert_eq!(english.format("files", &count(1)).unwrap(), "One file");
+        assert_eq!(english.format("files", &count(3)).unwrap(), "3 files");
+        assert_eq!(polish.format("files", &count(3)).unwrap(), "3 files (few)");
+        assert_eq!(polish.format("files", &count(5)).unwrap(), "5 files");
+        assert_eq!(lithuanian.format("files", &count(21)).unwrap(), "One file");
+        assert_eq!(lithuanian.format("files", &count(11)).unwrap(), "11 files");
+        assert_eq!(english.format("files.title", &count(5)).unwrap(), "Files");
+    }
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn messages_reference_each_other() {
+        let bundle = Bundle::parse(
+            "en",
+            "\
+# The product name
+-app = Iced
+about = About { -app }
+    .tooltip = Learn more about { about }
+multiline =
+    First line
+    second line
+missing = { $name } and { nowhere }",
+        )
+        .unwrap();
+
+        let args = Args::new();
+
+        assert_eq!(
+            bundle.format("about.tooltip", &args).unwrap(),
+            "Learn more about About Iced"
+        );
+        assert_eq!(
+            bundle.format("multiline", &args).unwrap(),
+            "First line\nsecond line"
+        );
+        assert_eq!(
+            bundle.format("missing", &args).unwrap(),
+            "{$name} and {nowhere}"
+        );
+        assert!(bundle.contains("about.tooltip"));
+        assert!(!bundle.contains("app"));
+    }
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn invalid_bundles_report_their_line() {
+        let error =
+            Bundle::parse("en", "valid = Yes\n\ninvalid { $x }").unwrap_err();
+
+        assert!(matches!(error, Error::Invalid { line: 3, .. }));
+
+        let error =
+            Bundle::parse("en", "a = { $x ->\n [one] x\n}").unwrap_err();
+
+        assert!(matches!(error, Error::Invalid { .. }));
+    }
+
+    #[test]
+    #[cfg(not(feature = "fluent"))]
+    fn bundles_need_the_fluent_feature() {
+        assert_eq!(
+            Bundle::parse("en", "greeting = Hello!").unwrap_err(),
+            Error::Unsupported
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fluent")]
+    fn catalogs_are_current_within_their_scope() {
+        let mut catalog = Catalog::new("de-AT");
+
+        catalog.add_bundle(Bundle::parse("en", "yes = Yes").unwrap());
+        catalog.add_bundle(Bundle::parse("de", "yes = Ja").unwrap());
+
+        assert_eq!(translate("yes"), "yes");
+
+        scope(&catalog, || {
+            assert_eq!(locale(), Locale::new("de-AT"));
+            assert_eq!(translate("yes"), "Ja");
+
+            scope(&Catalog::new("fr"), || {
+                assert_eq!(translate("yes"), "yes");
+            });
+
+            assert_eq!(translate("yes"), "Ja");
+        });
+
+        catalog.set_locale("fr");
+
+        assert_eq!(scope(&catalog, || translate("yes")), "Yes");
+        assert_eq!(locale(), Locale::ROOT);
+    }
+
+    #[test]
+    fn numbers_and_dates_follow_the_locale() {
+        let french = Locale::new("fr_FR.UTF-8");
+
+        assert_eq!(french.to_string(), "fr-FR");
+        assert_eq!(
+            french.format_number(-1234567.891, 2),
+            "-1\u{202f}234\u{202f}567,89"
+        );
+        assert_eq!(french.format_percent(0.5, 0), "50\u{a0}%");
+        assert_eq!(french.format_date(2024, 1, 31), "31/01/2024");
+        assert_eq!(Locale::ROOT.format_number(1234.5, 1), "1234.5");
+        assert_eq!(
+            Locale::new("he").direction(),
+            crate::layout::Direction::RightToLeft
+        );
+        assert_eq!(
+            scope(&Catalog::new(french), || format_date(2024, 1, 31)),
+            "31/01/2024"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::i18n::{Args, Locale};
+
+#[cfg(feature = "fluent")]
+use crate::i18n::Value;
+
+use std::fmt;
+
+#[cfg(feature = "fluent")]
+use std::sync::Arc;
+
+/// A set of translated messages for a [`Locale`], written in the [Fluent]
+/// syntax.
+///
+/// Bundles are parsed and formatted by [`fluent-bundle`], which follows the
+/// plural rules of the Unicode CLDR. It is only available with the `fluent`
+/// feature; without it, [`Bundle::parse`] fails with [`Error::Unsupported`]
+/// and the built-in strings of widgets stay in English.
+///
+/// ```
+/// # #[cfg(feature = "fluent")]
+/// # {
+/// use iced_core::i18n::{Args, Bundle, Locale};
+///
+/// let bundle = Bundle::parse(
+///     Locale::new("en"),
+///     r#"
+/// -brand = Iced
+/// welcome = Welcome to { -brand }, { $name }!
+/// unread = { $count ->
+///     [one] You have one unread message.
+///    *[other] You have { $count } unread messages.
+/// }
+/// "#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     bundle.format("welcome", &Args::new().with("name", "Ferris")),
+///     Some("Welcome to Iced, Ferris!".to_owned())
+/// );
+///
+/// assert_eq!(
+///     bundle.format("unread", &Args::new().with("count", 3)),
+///     Some("You have 3 unread messages.".to_owned())
+/// );
+/// # }
+/// ```
+///
+/// [Fluent]: https://projectfluent.org/
+/// [`fluent-bundle`]: https://docs.rs/fluent-bundle
+#[derive(Clone)]
+pub struct Bundle {
+    locale: Locale,
+    #[cfg(feature = "fluent")]
+    raw: Arc<
+        fluent_bundle::concurrent::FluentBundle<fluent_bundle::FluentResource>,
+    >,
+}
+
+impl Bundle {
+    /// Parses a [`Bundle`] for the given [`Locale`] from the contents of a
+    /// Fluent `.ftl` file.
+    pub fn parse(
+        locale: impl Into<Locale>,
+        source: &str,
+    ) -> Result<Self, Error> {
+        let locale = locale.into();
+
+        #[cfg(feature = "fluent")]
+        {
+            use fluent_bundle::concurrent::FluentBundle;
+            use fluent_bundle::FluentResource;
+
+            let resource = FluentResource::try_new(source.to_owned()).map_err(
+                |(_, errors)| {
+                    let error = &errors[0];
+                    let start = error.pos.start.min(source.len());
+
+                    Error::Invalid {
+                        line: source.as_bytes()[..start]
+                            .iter()
+                            .filter(|byte| **byte == b'\n')
+                            .count()
+                            + 1,
+                        reason: error.kind.to_string(),
+                    }
+                },
+            )?;
+
+            let language = locale.to_string().parse().unwrap_or_default();
+
+            let mut raw = FluentBundle::new_concurrent(vec![language]);
+            raw.set_use_isolating(false);
+            raw.add_resource_overriding(resource);
+
+            Ok(Self {
+                locale,
+                raw: Arc::new(raw),
+            })
+        }
+
+        #[cfg(not(feature = "fluent"))]
+        {
+            let _ = (locale, source);
+
+            Err(Error::Unsupported)
+        }
+    }
+
+    /// Returns the [`Locale`] of the [`Bundle`].
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Returns true if the [`Bundle`] has a message with the given id.
+    ///
+    /// The id can refer to an attribute of a message, like `button.tooltip`.
+    pub fn contains(&self, id: &str) -> bool {
+        #[cfg(feature = "fluent")]
+        {
+            let (message, attribute) = split(id);
+
+            self.raw
+                .get_message(message)
+                .is_some_and(|message| match attribute {
+                    Some(attribute) => {
+                        message.get_attribute(attribute).is_some()
+                    }
+                    None => message.value().is_some(),
+                })
+        }
+
+        #[cfg(not(feature = "fluent"))]
+        {
+            let _ = id;
+
+            false
+        }
+    }
+
+    /// Formats the message with the given id using the given [`Args`].
+    ///
+    /// The id can refer to an attribute of a message, like `button.tooltip`.
+    /// Unknown variables and references are rendered in braces, like
+    /// `{$name}`.
+    pub fn format(&self, id: &str, args: &Args) -> Option<String> {
+        #[cfg(feature = "fluent")]
+        {
+            use fluent_bundle::{FluentArgs, FluentValue};
+
+            let (message, attribute) = split(id);
+            let message = self.raw.get_message(message)?;
+
+            let pattern = match attribute {
+                Some(attribute) => message.get_attribute(attribute)?.value(),
+                None => message.value()?,
+            };
+
+            let mut arguments = FluentArgs::new();
+
+            for (name, value) in &args.0 {
+                arguments.set(
+                    name.clone(),
+                    match value {
+                        Value::String(string) => {
+                            FluentValue::from(string.clone())
+                        }
+                        Value::Number(number) => FluentValue::from(*number),
+                    },
+                );
+            }
+
+            let mut errors = Vec::new();
+
+            Some(
+                self.raw
+                    .format_pattern(pattern, Some(&arguments), &mut errors)
+                    .into_owned(),
+            )
+        }
+
+        #[cfg(not(feature = "fluent"))]
+        {
+            let _ = (id, args);
+
+            None
+        }
+    }
+}
+
+impl fmt::Debug for Bundle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bundle")
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "fluent")]
+fn split(id: &str) -> (&str, Option<&str>) {
+    match id.split_once('.') {
+        Some((message, attribute)) => (message, Some(attribute)),
+        None => (id, None),
+    }
+}
+
+/// An error while parsing a [`Bundle`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The source of the [`Bundle`] is not valid Fluent.
+    #[error("invalid message at line {line}: {reason}")]
+    Invalid {
+        /// The line of the error, starting at 1.
+        line: usize,
+
+        /// The reason of the error.
+        reason: String,
+    },
+
+    /// Bundles cannot be parsed because the `fluent` feature is disabled.
+    #[error("parsing bundles needs the `fluent` feature")]
+    Unsupported,
+}
@@ -0,0 +1,235 @@
+use crate::layout::Direction;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A language, optionally specific to a region, identified by a
+/// [BCP 47] tag like `en-US`.
+///
+/// The [`Locale`] decides how numbers and dates are formatted, and the
+/// plural rules of the messages of a [`Bundle`].
+///
+/// [`Bundle`]: super::Bundle
+///
+/// [BCP 47]: https://www.rfc-editor.org/info/bcp47
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    language: String,
+    region: Option<String>,
+}
+
+impl Locale {
+    /// The undetermined [`Locale`].
+    ///
+    /// It formats numbers without grouping and dates in ISO 8601 notation,
+    /// and it is used until an application picks a [`Locale`].
+    pub const ROOT: Self = Self {
+        language: String::new(),
+        region: None,
+    };
+
+    /// Parses a [`Locale`] from the given tag.
+    ///
+    /// Both `en-US` and POSIX-style `en_US.UTF-8` tags are understood; any
+    /// script, variant or encoding is ignored. An empty tag, `C` or `POSIX`
+    /// produce [`Locale::ROOT`].
+    pub fn new(tag: &str) -> Self {
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = tag.split(['-', '_']).filter(|tag| !tag.is_empty());
+
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+
+        if matches!(language.as_str(), "" | "c" | "posix" | "und") {
+            return Self::ROOT;
+        }
+
+        let region = subtags
+            .find(|subtag| {
+                subtag.len() == 2 && subtag.chars().all(char::is_alphabetic)
+                    || subtag.len() == 3 && subtag.chars().all(char::is_numeric)
+            })
+            .map(str::to_ascii_uppercase);
+
+        Self { language, region }
+    }
+
+    /// Returns the [`Locale`] of the user, as configured in the `LC_ALL`,
+    /// `LC_MESSAGES` or `LANG` environment variables.
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .map(|tag| Self::new(&tag))
+            .find(|locale| *locale != Self::ROOT)
+    }
+
+    /// Returns the lowercase language code of the [`Locale`], like `en`.
+    ///
+    /// It is empty for [`Locale::ROOT`].
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns the uppercase region code of the [`Locale`], like `US`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// Returns the [`Locale`] with the region removed.
+    pub fn without_region(&self) -> Self {
+        Self {
+            language: self.language.clone(),
+            region: None,
+        }
+    }
+
+    /// Returns the [`Direction`] in which the language of the [`Locale`] is
+    /// written.
+    pub fn direction(&self) -> Direction {
+        match self.language() {
+            "ar" | "dv" | "fa" | "he" | "ps" | "sd" | "ug" | "ur" | "yi" => {
+                Direction::RightToLeft
+            }
+            _ => Direction::LeftToRight,
+        }
+    }
+
+    /// Formats a number with the given amount of fractional digits, using
+    /// the decimal and grouping separators of the [`Locale`].
+    ///
+    /// ```
+    /// use iced_core::i18n::Locale;
+    ///
+    /// assert_eq!(Locale::new("en-US").format_number(1234.5, 2), "1,234.50");
+    /// assert_eq!(Locale::new("de").format_number(1234.5, 1), "1.234,5");
+    /// ```
+    pub fn format_number(&self, number: f64, fraction_digits: usize) -> String {
+        if !number.is_finite() {
+            return number.to_string();
+        }
+
+        let (decimal, group) = self.separators();
+        let formatted = format!("{:.*}", fraction_digits, number.abs());
+
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+
+        let mut result = String::with_capacity(formatted.len() + 8);
+
+        if number.is_sign_negative() && formatted.bytes().any(|b| b > b'0') {
+            result.push('-');
+        }
+
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                if let Some(group) = group {
+                    result.push_str(group);
+                }
+            }
+
+            result.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            result.push(decimal);
+            result.push_str(fraction);
+        }
+
+        result
+    }
+
+    /// Formats a ratio between `0.0` and `1.0` as a percentage with the
+    /// given amount of fractional digits.
+    pub fn format_percent(&self, ratio: f64, fraction_digits: usize) -> String {
+        let number = self.format_number(ratio * 100.0, fraction_digits);
+
+        match self.language() {
+            "de" | "fr" | "es" | "sv" | "nb" | "da" | "fi" | "cs" | "sk"
+            | "pl" | "ru" | "uk" => format!("{number}\u{a0}%"),
+            "tr" => format!("%{number}"),
+            _ => format!("{number}%"),
+        }
+    }
+
+    /// Formats a date of the Gregorian calendar with the numeric notation
+    /// of the [`Locale`], where `month` and `day` start at 1.
+    ///
+    /// ```
+    /// use iced_core::i18n::Locale;
+    ///
+    /// assert_eq!(Locale::ROOT.format_date(2024, 3, 9), "2024-03-09");
+    /// assert_eq!(Locale::new("en-US").format_date(2024, 3, 9), "3/9/2024");
+    /// assert_eq!(Locale::new("de-DE").format_date(2024, 3, 9), "09.03.2024");
+    /// ```
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        match (self.language(), self.region()) {
+            ("en", None | Some("US" | "PH")) => {
+                format!("{month}/{day}/{year}")
+            }
+            ("ja" | "zh", _) => format!("{year}/{month:02}/{day:02}"),
+            ("ko", _) => format!("{year}. {month}. {day}."),
+            ("nl", _) => format!("{day}-{month}-{year}"),
+            (
+                "de" | "ru" | "pl" | "cs" | "sk" | "fi" | "nb" | "da" | "tr"
+                | "uk",
+                _,
+            ) => format!("{day:02}.{month:02}.{year}"),
+            (
+                "en" | "fr" | "es" | "it" | "pt" | "el" | "ar" | "he" | "id"
+                | "vi",
+                _,
+            ) => format!("{day:02}/{month:02}/{year}"),
+            _ => format!("{year:04}-{month:02}-{day:02}"),
+        }
+    }
+
+    fn separators(&self) -> (char, Option<&'static str>) {
+        match self.language() {
+            "" => ('.', None),
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" => {
+                (',', Some("."))
+            }
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "uk" => {
+                (',', Some("\u{202f}"))
+            }
+            _ => ('.', Some(",")),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::ROOT
+    }
+}
+
+impl FromStr for Locale {
+    type Err = std::convert::Infallible;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(tag))
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(tag: &str) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.language.is_empty() {
+            return f.write_str("und");
+        }
+
+        f.write_str(&self.language)?;
+
+        if let Some(region) = &self.region {
+            write!(f, "-{region}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
 pub mod flex;
+pub mod wrap;
 
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -54,7 +57,7 @@ impl<'a> Layout<'a> {
     }
 
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
-    pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
+    pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
             Layout::with_offset(
                 Vector::new(self.position.x, self.position.y),
@@ -85,16 +88,23 @@ pub fn next_to_each_other(
         ((right_size.height - left_size.height) / 2.0, 0.0)
     };
 
-    Node::with_children(
-        Size::new(
-            left_size.width + spacing + right_size.width,
-            left_size.height.max(right_size.height),
-        ),
-        vec![
-            left_node.move_to(Point::new(0.0, left_y)),
-            right_node.move_to(Point::new(left_size.width + spacing, right_y)),
-        ],
-    )
+    let size = Size::new(
+        left_size.width + spacing + right_size.width,
+        left_size.height.max(right_size.height),
+    );
+
+    let mut children = vec![
+        left_node.move_to(Point::new(0.0, left_y)),
+        right_node.move_to(Point::new(left_size.width + spacing, right_y)),
+    ];
+
+    if limits.direction().is_rtl() {
+        for child in &mut children {
+            child.mirror_mut(size.width);
+        }
+    }
+
+    Node::with_children(size, children)
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -142,11 +152,15 @@ pub fn contained(
 
     let limits = limits.width(width).height(height);
     let content = f(&limits);
+    let size = limits.resolve(width, height, content.size());
+
+    let content = if limits.direction().is_rtl() {
+        content.mirror(size.width)
+    } else {
+        content
+    };
 
-    Node::with_children(
-        limits.resolve(width, height, content.size()),
-        vec![content],
-    )
+    Node::with_children(size, vec![content])
 }
 
 /// Computes the [`Node`] that fits the [`Limits`] given some width, height, and
@@ -163,6 +177,11 @@ pub fn padded(
 }
 
 /// Computes a [`padded`] [`Node`] with a positioning step.
+///
+/// In a right-to-left layout, the positioned content is mirrored; therefore,
+/// the left padding and a [`Start`] alignment end up on the right.
+///
+/// [`Start`]: crate::Alignment::Start
 pub fn positioned(
     limits: &Limits,
     width: impl Into<Length>,
@@ -183,8 +202,40 @@ pub fn positioned(
         .shrink(padding)
         .resolve(width, height, content.size());
 
-    Node::with_children(
-        size.expand(padding),
-        vec![position(content.move_to((padding.left, padding.top)), size)],
-    )
+    let size = size.expand(padding);
+    let content = position(content.move_to((padding.left, padding.top)), size);
+
+    let content = if limits.direction().is_rtl() {
+        content.mirror(size.width)
+    } else {
+        content
+    };
+
+    Node::with_children(size, vec![content])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn right_to_left_layouts_mirror_padding() {
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let padding = Padding {
+            left: 10.0,
+            ..Padding::ZERO
+        };
+
+        let layout = |limits: &Limits| {
+            padded(limits, Length::Fill, Length::Shrink, padding, |_| {
+                Node::new(Size::new(20.0, 20.0))
+            })
+        };
+
+        let ltr = layout(&limits);
+        let rtl = layout(&limits.with_direction(Direction::RightToLeft));
+
+        assert_eq!(ltr.children()[0].bounds().x, 10.0);
+        assert_eq!(rtl.children()[0].bounds().x, 70.0);
+    }
 }
@@ -0,0 +1,46 @@
+use crate::Alignment;
+
+/// The horizontal direction in which content flows.
+///
+/// In a [`RightToLeft`] layout, rows start on the right, horizontal
+/// alignments and paddings are mirrored, and vertical scrollbars are placed
+/// on the left.
+///
+/// [`RightToLeft`]: Direction::RightToLeft
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right, as in English.
+    #[default]
+    LeftToRight,
+
+    /// Content flows from right to left, as in Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Returns the [`Direction`] opposite to this one.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::LeftToRight => Self::RightToLeft,
+            Self::RightToLeft => Self::LeftToRight,
+        }
+    }
+
+    /// Resolves a logical horizontal [`Alignment`] to a physical one.
+    ///
+    /// [`Alignment::Start`] is the left side in a left-to-right layout and
+    /// the right side in a right-to-left one.
+    pub fn align(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (Self::LeftToRight, alignment)
+            | (Self::RightToLeft, alignment @ Alignment::Center) => alignment,
+            (Self::RightToLeft, Alignment::Start) => Alignment::End,
+            (Self::RightToLeft, Alignment::End) => Alignment::Start,
+        }
+    }
+}
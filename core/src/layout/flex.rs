@@ -58,6 +58,8 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// The layout is mirrored if the [`Limits`] have a right-to-left direction.
+///
 /// It returns a new layout [`Node`].
 pub fn resolve<Message, Theme, Renderer>(
     axis: Axis,
@@ -108,7 +110,8 @@ where
             );
 
             let child_limits =
-                Limits::new(Size::ZERO, Size::new(max_width, max_height));
+                Limits::new(Size::ZERO, Size::new(max_width, max_height))
+                    .with_direction(limits.direction());
 
             let layout =
                 child.as_widget().layout(tree, renderer, &child_limits);
@@ -164,7 +167,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout =
                 child.as_widget().layout(tree, renderer, &child_limits);
@@ -215,5 +219,15 @@ where
         Size::new(intrinsic_width, intrinsic_height),
     );
 
-    Node::with_children(size.expand(padding), nodes)
+    let size = size.expand(padding);
+
+    // Right-to-left layouts are mirrored; this flips the order of the items
+    // in a row and the alignment of the items in a column
+    if limits.direction().is_rtl() {
+        for node in &mut nodes {
+            node.mirror_mut(size.width);
+        }
+    }
+
+    Node::with_children(size, nodes)
 }
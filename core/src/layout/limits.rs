@@ -1,11 +1,16 @@
 #![allow(clippy::manual_clamp)]
+use crate::layout::Direction;
 use crate::{Length, Size};
 
 /// A set of size constraints for layouting.
+///
+/// [`Limits`] also carry the [`Direction`] of the layout, so it can flow
+/// down to every widget.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Limits {
     min: Size,
     max: Size,
+    direction: Direction,
 }
 
 impl Limits {
@@ -13,11 +18,27 @@ impl Limits {
     pub const NONE: Limits = Limits {
         min: Size::ZERO,
         max: Size::INFINITY,
+        direction: Direction::LeftToRight,
     };
 
     /// Creates new [`Limits`] with the given minimum and maximum [`Size`].
     pub const fn new(min: Size, max: Size) -> Limits {
-        Limits { min, max }
+        Limits {
+            min,
+            max,
+            direction: Direction::LeftToRight,
+        }
+    }
+
+    /// Sets the [`Direction`] of the [`Limits`].
+    pub const fn with_direction(mut self, direction: Direction) -> Limits {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the [`Direction`] of the [`Limits`].
+    pub fn direction(&self) -> Direction {
+        self.direction
     }
 
     /// Returns the minimum [`Size`] of the [`Limits`].
@@ -103,7 +124,11 @@ impl Limits {
             (self.max().height - size.height).max(0.0),
         );
 
-        Limits { min, max }
+        Limits {
+            min,
+            max,
+            direction: self.direction,
+        }
     }
 
     /// Removes the minimum width constraint for the current [`Limits`].
@@ -111,6 +136,7 @@ impl Limits {
         Limits {
             min: Size::ZERO,
             max: self.max,
+            direction: self.direction,
         }
     }
 
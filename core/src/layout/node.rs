@@ -102,6 +102,17 @@ impl Node {
         self.bounds.y = position.y;
     }
 
+    /// Mirrors the [`Node`] horizontally inside a parent of the given width.
+    pub fn mirror(mut self, width: f32) -> Self {
+        self.mirror_mut(width);
+        self
+    }
+
+    /// Mutable reference version of [`Self::mirror`].
+    pub fn mirror_mut(&mut self, width: f32) {
+        self.bounds.x = width - self.bounds.x - self.bounds.width;
+    }
+
     /// Translates the [`Node`] by the given translation.
     pub fn translate(self, translation: impl Into<Vector>) -> Self {
         let translation = translation.into();
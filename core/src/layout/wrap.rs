@@ -0,0 +1,141 @@
+//! Distribute elements in lines that wrap when they run out of space.
+use crate::alignment::Justify;
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Element, Length, Padding, Point, Size};
+
+use std::ops::Range;
+
+/// Computes a wrapping layout with the given limits, placing the items
+/// horizontally and moving them to a new line when they run out of space.
+///
+/// The items of each line are aligned vertically with `align_items` and
+/// distributed horizontally with `justify`. Lines start on the right if the
+/// [`Limits`] have a right-to-left direction.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    line_spacing: f32,
+    align_items: Alignment,
+    justify: Justify,
+    items: &[Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let child_limits = limits.loose();
+    let max_width = limits.max().width;
+
+    let mut nodes = Vec::with_capacity(items.len());
+    let mut lines = Vec::new();
+    let mut line = Line::starting_at(0);
+
+    for (child, tree) in items.iter().zip(trees) {
+        let node = child.as_widget().layout(tree, renderer, &child_limits);
+        let size = node.size();
+
+        if !line.is_empty() && line.width + spacing + size.width > max_width {
+            let next = Line::starting_at(line.items.end);
+
+            lines.push(std::mem::replace(&mut line, next));
+        }
+
+        if !line.is_empty() {
+            line.width += spacing;
+        }
+
+        line.width += size.width;
+        line.height = line.height.max(size.height);
+        line.items.end += 1;
+
+        nodes.push(node);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    let intrinsic_width =
+        lines.iter().map(|line| line.width).fold(0.0, f32::max);
+    let intrinsic_height = lines.iter().map(|line| line.height).sum::<f32>()
+        + line_spacing * lines.len().saturating_sub(1) as f32;
+
+    let size = limits.resolve(
+        width,
+        height,
+        Size::new(intrinsic_width, intrinsic_height),
+    );
+
+    let mut y = padding.top;
+
+    for line in &lines {
+        let free = (size.width - line.width).max(0.0);
+        let count = line.items.len() as f32;
+
+        let (offset, gap) = match justify {
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free / 2.0, 0.0),
+            Justify::End => (free, 0.0),
+            Justify::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (free / count / 2.0, free / count),
+            Justify::SpaceEvenly => {
+                (free / (count + 1.0), free / (count + 1.0))
+            }
+        };
+
+        let mut x = padding.left + offset;
+
+        for node in &mut nodes[line.items.clone()] {
+            node.move_to_mut(Point::new(x, y));
+            node.align_mut(
+                Alignment::Start,
+                align_items,
+                Size::new(0.0, line.height),
+            );
+
+            x += node.size().width + spacing + gap;
+        }
+
+        y += line.height + line_spacing;
+    }
+
+    let size = size.expand(padding);
+
+    if limits.direction().is_rtl() {
+        for node in &mut nodes {
+            node.mirror_mut(size.width);
+        }
+    }
+
+    Node::with_children(size, nodes)
+}
+
+/// A line of items in a wrapping layout.
+struct Line {
+    items: Range<usize>,
+    width: f32,
+    height: f32,
+}
+
+impl Line {
+    fn starting_at(index: usize) -> Self {
+        Self {
+            items: index..index,
+            width: 0.0,
+            height: 0.0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
@@ -9,19 +9,22 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
-#![forbid(unsafe_code, rust_2018_idioms)]
+#![forbid(unsafe_code)]
 #![deny(
+    rust_2018_idioms,
     missing_debug_implementations,
     missing_docs,
     unused_results,
     rustdoc::broken_intra_doc_links
 )]
 pub mod alignment;
+pub mod animation;
 pub mod border;
 pub mod clipboard;
 pub mod event;
 pub mod font;
 pub mod gradient;
+pub mod i18n;
 pub mod image;
 pub mod keyboard;
 pub mod layout;
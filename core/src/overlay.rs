@@ -23,8 +23,17 @@ where
     /// This [`Node`] is used by the runtime to compute the [`Layout`] of the
     /// user interface.
     ///
+    /// Overlays should lay out their contents in the given
+    /// [`layout::Direction`], which is the direction of the user interface
+    /// they are displayed on.
+    ///
     /// [`Node`]: layout::Node
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node;
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node;
 
     /// Draws the [`Overlay`] using the associated `Renderer`.
     fn draw(
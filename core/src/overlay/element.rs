@@ -47,8 +47,9 @@ where
         &mut self,
         renderer: &Renderer,
         bounds: Size,
+        direction: layout::Direction,
     ) -> layout::Node {
-        self.overlay.layout(renderer, bounds)
+        self.overlay.layout(renderer, bounds, direction)
     }
 
     /// Processes a runtime [`Event`].
@@ -138,8 +139,13 @@ impl<'a, A, B, Theme, Renderer> Overlay<B, Theme, Renderer>
 where
     Renderer: crate::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        self.content.layout(renderer, bounds)
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        self.content.layout(renderer, bounds, direction)
     }
 
     fn operate(
@@ -166,6 +172,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
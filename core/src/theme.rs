@@ -1,8 +1,19 @@
 //! Use the built-in theme and styles.
 pub mod palette;
 
+mod class;
+mod overrides;
+
+#[cfg(feature = "theme-file")]
+mod file;
+
+pub use class::{with_classes, Class, Classes};
+pub use overrides::{Overridable, Override, Overrides, Properties, Status};
 pub use palette::Palette;
 
+#[cfg(feature = "theme-file")]
+pub use file::Error;
+
 use std::fmt;
 use std::sync::Arc;
 
@@ -158,6 +169,60 @@ impl Theme {
             Self::Custom(custom) => &custom.extended,
         }
     }
+
+    /// Returns the widget [`Overrides`] of the [`Theme`].
+    ///
+    /// Only [`Custom`] themes can have overrides.
+    pub fn overrides(&self) -> &Overrides {
+        match self {
+            Self::Custom(custom) => &custom.overrides,
+            _ => &Overrides::NONE,
+        }
+    }
+
+    /// Applies the [`Override`] of the widget of the given appearance, if
+    /// the [`Theme`] has one.
+    ///
+    /// The built-in style functions of the overridable widgets call this on
+    /// the appearance they produce.
+    pub fn overridden<A>(&self, appearance: A, status: impl Into<Status>) -> A
+    where
+        A: Overridable,
+    {
+        match A::select(self.overrides()) {
+            Some(overrides) => {
+                appearance.apply(overrides.resolve(status.into()))
+            }
+            None => appearance,
+        }
+    }
+
+    /// Returns the style [`Classes`] registered in the [`Theme`].
+    ///
+    /// Only [`Custom`] themes can have classes.
+    pub fn classes(&self) -> &Classes {
+        match self {
+            Self::Custom(custom) => &custom.classes,
+            _ => &Classes::EMPTY,
+        }
+    }
+
+    /// Resolves the given [`Class`] for a widget with the given status and
+    /// appearance types, if a style is registered for it.
+    ///
+    /// The [`Classes`] of any [`with_classes`] scope are looked up first,
+    /// from the innermost one, and the ones of the [`Theme`] last.
+    pub fn resolve_class<Status, Appearance>(
+        &self,
+        class: &Class,
+        status: Status,
+    ) -> Option<Appearance>
+    where
+        Status: 'static,
+        Appearance: 'static,
+    {
+        class::resolve(self, class, status)
+    }
 }
 
 impl Default for Theme {
@@ -218,6 +283,8 @@ pub struct Custom {
     name: String,
     palette: Palette,
     extended: palette::Extended,
+    overrides: Overrides,
+    classes: Classes,
 }
 
 impl Custom {
@@ -237,8 +304,22 @@ impl Custom {
             name,
             palette,
             extended: generate(palette),
+            overrides: Overrides::default(),
+            classes: Classes::default(),
         }
     }
+
+    /// Sets the widget [`Overrides`] of the [`Custom`] theme.
+    pub fn overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Sets the style [`Classes`] of the [`Custom`] theme.
+    pub fn classes(mut self, classes: Classes) -> Self {
+        self.classes = classes;
+        self
+    }
 }
 
 impl fmt::Display for Custom {
@@ -246,3 +327,110 @@ impl fmt::Display for Custom {
         write!(f, "{}", self.name)
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{palette, Custom, Overrides, Palette, Theme};
+
+    use serde::de::{self, MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::sync::Arc;
+
+    /// The serialized form of a [`Custom`] theme.
+    ///
+    /// The [`palette::Extended`] is generated from the [`Palette`] unless it
+    /// is given explicitly.
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Definition {
+        name: String,
+        palette: Palette,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        extended: Option<palette::Extended>,
+        #[serde(flatten)]
+        overrides: Overrides,
+    }
+
+    impl Serialize for Theme {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Theme::Custom(custom) => {
+                    let generated = palette::Extended::generate(custom.palette);
+
+                    Definition {
+                        name: custom.name.clone(),
+                        palette: custom.palette,
+                        extended: (custom.extended != generated)
+                            .then_some(custom.extended),
+                        overrides: custom.overrides,
+                    }
+                    .serialize(serializer)
+                }
+                built_in => serializer.collect_str(built_in),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Theme {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ThemeVisitor)
+        }
+    }
+
+    /// Deserializes either the name of a built-in [`Theme`] or the
+    /// [`Definition`] of a custom one.
+    ///
+    /// Unlike an untagged enum, it keeps the errors of a [`Definition`]
+    /// intact, so they point at the invalid field.
+    struct ThemeVisitor;
+
+    impl<'de> Visitor<'de> for ThemeVisitor {
+        type Value = Theme;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("the name of a built-in theme or a custom theme")
+        }
+
+        fn visit_str<E>(self, name: &str) -> Result<Theme, E>
+        where
+            E: de::Error,
+        {
+            Theme::ALL
+                .iter()
+                .find(|theme| theme.to_string() == name)
+                .cloned()
+                .ok_or_else(|| {
+                    E::custom(format!("unknown built-in theme: {name:?}"))
+                })
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Theme, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let definition = Definition::deserialize(
+                de::value::MapAccessDeserializer::new(map),
+            )?;
+
+            let custom = match definition.extended {
+                Some(extended) => {
+                    Custom::with_fn(definition.name, definition.palette, |_| {
+                        extended
+                    })
+                }
+                None => Custom::new(definition.name, definition.palette),
+            };
+
+            Ok(Theme::Custom(Arc::new(
+                custom.overrides(definition.overrides),
+            )))
+        }
+    }
+}
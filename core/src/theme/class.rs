@@ -0,0 +1,330 @@
+use crate::Theme;
+
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// The name of a style class.
+///
+/// Widgets can be given a [`Class`] instead of a style function; the
+/// class is then resolved by the `Theme` every time the widget is drawn.
+///
+/// Implement `From<T> for Class` to use your own enum as a class.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Class(Cow<'static, str>);
+
+impl Class {
+    /// Creates a new [`Class`] with the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the name of the [`Class`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for Class {
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for Class {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A style function of a widget that can be registered as a class.
+type Style<Status, Appearance> =
+    Box<dyn Fn(&Theme, Status) -> Appearance + Send + Sync>;
+
+/// A registered style function, before being downcast to its [`Style`].
+type Erased = Arc<dyn Any + Send + Sync>;
+
+thread_local! {
+    /// The [`Classes`] in scope, innermost last.
+    static SCOPED: RefCell<Vec<Classes>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A set of style classes registered by an application.
+///
+/// A class is registered for a specific kind of widget, identified by the
+/// signature of its style function; the same name can be registered for
+/// different kinds of widgets.
+///
+/// ```no_run
+/// # mod button {
+/// #     #[derive(Clone, Copy)] pub enum Status { Active }
+/// #     pub struct Appearance;
+/// #     pub fn danger(_theme: &iced_core::Theme, _status: Status) -> Appearance { Appearance }
+/// # }
+/// use iced_core::theme::{Classes, Theme};
+///
+/// let classes = Classes::new().register("destructive", button::danger);
+/// ```
+#[derive(Clone, Default)]
+pub struct Classes {
+    styles: Option<Arc<HashMap<(TypeId, Class), Erased>>>,
+}
+
+impl Classes {
+    /// An empty set of [`Classes`].
+    pub const EMPTY: Self = Self { styles: None };
+
+    /// Creates an empty set of [`Classes`].
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Registers a style function under the given [`Class`].
+    ///
+    /// The function will be used by the widgets whose style has the same
+    /// signature.
+    pub fn register<Status, Appearance>(
+        mut self,
+        class: impl Into<Class>,
+        style: impl Fn(&Theme, Status) -> Appearance + Send + Sync + 'static,
+    ) -> Self
+    where
+        Status: 'static,
+        Appearance: 'static,
+    {
+        let style: Style<Status, Appearance> = Box::new(style);
+
+        let _ = Arc::make_mut(self.styles.get_or_insert_with(Arc::default))
+            .insert(
+                (TypeId::of::<Style<Status, Appearance>>(), class.into()),
+                Arc::new(style),
+            );
+
+        self
+    }
+
+    /// Returns the style function registered under the given [`Class`] for
+    /// the widgets with the given signature, if any.
+    pub fn get<Status, Appearance>(
+        &self,
+        class: &Class,
+    ) -> Option<&(dyn Fn(&Theme, Status) -> Appearance + Send + Sync)>
+    where
+        Status: 'static,
+        Appearance: 'static,
+    {
+        let styles = self.styles.as_ref()?;
+        let key = (TypeId::of::<Style<Status, Appearance>>(), class.clone());
+
+        styles
+            .get(&key)?
+            .downcast_ref::<Style<Status, Appearance>>()
+            .map(AsRef::as_ref)
+    }
+
+    /// Returns the erased style function registered under the given
+    /// [`Class`] for the widgets with the given signature, if any.
+    fn erased<Status, Appearance>(&self, class: &Class) -> Option<Erased>
+    where
+        Status: 'static,
+        Appearance: 'static,
+    {
+        let styles = self.styles.as_ref()?;
+        let key = (TypeId::of::<Style<Status, Appearance>>(), class.clone());
+
+        styles.get(&key).cloned()
+    }
+
+    /// Returns true if no classes are registered.
+    pub fn is_empty(&self) -> bool {
+        self.styles.as_ref().is_none_or(|styles| styles.is_empty())
+    }
+
+    /// Layers the given [`Classes`] on top of these ones.
+    ///
+    /// Classes registered in both sets are taken from `other`.
+    pub fn merge(mut self, other: &Self) -> Self {
+        match (&mut self.styles, &other.styles) {
+            (_, None) => {}
+            (None, Some(_)) => {
+                self.styles.clone_from(&other.styles);
+            }
+            (Some(styles), Some(other)) => {
+                Arc::make_mut(styles).extend(
+                    other
+                        .iter()
+                        .map(|(key, style)| (key.clone(), Arc::clone(style))),
+                );
+            }
+        }
+
+        self
+    }
+}
+
+/// Runs the given closure with the given [`Classes`] layered on top of the
+/// ones of the [`Theme`] and of any outer scope.
+///
+/// The `classes` widget calls this while processing its contents.
+pub fn with_classes<T>(classes: &Classes, f: impl FnOnce() -> T) -> T {
+    struct Scoped;
+
+    impl Drop for Scoped {
+        fn drop(&mut self) {
+            let _ = SCOPED.with(|scoped| scoped.borrow_mut().pop());
+        }
+    }
+
+    SCOPED.with(|scoped| scoped.borrow_mut().push(classes.clone()));
+
+    let _scoped = Scoped;
+
+    f()
+}
+
+/// Resolves the given [`Class`] with the style function registered for it,
+/// looking at the innermost [`with_classes`] scope first and at the
+/// [`Theme`] last.
+pub(super) fn resolve<Status, Appearance>(
+    theme: &Theme,
+    class: &Class,
+    status: Status,
+) -> Option<Appearance>
+where
+    Status: 'static,
+    Appearance: 'static,
+{
+    let scoped = SCOPED.with(|scoped| {
+        scoped
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|classes| classes.erased::<Status, Appearance>(class))
+    });
+
+    // The scope is released before styling, since styles may resolve other
+    // classes themselves
+    let style = scoped
+        .or_else(|| theme.classes().erased::<Status, Appearance>(class))?;
+
+    style
+        .downcast_ref::<Style<Status, Appearance>>()
+        .map(|style| style(theme, status))
+}
+
+impl fmt::Debug for Classes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                self.styles
+                    .iter()
+                    .flat_map(|styles| styles.keys())
+                    .map(|(_, class)| class),
+            )
+            .finish()
+    }
+}
+
+impl PartialEq for Classes {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.styles, &other.styles) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => self.is_empty() && other.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn red(_theme: &Theme, _status: u8) -> &'static str {
+        "red"
+    }
+
+    fn blue(_theme: &Theme, _status: u8) -> &'static str {
+        "blue"
+    }
+
+    #[test]
+    fn classes_are_registered_per_signature() {
+        let classes = Classes::new().register("accent", red);
+        let class = Class::from("accent");
+
+        let style = classes.get::<u8, &'static str>(&class).unwrap();
+
+        assert_eq!(style(&Theme::Dark, 0), "red");
+        assert!(classes.get::<u16, &'static str>(&class).is_none());
+        assert!(classes.get::<u8, &'static str>(&"other".into()).is_none());
+    }
+
+    #[test]
+    fn scoped_classes_take_precedence() {
+        let base = Classes::new().register("accent", red).register("a", red);
+        let layer = Classes::new().register("accent", blue);
+
+        let theme = Theme::Dark;
+        let resolve = |class: &'static str| {
+            theme.resolve_class::<u8, &'static str>(&class.into(), 0)
+        };
+
+        with_classes(&base, || {
+            with_classes(&layer, || {
+                assert_eq!(resolve("accent"), Some("blue"));
+                assert_eq!(resolve("a"), Some("red"));
+            });
+
+            assert_eq!(resolve("accent"), Some("red"));
+        });
+
+        assert_eq!(resolve("accent"), None);
+    }
+
+    #[test]
+    fn scoped_classes_fall_back_to_the_theme() {
+        let theme = Theme::Custom(Arc::new(
+            crate::theme::Custom::new(
+                "Ocean".to_owned(),
+                Theme::Nord.palette(),
+            )
+            .classes(Classes::new().register("accent", red)),
+        ));
+
+        let layer = Classes::new().register("other", blue);
+
+        with_classes(&layer, || {
+            assert_eq!(
+                theme.resolve_class::<u8, &'static str>(&"accent".into(), 0),
+                Some("red")
+            );
+        });
+    }
+
+    #[test]
+    fn classes_without_a_status_are_registered_with_a_unit_status() {
+        use crate::widget::text::{self, Catalog};
+        use crate::Color;
+
+        let classes =
+            Classes::new().register("muted", |_theme, ()| text::Appearance {
+                color: Some(Color::BLACK),
+            });
+
+        with_classes(&classes, || {
+            assert_eq!(
+                Theme::Dark.resolve(&"muted".into()).and_then(|a| a.color),
+                Some(Color::BLACK)
+            );
+            assert!(Theme::Dark.resolve(&"other".into()).is_none());
+        });
+    }
+}
@@ -0,0 +1,219 @@
+use crate::Theme;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+impl Theme {
+    /// Loads a [`Theme`] from the file at the given path.
+    ///
+    /// Files with a `json` extension are parsed as JSON; anything else is
+    /// parsed as TOML.
+    ///
+    /// A theme file defines a `name`, a `palette` with the `background`,
+    /// `text`, `primary`, `success` and `danger` colors in hex notation and,
+    /// optionally, an `extended` palette and per-widget [`Overrides`]:
+    ///
+    /// ```toml
+    /// name = "Ocean"
+    ///
+    /// [palette]
+    /// background = "#0b1d2a"
+    /// text = "#e0f0ff"
+    /// primary = "#2aa1d8"
+    /// success = "#3ad29f"
+    /// danger = "#ff5d5d"
+    ///
+    /// [button]
+    /// border_radius = 8.0
+    ///
+    /// [button.hovered]
+    /// background = "#46b6ea"
+    /// ```
+    ///
+    /// Combined with a filesystem watching subscription, this can be used to
+    /// reload a [`Theme`] while the application runs.
+    ///
+    /// [`Overrides`]: super::Overrides
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Self::from_json(&contents)
+        } else {
+            Self::from_toml(&contents)
+        }
+    }
+
+    /// Saves the [`Theme`] to the file at the given path.
+    ///
+    /// Like [`Theme::load`], the format is picked from the extension of the
+    /// file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        let contents = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            self.to_json()?
+        } else {
+            self.to_toml()?
+        };
+
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
+
+    /// Parses a [`Theme`] from TOML.
+    pub fn from_toml(contents: &str) -> Result<Self, Error> {
+        toml::from_str(contents)
+            .map_err(|error| Error::InvalidFormat(error.to_string()))
+    }
+
+    /// Parses a [`Theme`] from JSON.
+    pub fn from_json(contents: &str) -> Result<Self, Error> {
+        serde_json::from_str(contents)
+            .map_err(|error| Error::InvalidFormat(error.to_string()))
+    }
+
+    /// Serializes the [`Theme`] as TOML.
+    ///
+    /// Built-in themes are not TOML documents on their own and cannot be
+    /// serialized with this method.
+    pub fn to_toml(&self) -> Result<String, Error> {
+        toml::to_string_pretty(self)
+            .map_err(|error| Error::InvalidFormat(error.to_string()))
+    }
+
+    /// Serializes the [`Theme`] as JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self)
+            .map_err(|error| Error::InvalidFormat(error.to_string()))
+    }
+}
+
+/// An error while saving or loading a [`Theme`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read or written.
+    #[error("the theme file could not be accessed: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The contents of the file are not a valid [`Theme`].
+    #[error("the theme is invalid: {0}")]
+    InvalidFormat(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{Override, Overrides, Palette, Properties};
+    use crate::Color;
+
+    #[test]
+    fn custom_themes_round_trip() {
+        let theme = Theme::Custom(Arc::new(
+            crate::theme::Custom::new("Ocean".to_owned(), Palette::NORD)
+                .overrides(Overrides {
+                    button: Some(Override {
+                        active: Properties {
+                            border_radius: Some(8.0),
+                            ..Properties::default()
+                        },
+                        hovered: Properties {
+                            background: Some(Color::from_rgb8(
+                                0x46, 0xb6, 0xea,
+                            )),
+                            ..Properties::default()
+                        },
+                        ..Override::default()
+                    }),
+                    ..Overrides::default()
+                }),
+        ));
+
+        let toml = theme.to_toml().unwrap();
+        let json = theme.to_json().unwrap();
+
+        assert_eq!(Theme::from_toml(&toml).unwrap(), theme);
+        assert_eq!(Theme::from_json(&json).unwrap(), theme);
+    }
+
+    #[test]
+    fn built_in_themes_are_referenced_by_name() {
+        for theme in Theme::ALL {
+            let json = theme.to_json().unwrap();
+
+            assert_eq!(&Theme::from_json(&json).unwrap(), theme);
+        }
+    }
+
+    #[test]
+    fn invalid_colors_are_rejected() {
+        let toml = r##"
+            name = "Broken"
+
+            [palette]
+            background = "#000"
+            text = "#fff"
+            primary = "blue"
+            success = "#0f0"
+            danger = "#f00"
+        "##;
+
+        let Err(Error::InvalidFormat(message)) = Theme::from_toml(toml) else {
+            panic!("invalid colors must be rejected");
+        };
+
+        assert!(message.contains("line 7"), "{message}");
+        assert!(message.contains("invalid hex color: \"blue\""), "{message}");
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let palette = r##"
+            name = "Typo"
+
+            [palette]
+            background = "#000"
+            text = "#fff"
+            primary = "#00f"
+            success = "#0f0"
+            danger = "#f00"
+        "##;
+
+        assert!(Theme::from_toml(palette).is_ok());
+
+        for (typo, field) in [
+            ("[buton]\nborder_radius = 8.0", "buton"),
+            ("[button]\nborder_raidus = 8.0", "border_raidus"),
+            ("[button.hoverd]\nbackground = \"#fff\"", "hoverd"),
+            ("[button.hovered]\nbackgroud = \"#fff\"", "backgroud"),
+        ] {
+            let toml = format!("{palette}\n{typo}");
+
+            let Err(Error::InvalidFormat(message)) = Theme::from_toml(&toml)
+            else {
+                panic!("unknown field {field:?} must be rejected");
+            };
+
+            assert!(
+                message.contains(&format!("unknown field `{field}`")),
+                "{message}"
+            );
+        }
+    }
+}
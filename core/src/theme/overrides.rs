@@ -0,0 +1,235 @@
+use crate::{Background, Border, Color};
+
+/// The style overrides of the built-in widgets of a [`Theme`].
+///
+/// The default style of a widget is computed as usual from the palette of
+/// the [`Theme`], and then patched with the [`Override`] of its kind, if any.
+///
+/// [`Theme`]: super::Theme
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Overrides {
+    /// The [`Override`] of buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub button: Option<Override>,
+    /// The [`Override`] of checkboxes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub checkbox: Option<Override>,
+    /// The [`Override`] of containers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub container: Option<Override>,
+    /// The [`Override`] of pick lists.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub pick_list: Option<Override>,
+    /// The [`Override`] of radio buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub radio: Option<Override>,
+    /// The [`Override`] of text inputs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub text_input: Option<Override>,
+    /// The [`Override`] of togglers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub toggler: Option<Override>,
+}
+
+impl Overrides {
+    /// No overrides at all.
+    pub const NONE: Self = Self {
+        button: None,
+        checkbox: None,
+        container: None,
+        pick_list: None,
+        radio: None,
+        text_input: None,
+        toggler: None,
+    };
+}
+
+/// The style overrides of a kind of widget.
+///
+/// The [`Properties`] of the active status apply to every status, and the
+/// ones of the other statuses are layered on top.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Override {
+    /// The [`Properties`] of every status.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub active: Properties,
+    /// The [`Properties`] of a hovered widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Properties::is_empty")
+    )]
+    pub hovered: Properties,
+    /// The [`Properties`] of a pressed widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Properties::is_empty")
+    )]
+    pub pressed: Properties,
+    /// The [`Properties`] of a focused widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Properties::is_empty")
+    )]
+    pub focused: Properties,
+    /// The [`Properties`] of a disabled widget.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Properties::is_empty")
+    )]
+    pub disabled: Properties,
+}
+
+impl Override {
+    /// Returns the [`Properties`] that apply to the given [`Status`].
+    pub fn resolve(&self, status: Status) -> Properties {
+        let specific = match status {
+            Status::Active => return self.active,
+            Status::Hovered => self.hovered,
+            Status::Pressed => self.pressed,
+            Status::Focused => self.focused,
+            Status::Disabled => self.disabled,
+        };
+
+        self.active.merge(specific)
+    }
+}
+
+/// The appearance of a built-in widget that a [`Theme`] can override.
+///
+/// The statuses of the widget are mapped to a [`Status`] with a [`From`]
+/// implementation, and [`Theme::overridden`] layers the [`Properties`] of
+/// the matching [`Override`] on top of an appearance.
+///
+/// [`Theme`]: super::Theme
+/// [`Theme::overridden`]: super::Theme::overridden
+pub trait Overridable {
+    /// Returns the [`Override`] of the widget in the given [`Overrides`],
+    /// if any.
+    fn select(overrides: &Overrides) -> Option<&Override>;
+
+    /// Layers the given [`Properties`] on top of the appearance.
+    fn apply(self, properties: Properties) -> Self;
+}
+
+/// The status of a widget, as far as an [`Override`] is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The widget can be interacted with.
+    Active,
+    /// The widget is being hovered.
+    Hovered,
+    /// The widget is being pressed.
+    Pressed,
+    /// The widget has keyboard focus.
+    Focused,
+    /// The widget cannot be interacted with.
+    Disabled,
+}
+
+/// A set of optional style properties shared by most widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Properties {
+    /// The background [`Color`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub background: Option<Color>,
+    /// The text [`Color`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub text_color: Option<Color>,
+    /// The border [`Color`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub border_color: Option<Color>,
+    /// The border width.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub border_width: Option<f32>,
+    /// The radius of every corner of the border.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub border_radius: Option<f32>,
+}
+
+impl Properties {
+    /// Returns true if none of the [`Properties`] are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Layers the given [`Properties`] on top of these ones.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            background: other.background.or(self.background),
+            text_color: other.text_color.or(self.text_color),
+            border_color: other.border_color.or(self.border_color),
+            border_width: other.border_width.or(self.border_width),
+            border_radius: other.border_radius.or(self.border_radius),
+        }
+    }
+
+    /// Applies the [`Properties`] to the given [`Background`].
+    pub fn background(&self, background: Background) -> Background {
+        self.background.map(Background::Color).unwrap_or(background)
+    }
+
+    /// Applies the [`Properties`] to the given text [`Color`].
+    pub fn text_color(&self, color: Color) -> Color {
+        self.text_color.unwrap_or(color)
+    }
+
+    /// Applies the [`Properties`] to the given [`Border`].
+    pub fn border(&self, border: Border) -> Border {
+        Border {
+            color: self.border_color.unwrap_or(border.color),
+            width: self.border_width.unwrap_or(border.width),
+            radius: self.border_radius.map(Into::into).unwrap_or(border.radius),
+        }
+    }
+}
@@ -8,6 +8,7 @@ use palette::{FromColor, Hsl, Mix};
 
 /// A color palette.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Palette {
     /// The background [`Color`] of the [`Palette`].
     pub background: Color,
@@ -291,6 +292,7 @@ impl Palette {
 
 /// An extended set of colors generated from a [`Palette`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Extended {
     /// The set of background colors.
     pub background: Background,
@@ -422,6 +424,7 @@ impl Extended {
 
 /// A pair of background and text colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pair {
     /// The background color.
     pub color: Color,
@@ -446,6 +449,7 @@ impl Pair {
 
 /// A set of background colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Background {
     /// The base background color.
     pub base: Pair,
@@ -471,6 +475,7 @@ impl Background {
 
 /// A set of primary colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Primary {
     /// The base primary color.
     pub base: Pair,
@@ -496,6 +501,7 @@ impl Primary {
 
 /// A set of secondary colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Secondary {
     /// The base secondary color.
     pub base: Pair,
@@ -522,6 +528,7 @@ impl Secondary {
 
 /// A set of success colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Success {
     /// The base success color.
     pub base: Pair,
@@ -547,6 +554,7 @@ impl Success {
 
 /// A set of danger colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Danger {
     /// The base danger color.
     pub base: Pair,
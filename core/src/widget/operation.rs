@@ -28,6 +28,13 @@ pub trait Operation<T> {
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
     );
 
+    /// Reports the bounds of a widget.
+    ///
+    /// Widgets call this right before operating on their own state, like in
+    /// [`focusable`](Self::focusable), so operations can find out where
+    /// that state is.
+    fn bounds(&mut self, _id: Option<&Id>, _bounds: Rectangle) {}
+
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
@@ -132,6 +139,10 @@ where
                     self.operation.scrollable(state, id, bounds, translation);
                 }
 
+                fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+                    self.operation.bounds(id, bounds);
+                }
+
                 fn focusable(
                     &mut self,
                     state: &mut dyn Focusable,
@@ -161,6 +172,10 @@ where
             .container(id, bounds, operate_on_children);
         }
 
+        fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.operation.bounds(id, bounds);
+        }
+
         fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
             self.operation.focusable(state, id);
         }
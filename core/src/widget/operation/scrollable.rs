@@ -1,4 +1,6 @@
 //! Operate on widgets that can be scrolled.
+use crate::animation::Transition;
+use crate::widget::operation::{Focusable, Outcome};
 use crate::widget::{Id, Operation};
 use crate::{Rectangle, Vector};
 
@@ -9,74 +11,386 @@ pub trait Scrollable {
 
     /// Scroll the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis.
     fn scroll_to(&mut self, offset: AbsoluteOffset);
+
+    /// Animates the scroll of the widget towards the given `percentage` with
+    /// the given [`Transition`].
+    ///
+    /// By default, the widget snaps to the offset immediately.
+    fn snap_to_animated(
+        &mut self,
+        offset: RelativeOffset,
+        _transition: Transition,
+    ) {
+        self.snap_to(offset);
+    }
+
+    /// Animates the scroll of the widget towards the given [`AbsoluteOffset`]
+    /// with the given [`Transition`].
+    ///
+    /// By default, the widget scrolls to the offset immediately.
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset,
+        _transition: Transition,
+    ) {
+        self.scroll_to(offset);
+    }
+
+    /// Scrolls the widget so the given `target`, in the coordinates of its
+    /// contents, becomes visible with the given [`Placement`].
+    ///
+    /// It returns the visible part of the `target` afterwards, in the
+    /// coordinates of the widget itself, or `None` if the widget does not
+    /// support scrolling into view.
+    fn scroll_into_view(
+        &mut self,
+        _target: Rectangle,
+        _placement: Placement,
+    ) -> Option<Rectangle> {
+        None
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
 /// the provided `percentage`.
 pub fn snap_to<T>(target: Id, offset: RelativeOffset) -> impl Operation<T> {
-    struct SnapTo {
-        target: Id,
-        offset: RelativeOffset,
+    SnapTo {
+        target,
+        offset,
+        transition: None,
     }
+}
 
-    impl<T> Operation<T> for SnapTo {
-        fn container(
-            &mut self,
-            _id: Option<&Id>,
-            _bounds: Rectangle,
-            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
-        ) {
-            operate_on_children(self);
+/// Produces an [`Operation`] that animates the scroll of the widget with the
+/// given [`Id`] towards the provided `percentage`.
+pub fn snap_to_animated<T>(
+    target: Id,
+    offset: RelativeOffset,
+    transition: Transition,
+) -> impl Operation<T> {
+    SnapTo {
+        target,
+        offset,
+        transition: Some(transition),
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// the provided [`AbsoluteOffset`].
+pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
+    ScrollTo {
+        target,
+        offset,
+        transition: None,
+    }
+}
+
+/// Produces an [`Operation`] that animates the scroll of the widget with the
+/// given [`Id`] towards the provided [`AbsoluteOffset`].
+pub fn scroll_to_animated<T>(
+    target: Id,
+    offset: AbsoluteOffset,
+    transition: Transition,
+) -> impl Operation<T> {
+    ScrollTo {
+        target,
+        offset,
+        transition: Some(transition),
+    }
+}
+
+struct SnapTo {
+    target: Id,
+    offset: RelativeOffset,
+    transition: Option<Transition>,
+}
+
+impl<T> Operation<T> for SnapTo {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if Some(&self.target) == id {
+            match self.transition {
+                Some(transition) => {
+                    state.snap_to_animated(self.offset, transition);
+                }
+                None => state.snap_to(self.offset),
+            }
         }
+    }
+}
 
-        fn scrollable(
-            &mut self,
-            state: &mut dyn Scrollable,
-            id: Option<&Id>,
-            _bounds: Rectangle,
-            _translation: Vector,
-        ) {
-            if Some(&self.target) == id {
-                state.snap_to(self.offset);
+struct ScrollTo {
+    target: Id,
+    offset: AbsoluteOffset,
+    transition: Option<Transition>,
+}
+
+impl<T> Operation<T> for ScrollTo {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if Some(&self.target) == id {
+            match self.transition {
+                Some(transition) => {
+                    state.scroll_to_animated(self.offset, transition);
+                }
+                None => state.scroll_to(self.offset),
             }
         }
     }
+}
 
-    SnapTo { target, offset }
+/// Produces an [`Operation`] that scrolls every [`Scrollable`] enclosing the
+/// widget with the given [`Id`] so it becomes visible with the given
+/// [`Placement`].
+pub fn scroll_into_view<T: 'static>(
+    target: Id,
+    placement: Placement,
+) -> impl Operation<T> {
+    Find {
+        target: Target::Id(target),
+        placement,
+        depth: 0,
+        count: 0,
+        scrollables: Vec::new(),
+        last_bounds: None,
+        found: None,
+    }
 }
 
-/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
-/// the provided [`AbsoluteOffset`].
-pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
-    struct ScrollTo {
-        target: Id,
-        offset: AbsoluteOffset,
+/// Produces an [`Operation`] that scrolls every [`Scrollable`] enclosing the
+/// focused widget so it becomes visible with the given [`Placement`].
+pub fn scroll_focused_into_view<T: 'static>(
+    placement: Placement,
+) -> impl Operation<T> {
+    Find {
+        target: Target::Focused,
+        placement,
+        depth: 0,
+        count: 0,
+        scrollables: Vec::new(),
+        last_bounds: None,
+        found: None,
     }
+}
 
-    impl<T> Operation<T> for ScrollTo {
-        fn container(
-            &mut self,
-            _id: Option<&Id>,
-            _bounds: Rectangle,
-            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
-        ) {
-            operate_on_children(self);
+/// Where a widget is placed inside of a [`Scrollable`] when it is scrolled
+/// into view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// The widget is aligned to the start of the [`Scrollable`].
+    Start,
+    /// The widget is centered in the [`Scrollable`].
+    Center,
+    /// The widget is aligned to the end of the [`Scrollable`].
+    End,
+    /// The [`Scrollable`] is scrolled as little as possible; it does not
+    /// move at all if the widget is already visible.
+    #[default]
+    Nearest,
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Id(Id),
+    Focused,
+}
+
+/// Finds the bounds of the target and the indices of the scrollables that
+/// enclose it, in traversal order.
+struct Find {
+    target: Target,
+    placement: Placement,
+    depth: usize,
+    count: usize,
+    scrollables: Vec<(usize, usize)>,
+    last_bounds: Option<Rectangle>,
+    found: Option<(Rectangle, Vec<usize>)>,
+}
+
+impl Find {
+    fn is_target(&self, id: Option<&Id>) -> bool {
+        matches!(&self.target, Target::Id(target) if Some(target) == id)
+    }
+
+    fn found(&mut self, bounds: Rectangle) {
+        self.found = Some((
+            bounds,
+            self.scrollables.iter().map(|(index, _)| *index).collect(),
+        ));
+    }
+}
+
+impl<T: 'static> Operation<T> for Find {
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if self.is_target(id) {
+            self.found(bounds);
+            return;
+        }
+
+        self.scrollables.push((self.count, self.depth));
+        self.count += 1;
+    }
+
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if self.is_target(id) {
+            self.found(bounds);
+            return;
+        }
+
+        self.depth += 1;
+
+        operate_on_children(self);
+
+        self.depth -= 1;
+
+        match self.scrollables.last() {
+            Some((_, depth)) if self.depth == *depth => {
+                let _ = self.scrollables.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if self.found.is_some() {
+            return;
         }
 
-        fn scrollable(
-            &mut self,
-            state: &mut dyn Scrollable,
-            id: Option<&Id>,
-            _bounds: Rectangle,
-            _translation: Vector,
-        ) {
-            if Some(&self.target) == id {
-                state.scroll_to(self.offset);
+        if self.is_target(id) {
+            self.found(bounds);
+        } else {
+            self.last_bounds = Some(bounds);
+        }
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if let (Target::Focused, Some(bounds)) =
+            (&self.target, self.last_bounds.take())
+        {
+            if state.is_focused() {
+                self.found(bounds);
+            }
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match &self.found {
+            Some((target, scrollables)) if !scrollables.is_empty() => {
+                Outcome::Chain(Box::new(Reveal {
+                    target: *target,
+                    placement: self.placement,
+                    scrollables: scrollables.clone(),
+                    count: 0,
+                }))
             }
+            _ => Outcome::None,
         }
     }
+}
+
+/// Scrolls the innermost of the remaining scrollables and chains itself
+/// until all of them have been scrolled.
+struct Reveal {
+    target: Rectangle,
+    placement: Placement,
+    scrollables: Vec<usize>,
+    count: usize,
+}
 
-    ScrollTo { target, offset }
+impl<T: 'static> Operation<T> for Reveal {
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if self.scrollables.last() == Some(&self.count) {
+            self.target = state
+                .scroll_into_view(self.target, self.placement)
+                .unwrap_or(bounds);
+        }
+
+        self.count += 1;
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.scrollables.last() >= Some(&self.count) {
+            operate_on_children(self);
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        let mut scrollables = self.scrollables.clone();
+        let _ = scrollables.pop();
+
+        if scrollables.is_empty() {
+            Outcome::None
+        } else {
+            Outcome::Chain(Box::new(Reveal {
+                target: self.target,
+                placement: self.placement,
+                scrollables,
+                count: 0,
+            }))
+        }
+    }
 }
 
 /// The amount of absolute offset in each direction of a [`Scrollable`].
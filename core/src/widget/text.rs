@@ -4,6 +4,7 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text::{self, Paragraph};
+use crate::theme::Class;
 use crate::widget::tree::{self, Tree};
 use crate::{
     Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
@@ -81,6 +82,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`Text`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme| {
+            theme
+                .resolve(&class)
+                .unwrap_or_else(|| theme.default_style())
+        });
+        self
+    }
+
     /// Sets the [`Color`] of the [`Text`].
     pub fn color(self, color: impl Into<Color>) -> Self {
         self.color_maybe(Some(color))
@@ -336,6 +355,26 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of some [`Text`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Text`] has no status, so its [`Classes`] are registered with a `()`
+/// status; e.g. `|theme, ()| style(theme)`.
+///
+/// [`Classes`]: crate::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class) -> Option<Appearance> {
+        self.resolve_class(class, ())
+    }
+}
+
 impl DefaultStyle for Color {
     fn default_style(&self) -> Appearance {
         Appearance { color: Some(*self) }
@@ -70,17 +70,6 @@ impl App {
                 Command::none()
             }
             Message::Event(event) => match event {
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(key::Named::Tab),
-                    modifiers,
-                    ..
-                }) => {
-                    if modifiers.shift() {
-                        widget::focus_previous()
-                    } else {
-                        widget::focus_next()
-                    }
-                }
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     key: keyboard::Key::Named(key::Named::Escape),
                     ..
@@ -390,10 +379,12 @@ mod modal {
             &mut self,
             renderer: &Renderer,
             _bounds: Size,
+            direction: layout::Direction,
         ) -> layout::Node {
             let limits = layout::Limits::new(Size::ZERO, self.size)
                 .width(Length::Fill)
-                .height(Length::Fill);
+                .height(Length::Fill)
+                .with_direction(direction);
 
             let child = self
                 .content
@@ -1,17 +1,12 @@
-use iced::event::{self, Event};
-use iced::keyboard;
-use iced::keyboard::key;
 use iced::widget::{
-    self, button, column, container, pick_list, row, slider, text, text_input,
+    button, column, container, pick_list, row, slider, text, text_input,
 };
-use iced::{Alignment, Command, Element, Length, Subscription};
+use iced::{Alignment, Command, Element, Length};
 
 use toast::{Status, Toast};
 
 pub fn main() -> iced::Result {
-    iced::program("Toast - Iced", App::update, App::view)
-        .subscription(App::subscription)
-        .run()
+    iced::program("Toast - Iced", App::update, App::view).run()
 }
 
 struct App {
@@ -29,7 +24,6 @@ enum Message {
     Body(String),
     Status(Status),
     Timeout(f64),
-    Event(Event),
 }
 
 impl App {
@@ -45,10 +39,6 @@ impl App {
         }
     }
 
-    fn subscription(&self) -> Subscription<Message> {
-        event::listen().map(Message::Event)
-    }
-
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::Add => {
@@ -79,16 +69,6 @@ impl App {
                 self.timeout_secs = timeout as u64;
                 Command::none()
             }
-            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
-                key: keyboard::Key::Named(key::Named::Tab),
-                modifiers,
-                ..
-            })) if modifiers.shift() => widget::focus_previous(),
-            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
-                key: keyboard::Key::Named(key::Named::Tab),
-                ..
-            })) => widget::focus_next(),
-            Message::Event(_) => Command::none(),
         }
     }
 
@@ -476,8 +456,10 @@ mod toast {
             &mut self,
             renderer: &Renderer,
             bounds: Size,
+            direction: layout::Direction,
         ) -> layout::Node {
-            let limits = layout::Limits::new(Size::ZERO, bounds);
+            let limits = layout::Limits::new(Size::ZERO, bounds)
+                .with_direction(direction);
 
             layout::flex::resolve(
                 layout::flex::Axis::Vertical,
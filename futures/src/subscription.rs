@@ -1,8 +1,21 @@
 //! Listen to external events in your application.
 mod tracker;
 
+#[cfg(all(feature = "watch", not(target_arch = "wasm32")))]
+pub mod watch;
+
 pub use tracker::Tracker;
 
+#[cfg(all(feature = "watch", not(target_arch = "wasm32")))]
+pub use watch::watch_path;
+
+#[cfg(all(
+    feature = "watch",
+    feature = "theme-file",
+    not(target_arch = "wasm32")
+))]
+pub use watch::watch_theme;
+
 use crate::core::event::{self, Event};
 use crate::core::Hasher;
 use crate::futures::{Future, Stream};
@@ -0,0 +1,233 @@
+//! Watch the filesystem for changes.
+use crate::core::Hasher;
+use crate::subscription::{self, EventStream, Subscription};
+use crate::BoxStream;
+
+use notify_debouncer_full::notify;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A change in the filesystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A file or directory was created.
+    Created(PathBuf),
+
+    /// The contents or the metadata of a file or directory were modified.
+    Modified(PathBuf),
+
+    /// A file or directory was removed.
+    Removed(PathBuf),
+
+    /// A file or directory was renamed.
+    Renamed {
+        /// The path before the rename.
+        from: PathBuf,
+        /// The path after the rename.
+        to: PathBuf,
+    },
+
+    /// The watcher failed; it may have missed some changes.
+    Failed(String),
+}
+
+/// Returns a [`Subscription`] that watches the given path for changes.
+///
+/// If `recursive` is true, all the subdirectories of the path will be
+/// watched as well.
+///
+/// The [`Subscription`] is identified by the path and the `recursive` flag,
+/// so only one watcher will be kept alive for each of them no matter how many
+/// times it is requested. Watching the same path both recursively and
+/// non-recursively keeps two watchers, since each one produces a different
+/// set of events and sharing them would drop or leak subdirectory changes.
+///
+/// Events are debounced; a burst of changes to the same file produces a
+/// single [`Event`].
+pub fn watch_path(
+    path: impl Into<PathBuf>,
+    recursive: bool,
+) -> Subscription<Event> {
+    Subscription::from_recipe(Watch {
+        path: path.into(),
+        recursive,
+    })
+}
+
+#[derive(Debug)]
+struct Watch {
+    path: PathBuf,
+    recursive: bool,
+}
+
+impl subscription::Recipe for Watch {
+    type Output = Event;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.path.hash(state);
+        self.recursive.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<Event> {
+        use futures::channel::mpsc;
+        use futures::stream::{self, StreamExt};
+        use notify::Watcher;
+
+        const TIMEOUT: Duration = Duration::from_millis(100);
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let debouncer = notify_debouncer_full::new_debouncer(
+            TIMEOUT,
+            None,
+            move |result: notify_debouncer_full::DebounceEventResult| {
+                let events: Vec<_> = match result {
+                    Ok(events) => events
+                        .into_iter()
+                        .filter_map(|event| convert(event.event))
+                        .collect(),
+                    Err(errors) => errors
+                        .into_iter()
+                        .map(|error| Event::Failed(error.to_string()))
+                        .collect(),
+                };
+
+                for event in events {
+                    let _ = sender.unbounded_send(event);
+                }
+            },
+        );
+
+        let mode = if self.recursive {
+            notify::RecursiveMode::Recursive
+        } else {
+            notify::RecursiveMode::NonRecursive
+        };
+
+        let debouncer = debouncer.and_then(|mut debouncer| {
+            debouncer.watcher().watch(&self.path, mode)?;
+            debouncer.cache().add_root(&self.path, mode);
+
+            Ok(debouncer)
+        });
+
+        match debouncer {
+            Ok(debouncer) => {
+                // The watcher stops as soon as the debouncer is dropped, so
+                // we keep it alive as long as the stream
+                Box::pin(receiver.map(move |event| {
+                    let _ = &debouncer;
+
+                    event
+                }))
+            }
+            Err(error) => Box::pin(stream::once(async move {
+                Event::Failed(error.to_string())
+            })),
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that loads the [`Theme`] at the given path
+/// and reloads it every time the file changes.
+///
+/// The parent directory of the file is watched, so the [`Theme`] keeps
+/// reloading even if an editor replaces the file when saving it.
+///
+/// [`Theme`]: crate::core::Theme
+#[cfg(feature = "theme-file")]
+pub fn watch_theme(
+    path: impl Into<PathBuf>,
+) -> Subscription<Result<crate::core::Theme, crate::core::theme::Error>> {
+    Subscription::from_recipe(WatchTheme { path: path.into() })
+}
+
+#[cfg(feature = "theme-file")]
+#[derive(Debug)]
+struct WatchTheme {
+    path: PathBuf,
+}
+
+#[cfg(feature = "theme-file")]
+impl subscription::Recipe for WatchTheme {
+    type Output = Result<crate::core::Theme, crate::core::theme::Error>;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.path.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use crate::core::Theme;
+        use futures::future;
+        use futures::stream::{self, StreamExt};
+
+        let path = self.path;
+
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                parent.to_path_buf()
+            }
+            _ => PathBuf::from("."),
+        };
+
+        let changes = Box::new(Watch {
+            path: directory,
+            recursive: false,
+        })
+        .stream(input);
+
+        let initial = Theme::load(&path);
+
+        Box::pin(stream::once(future::ready(initial)).chain(
+            changes.filter_map(move |event| {
+                let is_theme =
+                    |changed: &PathBuf| changed.file_name() == path.file_name();
+
+                let result = match event {
+                    Event::Created(changed) | Event::Modified(changed)
+                        if is_theme(&changed) =>
+                    {
+                        Some(Theme::load(&path))
+                    }
+                    Event::Renamed { to, .. } if is_theme(&to) => {
+                        Some(Theme::load(&path))
+                    }
+                    Event::Failed(error) => {
+                        Some(Err(std::io::Error::other(error).into()))
+                    }
+                    _ => None,
+                };
+
+                future::ready(result)
+            }),
+        ))
+    }
+}
+
+fn convert(event: notify::Event) -> Option<Event> {
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+
+    let mut paths = event.paths.into_iter();
+
+    match event.kind {
+        EventKind::Create(_)
+        | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            paths.next().map(Event::Created)
+        }
+        EventKind::Remove(_)
+        | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            paths.next().map(Event::Removed)
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            let from = paths.next()?;
+            let to = paths.next()?;
+
+            Some(Event::Renamed { from, to })
+        }
+        EventKind::Modify(_) => paths.next().map(Event::Modified),
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => None,
+    }
+}
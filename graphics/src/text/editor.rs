@@ -276,10 +276,30 @@ impl editor::Editor for Editor {
                             );
                         }
                         // Other motions simply move the cursor to one end of the selection
-                        _ => editor.set_cursor(match motion.direction() {
-                            Direction::Left => left,
-                            Direction::Right => right,
-                        }),
+                        _ => {
+                            // Horizontal motions are visual, so they are
+                            // mirrored inside right-to-left lines
+                            let is_rtl = matches!(
+                                motion,
+                                Motion::Left
+                                    | Motion::Right
+                                    | Motion::WordLeft
+                                    | Motion::WordRight
+                            ) && editor
+                                .buffer()
+                                .layout_runs()
+                                .find(|run| run.line_i == cursor.line)
+                                .is_some_and(|run| run.rtl);
+
+                            editor.set_cursor(
+                                match (motion.direction(), is_rtl) {
+                                    (Direction::Left, false)
+                                    | (Direction::Right, true) => left,
+                                    (Direction::Right, false)
+                                    | (Direction::Left, true) => right,
+                                },
+                            );
+                        }
                     }
                 } else {
                     editor.action(font_system.raw(), motion_to_action(motion));
@@ -88,6 +88,8 @@ impl core::text::Paragraph for Paragraph {
             text::to_shaping(text.shaping),
         );
 
+        fit_unbounded(&mut buffer, font_system.raw());
+
         let min_bounds = text::measure(&buffer);
 
         Self(Some(Arc::new(Internal {
@@ -120,6 +122,8 @@ impl core::text::Paragraph for Paragraph {
                     new_bounds.height,
                 );
 
+                fit_unbounded(&mut internal.buffer, font_system.raw());
+
                 internal.bounds = new_bounds;
                 internal.min_bounds = text::measure(&internal.buffer);
 
@@ -192,43 +196,66 @@ impl core::text::Paragraph for Paragraph {
         let run = self.internal().buffer.layout_runs().nth(line)?;
 
         // index represents a grapheme, not a glyph
-        // Let's find the first glyph for the given grapheme cluster
-        let mut last_start = None;
-        let mut last_grapheme_count = 0;
-        let mut graphemes_seen = 0;
+        // Let's find the glyph containing the given grapheme cluster.
+        // Glyphs are stored in visual order, so we look for it by its
+        // logical byte offset instead of counting glyphs
+        let start = run.glyphs.iter().map(|glyph| glyph.start).min()?;
+        let end = run.glyphs.iter().map(|glyph| glyph.end).max()?;
+
+        let offset = run.text[start..end]
+            .grapheme_indices(false)
+            .nth(index)
+            .map_or(end, |(offset, _)| start + offset);
 
         let glyph = run
             .glyphs
             .iter()
-            .find(|glyph| {
-                if Some(glyph.start) != last_start {
-                    last_grapheme_count = run.text[glyph.start..glyph.end]
-                        .graphemes(false)
-                        .count();
-                    last_start = Some(glyph.start);
-                    graphemes_seen += last_grapheme_count;
-                }
-
-                graphemes_seen >= index
-            })
-            .or_else(|| run.glyphs.last())?;
-
-        let advance = if index == 0 {
-            0.0
+            .find(|glyph| (glyph.start..glyph.end).contains(&offset))
+            .or_else(|| run.glyphs.iter().max_by_key(|glyph| glyph.end))?;
+
+        let graphemes =
+            run.text[glyph.start..glyph.end].graphemes(false).count();
+
+        let preceding = run.text
+            [glyph.start..offset.clamp(glyph.start, glyph.end)]
+            .graphemes(false)
+            .count();
+
+        let advance = glyph.w * preceding as f32 / graphemes.max(1) as f32;
+
+        // Right-to-left glyphs are traversed from their right edge
+        let x = if glyph.level.is_rtl() {
+            glyph.x + glyph.w - advance
         } else {
-            glyph.w
-                * (1.0
-                    - graphemes_seen.saturating_sub(index) as f32
-                        / last_grapheme_count.max(1) as f32)
+            glyph.x + advance
         };
 
         Some(Point::new(
-            glyph.x + glyph.x_offset * glyph.font_size + advance,
+            x + glyph.x_offset * glyph.font_size,
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
 }
 
+/// Gives a finite width to a buffer of unbounded width with right-to-left
+/// lines.
+///
+/// cosmic-text lays out right-to-left lines starting from the right edge of
+/// the buffer, which would otherwise be infinitely far away. Each line keeps
+/// its own alignment within the measured width.
+fn fit_unbounded(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+) {
+    let (width, height) = buffer.size();
+
+    if width.is_infinite() && buffer.layout_runs().any(|run| run.rtl) {
+        let width = text::measure(buffer).width.ceil();
+
+        buffer.set_size(font_system, width, height);
+    }
+}
+
 impl Default for Paragraph {
     fn default() -> Self {
         Self(Some(Arc::new(Internal::default())))
@@ -1,7 +1,7 @@
 //! Access the clipboard.
 use crate::command::{self, Command};
 use crate::core::clipboard::Kind;
-use crate::futures::MaybeSend;
+use crate::futures::{MaybeSend, MaybeSync};
 
 use std::fmt;
 
@@ -20,7 +20,7 @@ impl<T> Action<T> {
     /// Maps the output of a clipboard [`Action`] using the provided closure.
     pub fn map<A>(
         self,
-        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
     ) -> Action<A>
     where
         T: 'static,
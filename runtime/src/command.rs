@@ -1,16 +1,21 @@
 //! Run asynchronous actions.
 mod action;
+mod chain;
 
 pub use action::Action;
+pub use chain::{Chain, Continuation};
 
 use crate::core::widget;
 use crate::futures::futures;
-use crate::futures::MaybeSend;
+use crate::futures::{boxed_stream, MaybeSend, MaybeSync};
 
 use futures::channel::mpsc;
+use futures::stream::AbortHandle;
 use futures::Stream;
 use std::fmt;
 use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// A set of asynchronous actions to be performed by some runtime.
 #[must_use = "`Command` must be returned to runtime to take effect"]
@@ -51,6 +56,14 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that produces the given value right away.
+    pub fn done(value: T) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        Self::perform(futures::future::ready(value), std::convert::identity)
+    }
+
     /// Creates a [`Command`] that runs the given stream to completion.
     pub fn run<A>(
         stream: impl Stream<Item = A> + 'static + MaybeSend,
@@ -82,7 +95,7 @@ impl<T> Command<T> {
     /// Applies a transformation to the result of a [`Command`].
     pub fn map<A>(
         self,
-        f: impl Fn(T) -> A + 'static + MaybeSend + Sync + Clone,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync + Clone,
     ) -> Command<A>
     where
         T: 'static,
@@ -100,6 +113,99 @@ impl<T> Command<T> {
         }
     }
 
+    /// Chains a new [`Command`] to be performed once the current one
+    /// produces an output.
+    ///
+    /// The closure is called with every output of the current [`Command`],
+    /// so it may be called more than once if the [`Command`] runs a stream
+    /// or a batch of actions.
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + 'static + MaybeSend + MaybeSync,
+    ) -> Command<A>
+    where
+        T: MaybeSend + 'static,
+    {
+        match self.0 {
+            Internal::None => Command::none(),
+            _ => Command::single(Action::Chain(Chain::new(self, f))),
+        }
+    }
+
+    /// Performs the current [`Command`] and discards all of its outputs.
+    pub fn discard<A>(self) -> Command<A>
+    where
+        T: MaybeSend + 'static,
+    {
+        self.then(|_| Command::none())
+    }
+
+    /// Collects all the outputs of the current [`Command`] and produces them
+    /// at once, in the order they are produced, when all of its actions
+    /// have finished.
+    ///
+    /// This is useful to wait for the results of a [`Command::batch`].
+    pub fn collect(self) -> Command<Vec<T>>
+    where
+        T: MaybeSend + 'static,
+    {
+        chain::collect(self)
+    }
+
+    /// Makes the [`Command`] abortable, returning it alongside a [`Handle`]
+    /// that can be used to abort it.
+    ///
+    /// Aborting a [`Command`] drops any of its futures or streams that are
+    /// still running, so they will not produce any more messages. This
+    /// includes the commands chained with [`Command::then`], which are not
+    /// run at all once aborted. Any other actions of the [`Command`] are not
+    /// affected.
+    pub fn abortable(self) -> (Self, Handle)
+    where
+        T: 'static,
+    {
+        let handle = Handle {
+            registry: Arc::new(Registry::default()),
+        };
+
+        (self.abortable_with(&handle), handle)
+    }
+
+    /// Makes the [`Command`] abortable with the given [`Handle`].
+    pub(crate) fn abortable_with(self, handle: &Handle) -> Self
+    where
+        T: 'static,
+    {
+        use futures::stream;
+
+        let abortable = |action| match action {
+            Action::Future(future) => {
+                let (stream, abort) = stream::abortable(stream::once(future));
+                handle.register(abort);
+
+                Action::Stream(boxed_stream(stream))
+            }
+            Action::Stream(stream) => {
+                let (stream, abort) = stream::abortable(stream);
+                handle.register(abort);
+
+                Action::Stream(boxed_stream(stream))
+            }
+            Action::Chain(chain) => Action::Chain(chain.abortable(handle)),
+            action => action,
+        };
+
+        let command = match self.0 {
+            Internal::None => Internal::None,
+            Internal::Single(action) => Internal::Single(abortable(action)),
+            Internal::Batch(batch) => {
+                Internal::Batch(batch.into_iter().map(abortable).collect())
+            }
+        };
+
+        Self(command)
+    }
+
     /// Returns all of the actions of the [`Command`].
     pub fn actions(self) -> Vec<Action<T>> {
         let Command(command) = self;
@@ -112,6 +218,26 @@ impl<T> Command<T> {
     }
 }
 
+impl<T, E> Command<Result<T, E>> {
+    /// Chains a new [`Command`] to be performed if the current one succeeds.
+    ///
+    /// Any error is produced by the resulting [`Command`] as is.
+    pub fn and_then<A>(
+        self,
+        f: impl Fn(T) -> Command<Result<A, E>> + 'static + MaybeSend + MaybeSync,
+    ) -> Command<Result<A, E>>
+    where
+        T: MaybeSend + 'static,
+        E: MaybeSend + 'static,
+        A: MaybeSend + 'static,
+    {
+        self.then(move |result| match result {
+            Ok(value) => f(value),
+            Err(error) => Command::done(Err(error)),
+        })
+    }
+}
+
 impl<Message> From<()> for Command<Message> {
     fn from(_value: ()) -> Self {
         Self::none()
@@ -126,6 +252,61 @@ impl<T> fmt::Debug for Command<T> {
     }
 }
 
+/// A handle to an abortable [`Command`].
+///
+/// A [`Handle`] can be obtained with [`Command::abortable`] and stored in the
+/// state of your application. You can then call [`Handle::abort`] during
+/// `update` to stop the [`Command`].
+#[derive(Debug, Clone)]
+pub struct Handle {
+    registry: Arc<Registry>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    handles: Mutex<Vec<AbortHandle>>,
+    is_aborted: AtomicBool,
+}
+
+impl Handle {
+    /// Aborts the [`Command`] of the [`Handle`].
+    ///
+    /// Its running futures and streams will be dropped by the runtime the
+    /// next time they are polled, and its pending continuations will not
+    /// run.
+    pub fn abort(&self) {
+        self.registry.is_aborted.store(true, Ordering::SeqCst);
+
+        for handle in self.handles().iter() {
+            handle.abort();
+        }
+    }
+
+    /// Returns true if the [`Command`] of the [`Handle`] has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.registry.is_aborted.load(Ordering::SeqCst)
+    }
+
+    /// Adds the [`AbortHandle`] of a future or stream of the [`Command`],
+    /// which may be produced by a continuation after the [`Command`] started.
+    fn register(&self, handle: AbortHandle) {
+        let mut handles = self.handles();
+
+        if self.is_aborted() {
+            handle.abort();
+        }
+
+        handles.push(handle);
+    }
+
+    fn handles(&self) -> std::sync::MutexGuard<'_, Vec<AbortHandle>> {
+        self.registry
+            .handles
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
 /// Creates a [`Command`] that produces the `Message`s published from a [`Future`]
 /// to an [`mpsc::Sender`] with the given bounds.
 pub fn channel<Fut, Message>(
@@ -145,3 +326,121 @@ where
 
     Command::single(Action::Stream(Box::pin(stream::select(receiver, runner))))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    type Erased = Box<dyn std::any::Any + Send>;
+
+    /// Runs the futures, streams and chains of a [`Command`] to completion,
+    /// like a runtime would.
+    fn run<T: 'static>(command: Command<T>) -> Vec<T> {
+        let mut outputs = Vec::new();
+
+        for action in command.actions() {
+            match action {
+                Action::Future(future) => outputs.push(block_on(future)),
+                Action::Stream(stream) => {
+                    outputs.extend(block_on(stream.collect::<Vec<_>>()));
+                }
+                Action::Chain(chain) => {
+                    // Erasing the continuations keeps the recursion finite
+                    let continuations = run(chain
+                        .into_command()
+                        .map(|continuation| Box::new(continuation) as Erased));
+
+                    for continuation in continuations {
+                        let continuation = continuation
+                            .downcast::<Continuation<T>>()
+                            .expect("Downcast continuation");
+
+                        outputs.extend(run(continuation.resume()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        outputs
+    }
+
+    fn chain(command: Command<u32>) -> Chain<u32> {
+        match command.actions().pop() {
+            Some(Action::Chain(chain)) => chain,
+            action => panic!("expected a chain, found {action:?}"),
+        }
+    }
+
+    #[test]
+    fn abortable_chains_run_to_completion() {
+        let (command, handle) = Command::done(1)
+            .then(|n: u32| Command::batch([Command::done(n), Command::done(2)]))
+            .then(|n| Command::done(n * 10))
+            .abortable();
+
+        assert_eq!(run(command), [10, 20]);
+        assert!(!handle.is_aborted());
+    }
+
+    #[test]
+    fn aborted_chains_produce_nothing() {
+        let (command, handle) = Command::done(1)
+            .then(|n: u32| Command::done(n + 1))
+            .abortable();
+
+        handle.abort();
+
+        assert!(run(command).is_empty());
+
+        let (command, handle) = Command::done(Ok::<u32, ()>(1))
+            .and_then(|n| Command::done(Ok(n + 1)))
+            .map(|result| result.unwrap_or_default())
+            .collect()
+            .map(|outputs: Vec<u32>| outputs.len() as u32)
+            .abortable();
+
+        handle.abort();
+
+        assert!(run(command).is_empty());
+    }
+
+    #[test]
+    fn aborting_stops_pending_continuations() {
+        let (command, handle) = Command::done(1)
+            .then(|n: u32| Command::done(n + 1))
+            .abortable();
+
+        // The first command finishes before the abort...
+        let continuations = run(chain(command).into_command());
+        assert_eq!(continuations.len(), 1);
+
+        handle.abort();
+
+        // ...but its continuation never runs
+        assert!(continuations
+            .into_iter()
+            .flat_map(|continuation| run(continuation.resume()))
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn aborting_drops_commands_produced_by_continuations() {
+        let (command, handle) = Command::done(1)
+            .then(|n: u32| Command::done(n + 1))
+            .abortable();
+
+        let next: Vec<_> = run(chain(command).into_command())
+            .into_iter()
+            .map(Continuation::resume)
+            .collect();
+
+        handle.abort();
+
+        assert!(next.into_iter().flat_map(run).next().is_none());
+    }
+}
@@ -1,7 +1,11 @@
 use crate::clipboard;
+use crate::command::Chain;
 use crate::core::widget;
+use crate::dialog;
 use crate::font;
-use crate::futures::MaybeSend;
+use crate::futures::{MaybeSend, MaybeSync};
+use crate::i18n;
+use crate::notification;
 use crate::system;
 use crate::window;
 
@@ -23,6 +27,9 @@ pub enum Action<T> {
     /// [`Stream`]: iced_futures::BoxStream
     Stream(iced_futures::BoxStream<T>),
 
+    /// Run a [`Chain`] of commands.
+    Chain(Chain<T>),
+
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
@@ -32,6 +39,15 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action<T>),
 
+    /// Run a dialog action.
+    Dialog(dialog::Action<T>),
+
+    /// Run a notification action.
+    Notification(notification::Action<T>),
+
+    /// Run an i18n action.
+    I18n(i18n::Action<T>),
+
     /// Run a widget action.
     Widget(Box<dyn widget::Operation<T>>),
 
@@ -54,7 +70,7 @@ impl<T> Action<T> {
     /// [`Command`]: crate::Command
     pub fn map<A>(
         self,
-        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
     ) -> Action<A>
     where
         A: 'static,
@@ -65,9 +81,15 @@ impl<T> Action<T> {
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
             Self::Stream(stream) => Action::Stream(Box::pin(stream.map(f))),
+            Self::Chain(chain) => Action::Chain(chain.map(f)),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
             Self::Window(window) => Action::Window(window.map(f)),
             Self::System(system) => Action::System(system.map(f)),
+            Self::Dialog(dialog) => Action::Dialog(dialog.map(f)),
+            Self::Notification(notification) => {
+                Action::Notification(notification.map(f))
+            }
+            Self::I18n(action) => Action::I18n(action.map(f)),
             Self::Widget(operation) => {
                 Action::Widget(Box::new(widget::operation::map(operation, f)))
             }
@@ -85,6 +107,7 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
             Self::Stream(_) => write!(f, "Action::Stream"),
+            Self::Chain(chain) => write!(f, "Action::{chain:?}"),
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({action:?})")
             }
@@ -92,6 +115,11 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::Window({action:?})")
             }
             Self::System(action) => write!(f, "Action::System({action:?})"),
+            Self::Dialog(action) => write!(f, "Action::Dialog({action:?})"),
+            Self::Notification(action) => {
+                write!(f, "Action::Notification({action:?})")
+            }
+            Self::I18n(action) => write!(f, "Action::I18n({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
             Self::Custom(_) => write!(f, "Action::Custom"),
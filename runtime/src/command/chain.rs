@@ -0,0 +1,288 @@
+use crate::command::{Action, Command, Handle};
+use crate::futures::futures::stream::{self, StreamExt};
+use crate::futures::futures::{future, FutureExt};
+use crate::futures::{boxed_stream, MaybeSend, MaybeSync};
+
+use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A [`Command`] followed by the commands produced with each of its outputs.
+///
+/// A [`Chain`] can be created with [`Command::then`]. Runtimes must run the
+/// [`Command`] of [`Chain::into_command`] and then run the [`Command`]
+/// returned by [`Continuation::resume`] for each of its outputs.
+pub struct Chain<T> {
+    command: Box<Command<Output>>,
+    then: Arc<dyn Then<T>>,
+}
+
+impl<T> Chain<T> {
+    pub(crate) fn new<A>(
+        command: Command<A>,
+        f: impl Fn(A) -> Command<T> + MaybeSend + MaybeSync + 'static,
+    ) -> Self
+    where
+        A: MaybeSend + 'static,
+    {
+        Self {
+            command: Box::new(command.map(Output::new)),
+            then: Arc::new(move |output: Output| f(output.downcast())),
+        }
+    }
+
+    /// Turns the [`Chain`] into a [`Command`] producing a [`Continuation`]
+    /// for each output of its first [`Command`].
+    pub fn into_command(self) -> Command<Continuation<T>>
+    where
+        T: 'static,
+    {
+        let then = self.then;
+
+        (*self.command).map(move |output| Continuation {
+            output,
+            then: then.clone(),
+        })
+    }
+
+    /// Applies a transformation to the outputs of the [`Chain`].
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + MaybeSend + MaybeSync + 'static,
+    ) -> Chain<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        self.map_erased(Arc::new(f))
+    }
+
+    // Mapping a `Chain` maps the commands it produces, which may be chains
+    // themselves; erasing the closure keeps the recursion finite.
+    fn map_erased<A>(self, f: Arc<dyn Map<T, A>>) -> Chain<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let then = self.then;
+
+        Chain {
+            command: self.command,
+            then: Arc::new(move |output| {
+                let f = f.clone();
+
+                then(output).map(move |value| f(value))
+            }),
+        }
+    }
+
+    /// Makes the [`Chain`] abortable with the given [`Handle`], including
+    /// every [`Command`] produced by its continuations.
+    pub(crate) fn abortable(self, handle: &Handle) -> Self
+    where
+        T: 'static,
+    {
+        let then = self.then;
+        let handle = handle.clone();
+
+        Chain {
+            command: Box::new(self.command.abortable_with(&handle)),
+            then: Arc::new(move |output| {
+                if handle.is_aborted() {
+                    return Command::none();
+                }
+
+                then(output).abortable_with(&handle)
+            }),
+        }
+    }
+
+    /// Turns the [`Chain`] into a [`Command`] producing all of its outputs
+    /// at once.
+    fn collect(self) -> Command<Vec<T>>
+    where
+        T: MaybeSend + 'static,
+    {
+        let then = self.then;
+
+        (*self.command).collect().then(move |outputs| {
+            gather(outputs.into_iter().map(|output| then(output).collect()))
+        })
+    }
+}
+
+impl<T> fmt::Debug for Chain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chain({:?})", self.command)
+    }
+}
+
+/// An output of the first [`Command`] of a [`Chain`], ready to produce the
+/// next [`Command`].
+pub struct Continuation<T> {
+    output: Output,
+    then: Arc<dyn Then<T>>,
+}
+
+impl<T> Continuation<T> {
+    /// Produces the next [`Command`] of the [`Chain`].
+    pub fn resume(self) -> Command<T> {
+        (self.then)(self.output)
+    }
+}
+
+impl<T> fmt::Debug for Continuation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Continuation")
+    }
+}
+
+trait Then<T>: Fn(Output) -> Command<T> + MaybeSend + MaybeSync {}
+
+impl<F, T> Then<T> for F where
+    F: Fn(Output) -> Command<T> + MaybeSend + MaybeSync
+{
+}
+
+trait Map<T, A>: Fn(T) -> A + MaybeSend + MaybeSync {}
+
+impl<F, T, A> Map<T, A> for F where F: Fn(T) -> A + MaybeSend + MaybeSync {}
+
+/// The output of the first [`Command`] of a [`Chain`], with its type erased.
+struct Output(Box<dyn Erased>);
+
+impl Output {
+    fn new<T: MaybeSend + 'static>(value: T) -> Self {
+        Self(Box::new(value))
+    }
+
+    fn downcast<T: 'static>(self) -> T {
+        *self
+            .0
+            .into_any()
+            .downcast()
+            .expect("Output of a chain must have the type of its command")
+    }
+}
+
+trait Erased: MaybeSend {
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: MaybeSend + 'static> Erased for T {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// The progress of an [`Action`] being collected.
+enum Step<T> {
+    /// The [`Action`] produced an output and may produce more.
+    Item(T),
+
+    /// The [`Action`] has finished, producing the given outputs last.
+    Done(Vec<T>),
+}
+
+/// Collects all the outputs of a [`Command`] into a single [`Vec`].
+pub(crate) fn collect<T>(command: Command<T>) -> Command<Vec<T>>
+where
+    T: MaybeSend + 'static,
+{
+    let steps: Vec<_> = command.actions().into_iter().map(steps).collect();
+    let remaining = steps.len();
+
+    track(Command::batch(steps), remaining)
+}
+
+/// Gathers the outputs of some commands that produce exactly one [`Vec`]
+/// each.
+fn gather<T>(
+    commands: impl IntoIterator<Item = Command<Vec<T>>>,
+) -> Command<Vec<T>>
+where
+    T: MaybeSend + 'static,
+{
+    let commands: Vec<_> = commands
+        .into_iter()
+        .map(|command| command.map(Step::Done))
+        .collect();
+    let remaining = commands.len();
+
+    track(Command::batch(commands), remaining)
+}
+
+/// Accumulates the outputs of the given [`Step`]s until `remaining` actions
+/// are done.
+fn track<T>(steps: Command<Step<T>>, remaining: usize) -> Command<Vec<T>>
+where
+    T: MaybeSend + 'static,
+{
+    if remaining == 0 {
+        return Command::done(Vec::new());
+    }
+
+    let state = Arc::new(Mutex::new((Vec::new(), remaining)));
+
+    steps.then(move |step| {
+        let mut state = state.lock().expect("Lock collected outputs");
+        let (outputs, remaining) = &mut *state;
+
+        match step {
+            Step::Item(output) => {
+                outputs.push(output);
+
+                Command::none()
+            }
+            Step::Done(last) => {
+                outputs.extend(last);
+                *remaining -= 1;
+
+                if *remaining == 0 {
+                    Command::done(std::mem::take(outputs))
+                } else {
+                    Command::none()
+                }
+            }
+        }
+    })
+}
+
+/// Turns an [`Action`] into a [`Command`] that reports its progress, ending
+/// with a single [`Step::Done`].
+fn steps<T>(action: Action<T>) -> Command<Step<T>>
+where
+    T: MaybeSend + 'static,
+{
+    match action {
+        Action::Future(future) => Command::single(Action::Future(Box::pin(
+            future.map(|output| Step::Done(vec![output])),
+        ))),
+        Action::Stream(stream) => {
+            Command::single(Action::Stream(boxed_stream(
+                stream
+                    .map(Step::Item)
+                    .chain(stream::once(future::ready(Step::Done(Vec::new())))),
+            )))
+        }
+        Action::Chain(chain) => chain.collect().map(Step::Done),
+        // These actions are run asynchronously and always produce a single
+        // output.
+        action @ (Action::System(_)
+        | Action::Dialog(_)
+        | Action::Notification(_)) => {
+            Command::single(action.map(|output| Step::Done(vec![output])))
+        }
+        // These actions are run immediately by the runtime, so any output
+        // is delivered before the `Done` step.
+        action @ (Action::Clipboard(_)
+        | Action::Window(_)
+        | Action::I18n(_)
+        | Action::Widget(_)
+        | Action::LoadFont { .. }
+        | Action::Custom(_)) => Command::batch([
+            Command::single(action.map(Step::Item)),
+            Command::done(Step::Done(Vec::new())),
+        ]),
+    }
+}
@@ -31,6 +31,7 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+    timeline: Option<(usize, usize)>,
 }
 
 impl Debug {
@@ -63,6 +64,7 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+            timeline: None,
         }
     }
 
@@ -70,6 +72,10 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
@@ -136,6 +142,10 @@ impl Debug {
         self.message_count += 1;
     }
 
+    pub fn log_timeline(&mut self, position: usize, len: usize) {
+        self.timeline = Some((position, len));
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -167,6 +177,12 @@ impl Debug {
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
         lines.push(key_value("Message count:", self.message_count));
+        if let Some((position, len)) = self.timeline {
+            lines.push(format!(
+                "Timeline: {position}/{len} (F9 back, F10 forward, F8 export)"
+            ));
+        }
+
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
             if msg.len() <= 100 {
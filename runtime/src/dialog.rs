@@ -0,0 +1,210 @@
+//! Show native file dialogs.
+use crate::command::{self, Command};
+use crate::core::window::Id;
+use crate::futures::{MaybeSend, MaybeSync};
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// A dialog action to be performed by some [`Command`].
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Ask the user to pick an existing file and produce `T` with the result.
+    OpenFile(Id, FileDialog, Box<dyn Closure<Option<PathBuf>, T>>),
+
+    /// Ask the user to pick some existing files and produce `T` with the
+    /// result.
+    OpenFiles(Id, FileDialog, Box<dyn Closure<Option<Vec<PathBuf>>, T>>),
+
+    /// Ask the user for a path to save a file into and produce `T` with the
+    /// result.
+    SaveFile(Id, FileDialog, Box<dyn Closure<Option<PathBuf>, T>>),
+
+    /// Ask the user to pick a folder and produce `T` with the result.
+    PickFolder(Id, FileDialog, Box<dyn Closure<Option<PathBuf>, T>>),
+}
+
+/// A closure that turns the result of a dialog into some output.
+///
+/// Dialogs are run asynchronously, so the closure may be called from a
+/// different thread.
+pub trait Closure<A, T>: FnOnce(A) -> T + MaybeSend {}
+
+impl<F, A, T> Closure<A, T> for F where F: FnOnce(A) -> T + MaybeSend {}
+
+impl<T> Action<T> {
+    /// Returns the [`Id`] of the window the dialog of the [`Action`] belongs
+    /// to.
+    pub fn window(&self) -> Id {
+        match self {
+            Self::OpenFile(id, ..)
+            | Self::OpenFiles(id, ..)
+            | Self::SaveFile(id, ..)
+            | Self::PickFolder(id, ..) => *id,
+        }
+    }
+
+    /// Maps the output of a dialog [`Action`] using the provided closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::OpenFile(id, dialog, o) => {
+                Action::OpenFile(id, dialog, Box::new(move |s| f(o(s))))
+            }
+            Self::OpenFiles(id, dialog, o) => {
+                Action::OpenFiles(id, dialog, Box::new(move |s| f(o(s))))
+            }
+            Self::SaveFile(id, dialog, o) => {
+                Action::SaveFile(id, dialog, Box::new(move |s| f(o(s))))
+            }
+            Self::PickFolder(id, dialog, o) => {
+                Action::PickFolder(id, dialog, Box::new(move |s| f(o(s))))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OpenFile(id, dialog, _) => {
+                write!(f, "Action::OpenFile({id:?}, {dialog:?})")
+            }
+            Self::OpenFiles(id, dialog, _) => {
+                write!(f, "Action::OpenFiles({id:?}, {dialog:?})")
+            }
+            Self::SaveFile(id, dialog, _) => {
+                write!(f, "Action::SaveFile({id:?}, {dialog:?})")
+            }
+            Self::PickFolder(id, dialog, _) => {
+                write!(f, "Action::PickFolder({id:?}, {dialog:?})")
+            }
+        }
+    }
+}
+
+/// The configuration of a native file dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDialog {
+    /// The title of the dialog.
+    pub title: Option<String>,
+
+    /// The directory the dialog starts in.
+    pub directory: Option<PathBuf>,
+
+    /// The file name suggested by the dialog.
+    pub file_name: Option<String>,
+
+    /// The [`Filter`]s the user can choose from.
+    pub filters: Vec<Filter>,
+}
+
+impl FileDialog {
+    /// Creates a new [`FileDialog`] with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the [`FileDialog`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the starting directory of the [`FileDialog`].
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the suggested file name of the [`FileDialog`].
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Adds a [`Filter`] to the [`FileDialog`].
+    pub fn filter(
+        mut self,
+        name: impl Into<String>,
+        extensions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.filters.push(Filter {
+            name: name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+}
+
+/// A named set of file extensions a [`FileDialog`] can be restricted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The name of the [`Filter`].
+    pub name: String,
+
+    /// The file extensions of the [`Filter`], without a leading dot.
+    pub extensions: Vec<String>,
+}
+
+/// Asks the user to pick an existing file, using a dialog parented to the
+/// window with the given [`Id`].
+pub fn open_file<Message>(
+    id: Id,
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Dialog(Action::OpenFile(
+        id,
+        dialog,
+        Box::new(f),
+    )))
+}
+
+/// Asks the user to pick some existing files, using a dialog parented to the
+/// window with the given [`Id`].
+pub fn open_files<Message>(
+    id: Id,
+    dialog: FileDialog,
+    f: impl FnOnce(Option<Vec<PathBuf>>) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Dialog(Action::OpenFiles(
+        id,
+        dialog,
+        Box::new(f),
+    )))
+}
+
+/// Asks the user for a path to save a file into, using a dialog parented to
+/// the window with the given [`Id`].
+pub fn save_file<Message>(
+    id: Id,
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Dialog(Action::SaveFile(
+        id,
+        dialog,
+        Box::new(f),
+    )))
+}
+
+/// Asks the user to pick a folder, using a dialog parented to the window with
+/// the given [`Id`].
+pub fn pick_folder<Message>(
+    id: Id,
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Dialog(Action::PickFolder(
+        id,
+        dialog,
+        Box::new(f),
+    )))
+}
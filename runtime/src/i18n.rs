@@ -0,0 +1,82 @@
+//! Change the language of an application.
+pub use iced_core::i18n::*;
+
+use crate::command::{self, Command};
+use crate::futures::{MaybeSend, MaybeSync};
+
+use std::fmt;
+
+/// An i18n action to be performed by some [`Command`].
+///
+/// The runtime owns the [`Catalog`] of an application and builds its views
+/// again after every change.
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Change the current [`Locale`] of the [`Catalog`].
+    ChangeLocale(Locale),
+
+    /// Add a [`Bundle`] of translations to the [`Catalog`].
+    AddBundle(Bundle),
+
+    /// Fetch the current [`Locale`] of the [`Catalog`] and produce `T` with
+    /// it.
+    FetchLocale(Box<dyn FnOnce(Locale) -> T>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of an i18n [`Action`] using the provided closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::ChangeLocale(locale) => Action::ChangeLocale(locale),
+            Self::AddBundle(bundle) => Action::AddBundle(bundle),
+            Self::FetchLocale(o) => {
+                Action::FetchLocale(Box::new(move |s| f(o(s))))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ChangeLocale(locale) => {
+                write!(f, "Action::ChangeLocale({locale})")
+            }
+            Self::AddBundle(bundle) => {
+                write!(f, "Action::AddBundle({})", bundle.locale())
+            }
+            Self::FetchLocale(_) => write!(f, "Action::FetchLocale"),
+        }
+    }
+}
+
+/// Changes the current [`Locale`] of the application.
+///
+/// Once the [`Locale`] is changed, the user interface is built again so
+/// every view picks up the new translations and formats.
+pub fn change_locale<Message>(locale: impl Into<Locale>) -> Command<Message> {
+    Command::single(command::Action::I18n(Action::ChangeLocale(locale.into())))
+}
+
+/// Adds a [`Bundle`] of translations to the application.
+///
+/// A [`Bundle`] replaces any previous one for the same [`Locale`]. The
+/// first [`Bundle`] added is used as the fallback for messages missing
+/// from the current [`Locale`].
+pub fn add_bundle<Message>(bundle: Bundle) -> Command<Message> {
+    Command::single(command::Action::I18n(Action::AddBundle(bundle)))
+}
+
+/// Fetches the current [`Locale`] of the application.
+pub fn fetch_locale<Message>(
+    f: impl FnOnce(Locale) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::I18n(Action::FetchLocale(Box::new(f))))
+}
@@ -18,8 +18,11 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod clipboard;
 pub mod command;
+pub mod dialog;
 pub mod font;
+pub mod i18n;
 pub mod keyboard;
+pub mod notification;
 pub mod overlay;
 pub mod program;
 pub mod system;
@@ -29,6 +32,9 @@ pub mod window;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+#[cfg(feature = "time-travel")]
+pub mod recorder;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -0,0 +1,143 @@
+//! Show desktop notifications.
+use crate::command::{self, Command};
+use crate::futures::{MaybeSend, MaybeSync};
+
+use std::fmt;
+use std::time::Duration;
+
+/// A notification action to be performed by some [`Command`].
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Show a [`Notification`] and produce `T` with the result.
+    Show(Notification, Box<dyn Closure<T>>),
+}
+
+/// A closure that turns the result of showing a [`Notification`] into some
+/// output.
+///
+/// Notifications are shown asynchronously, so the closure may be called from
+/// a different thread.
+pub trait Closure<T>: FnOnce(Result<(), Error>) -> T + MaybeSend {}
+
+impl<F, T> Closure<T> for F where F: FnOnce(Result<(), Error>) -> T + MaybeSend {}
+
+impl<T> Action<T> {
+    /// Maps the output of a notification [`Action`] using the provided
+    /// closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Show(notification, o) => {
+                Action::Show(notification, Box::new(move |s| f(o(s))))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Show(notification, _) => {
+                write!(f, "Action::Show({notification:?})")
+            }
+        }
+    }
+}
+
+/// A desktop notification.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notification {
+    /// The summary of the [`Notification`]; normally its title.
+    pub summary: String,
+
+    /// The body of the [`Notification`].
+    pub body: Option<String>,
+
+    /// The name or path of the icon of the [`Notification`].
+    pub icon: Option<String>,
+
+    /// The [`Urgency`] of the [`Notification`].
+    pub urgency: Urgency,
+
+    /// How long the [`Notification`] should stay visible.
+    ///
+    /// If `None`, the platform decides.
+    pub timeout: Option<Duration>,
+}
+
+impl Notification {
+    /// Creates a new [`Notification`] with the given summary.
+    pub fn new(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the body of the [`Notification`].
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the icon of the [`Notification`].
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the [`Urgency`] of the [`Notification`].
+    pub fn urgency(mut self, urgency: Urgency) -> Self {
+        self.urgency = urgency;
+        self
+    }
+
+    /// Sets the timeout of the [`Notification`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// The urgency of a [`Notification`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Urgency {
+    /// A low priority notification.
+    Low,
+
+    /// A regular notification.
+    #[default]
+    Normal,
+
+    /// An important notification that should not be missed.
+    Critical,
+}
+
+/// An error while showing a [`Notification`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// Notifications are not supported by the current platform or shell.
+    #[error("notifications are not supported")]
+    Unsupported,
+
+    /// The notification service failed to show the [`Notification`].
+    #[error("the notification could not be shown: {0}")]
+    Failed(String),
+}
+
+/// Shows the given [`Notification`].
+pub fn show<Message>(
+    notification: Notification,
+    f: impl FnOnce(Result<(), Error>) -> Message + MaybeSend + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Notification(Action::Show(
+        notification,
+        Box::new(f),
+    )))
+}
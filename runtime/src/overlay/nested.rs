@@ -30,30 +30,35 @@ where
         &mut self,
         renderer: &Renderer,
         bounds: Size,
+        direction: layout::Direction,
     ) -> layout::Node {
         fn recurse<Message, Theme, Renderer>(
             element: &mut overlay::Element<'_, Message, Theme, Renderer>,
             renderer: &Renderer,
             bounds: Size,
+            direction: layout::Direction,
         ) -> layout::Node
         where
             Renderer: renderer::Renderer,
         {
-            let node = element.layout(renderer, bounds);
+            let node = element.layout(renderer, bounds, direction);
 
             if let Some(mut nested) =
                 element.overlay(Layout::new(&node), renderer)
             {
                 layout::Node::with_children(
                     node.size(),
-                    vec![node, recurse(&mut nested, renderer, bounds)],
+                    vec![
+                        node,
+                        recurse(&mut nested, renderer, bounds, direction),
+                    ],
                 )
             } else {
                 layout::Node::with_children(node.size(), vec![node])
             }
         }
 
-        recurse(&mut self.overlay, renderer, bounds)
+        recurse(&mut self.overlay, renderer, bounds, direction)
     }
 
     /// Draws the [`Nested`] overlay using the associated `Renderer`.
@@ -0,0 +1,426 @@
+//! Record, replay, and travel through the messages of an application.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A state that can be captured by a [`Recorder`].
+///
+/// A [`Recorder`] keeps a snapshot of the initial state of an application
+/// and rebuilds any later state by replaying the recorded messages on top of
+/// it.
+pub trait Snapshot {
+    /// Captures a copy of the current state.
+    fn snapshot(&self) -> Self;
+}
+
+/// A history of the messages processed by an application, starting from a
+/// snapshot of its initial state.
+///
+/// When the [`Recorder`] is moved back in time, the application is paused
+/// until it is moved forward to the last recorded message again.
+pub struct Recorder<State, Message> {
+    initial: State,
+    messages: Vec<Message>,
+    position: usize,
+    snapshot: fn(&State) -> State,
+    clone: fn(&Message) -> Message,
+    save: fn(&[Message], &Path) -> Result<(), Error>,
+}
+
+impl<State, Message> Recorder<State, Message> {
+    /// Creates a new, empty [`Recorder`] starting at the given initial
+    /// state.
+    pub fn new(initial: &State) -> Self
+    where
+        State: Snapshot,
+        Message: Clone + Serialize + DeserializeOwned,
+    {
+        Self {
+            initial: initial.snapshot(),
+            messages: Vec::new(),
+            position: 0,
+            snapshot: State::snapshot,
+            clone: Message::clone,
+            save: save::<Message>,
+        }
+    }
+
+    /// Creates a new [`Recorder`] that replays the given [`Recording`] on top
+    /// of the given initial state.
+    ///
+    /// The [`Recorder`] starts paused at the beginning of the [`Recording`].
+    pub fn replay(initial: &State, recording: Recording<Message>) -> Self
+    where
+        State: Snapshot,
+        Message: Clone + Serialize + DeserializeOwned,
+    {
+        Self {
+            messages: recording.messages,
+            ..Self::new(initial)
+        }
+    }
+
+    /// Records a message at the current position, discarding any messages
+    /// after it.
+    pub fn record(&mut self, message: &Message) {
+        self.messages.truncate(self.position);
+        self.messages.push((self.clone)(message));
+        self.position = self.messages.len();
+    }
+
+    /// Returns the amount of recorded messages that have been applied to the
+    /// current state.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the amount of recorded messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns true if no messages have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns true if the [`Recorder`] has been moved back in time.
+    ///
+    /// A paused application should ignore any new messages.
+    pub fn is_paused(&self) -> bool {
+        self.position < self.messages.len()
+    }
+
+    /// Returns the recorded messages.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Moves the [`Recorder`] one message back in time, rebuilding the given
+    /// state with the provided `update` logic.
+    ///
+    /// Returns false if there are no messages before the current position.
+    pub fn step_back(
+        &mut self,
+        state: &mut State,
+        update: impl FnMut(&mut State, Message),
+    ) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+
+        self.travel(self.position - 1, state, update);
+
+        true
+    }
+
+    /// Moves the [`Recorder`] one message forward in time, applying it to
+    /// the given state with the provided `update` logic.
+    ///
+    /// Returns false if there are no messages after the current position.
+    pub fn step_forward(
+        &mut self,
+        state: &mut State,
+        mut update: impl FnMut(&mut State, Message),
+    ) -> bool {
+        let Some(message) = self.messages.get(self.position) else {
+            return false;
+        };
+
+        update(state, (self.clone)(message));
+        self.position += 1;
+
+        true
+    }
+
+    /// Moves the [`Recorder`] to the given position, rebuilding the given
+    /// state from its initial snapshot with the provided `update` logic.
+    ///
+    /// The position is clamped to the amount of recorded messages.
+    pub fn travel(
+        &mut self,
+        position: usize,
+        state: &mut State,
+        mut update: impl FnMut(&mut State, Message),
+    ) {
+        let position = position.min(self.messages.len());
+
+        *state = (self.snapshot)(&self.initial);
+
+        for message in &self.messages[..position] {
+            update(state, (self.clone)(message));
+        }
+
+        self.position = position;
+    }
+
+    /// Returns a [`Recording`] of all the recorded messages.
+    pub fn recording(&self) -> Recording<Message> {
+        Recording {
+            messages: self.messages.iter().map(self.clone).collect(),
+        }
+    }
+
+    /// Saves all the recorded messages to the file at the given path.
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        (self.save)(&self.messages, path.as_ref())
+    }
+}
+
+impl<State, Message> fmt::Debug for Recorder<State, Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder")
+            .field("position", &self.position)
+            .field("len", &self.messages.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The recorded messages of an application, which can be saved to and
+/// loaded from a file.
+///
+/// A [`Recording`] is stored as a JSON array of messages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording<Message> {
+    messages: Vec<Message>,
+}
+
+impl<Message> Recording<Message> {
+    /// Returns the messages of the [`Recording`].
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Loads a [`Recording`] from the file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error>
+    where
+        Message: DeserializeOwned,
+    {
+        let contents = fs::read_to_string(path)?;
+
+        let messages = serde_json::from_str(&contents)
+            .map_err(|error| Error::InvalidFormat(error.to_string()))?;
+
+        Ok(Self { messages })
+    }
+
+    /// Saves the [`Recording`] to the file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        Message: Serialize,
+    {
+        save(&self.messages, path.as_ref())
+    }
+}
+
+impl<Message> From<Vec<Message>> for Recording<Message> {
+    fn from(messages: Vec<Message>) -> Self {
+        Self { messages }
+    }
+}
+
+fn save<Message: Serialize>(
+    messages: &[Message],
+    path: &Path,
+) -> Result<(), Error> {
+    let contents = serde_json::to_string_pretty(messages)
+        .map_err(|error| Error::InvalidFormat(error.to_string()))?;
+
+    fs::write(path, contents)?;
+
+    Ok(())
+}
+
+/// A timeline of recorded messages that can be traveled by a shell.
+///
+/// A shell may obtain the [`Timeline`] of an application to let users step
+/// through its history.
+pub trait Timeline {
+    /// Returns the amount of recorded messages that have been applied to the
+    /// current state.
+    fn position(&self) -> usize;
+
+    /// Returns the amount of recorded messages.
+    fn len(&self) -> usize;
+
+    /// Returns true if no messages have been recorded.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves the [`Timeline`] one message back in time.
+    fn step_back(&mut self);
+
+    /// Moves the [`Timeline`] one message forward in time.
+    fn step_forward(&mut self);
+
+    /// Saves all the recorded messages to the file at the given path.
+    fn export(&self, path: &Path) -> Result<(), Error>;
+}
+
+/// An error while saving or loading a [`Recording`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read or written.
+    #[error("the recording file could not be accessed: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The contents of the file are not a valid [`Recording`].
+    #[error("the recording is invalid: {0}")]
+    InvalidFormat(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    struct Counter {
+        value: i64,
+        history: Vec<i64>,
+    }
+
+    impl Snapshot for Counter {
+        fn snapshot(&self) -> Self {
+            self.clone()
+        }
+    }
+
+    fn update(counter: &mut Counter, message: i64) {
+        counter.value += message;
+        counter.history.push(counter.value);
+    }
+
+    fn record(
+        recorder: &mut Recorder<Counter, i64>,
+        counter: &mut Counter,
+        message: i64,
+    ) {
+        recorder.record(&message);
+        update(counter, message);
+    }
+
+    #[test]
+    fn traveling_rebuilds_every_state() {
+        let mut counter = Counter::default();
+        let mut recorder = Recorder::new(&counter);
+
+        let mut states = vec![counter.clone()];
+
+        for message in [1, 5, -3, 10] {
+            record(&mut recorder, &mut counter, message);
+            states.push(counter.clone());
+        }
+
+        assert_eq!(recorder.len(), 4);
+        assert!(!recorder.is_paused());
+
+        for position in (0..4).rev() {
+            assert!(recorder.step_back(&mut counter, update));
+            assert_eq!(recorder.position(), position);
+            assert_eq!(counter, states[position]);
+            assert!(recorder.is_paused());
+        }
+
+        assert!(!recorder.step_back(&mut counter, update));
+
+        for state in &states[1..] {
+            assert!(recorder.step_forward(&mut counter, update));
+            assert_eq!(&counter, state);
+        }
+
+        assert!(!recorder.step_forward(&mut counter, update));
+        assert!(!recorder.is_paused());
+    }
+
+    #[test]
+    fn recording_in_the_past_discards_the_future() {
+        let mut counter = Counter::default();
+        let mut recorder = Recorder::new(&counter);
+
+        for message in [1, 2, 3] {
+            record(&mut recorder, &mut counter, message);
+        }
+
+        recorder.travel(1, &mut counter, update);
+        record(&mut recorder, &mut counter, 7);
+
+        assert_eq!(recorder.messages(), &[1, 7]);
+        assert_eq!(recorder.position(), 2);
+        assert_eq!(counter.value, 8);
+    }
+
+    #[test]
+    fn replays_are_deterministic() {
+        let mut counter = Counter::default();
+        let mut recorder = Recorder::new(&counter);
+
+        for message in [4, -2, 9, 1] {
+            record(&mut recorder, &mut counter, message);
+        }
+
+        let mut replayed = Counter::default();
+        let mut replay = Recorder::replay(&replayed, recorder.recording());
+
+        assert_eq!(replay.position(), 0);
+        assert!(replay.is_paused());
+
+        replay.travel(usize::MAX, &mut replayed, update);
+
+        assert_eq!(replay.position(), 4);
+        assert_eq!(replayed, counter);
+
+        replay.travel(4, &mut replayed, update);
+
+        assert_eq!(replayed, counter);
+    }
+
+    #[test]
+    fn recordings_are_saved_as_json_arrays() {
+        let path = std::env::temp_dir()
+            .join(format!("iced-recorder-test-{}.json", std::process::id()));
+
+        let mut counter = Counter::default();
+        let mut recorder = Recorder::new(&counter);
+
+        for message in [3, -1] {
+            record(&mut recorder, &mut counter, message);
+        }
+
+        recorder.export(&path).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let loaded = Recording::<i64>::load(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents, "[\n  3,\n  -1\n]");
+        assert_eq!(loaded, recorder.recording());
+        assert_eq!(loaded.messages(), &[3, -1]);
+    }
+
+    #[test]
+    fn invalid_recordings_are_rejected() {
+        let path = std::env::temp_dir()
+            .join(format!("iced-recorder-invalid-{}.json", std::process::id()));
+
+        fs::write(&path, "{ \"messages\": 3 }").unwrap();
+
+        let result = Recording::<i64>::load(&path);
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(Error::InvalidFormat(_))));
+    }
+}
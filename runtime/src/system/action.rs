@@ -1,6 +1,6 @@
 use crate::system;
 
-use iced_futures::MaybeSend;
+use iced_futures::{MaybeSend, MaybeSync};
 use std::fmt;
 
 /// An operation to be performed on the system.
@@ -17,7 +17,7 @@ impl<T> Action<T> {
     /// Maps the output of a system [`Action`] using the provided closure.
     pub fn map<A>(
         self,
-        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
     ) -> Action<A>
     where
         T: 'static,
@@ -1,5 +1,7 @@
 //! Implement your own event loop to drive a user interface.
 use crate::core::event::{self, Event};
+use crate::core::i18n;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -8,6 +10,8 @@ use crate::core::window;
 use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
 use crate::overlay;
 
+use std::any::Any;
+
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
 /// It can be updated and drawn.
@@ -27,6 +31,8 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    direction: layout::Direction,
+    catalog: i18n::Catalog,
 }
 
 impl<'a, Message, Theme, Renderer> UserInterface<'a, Message, Theme, Renderer>
@@ -89,6 +95,32 @@ where
         bounds: Size,
         cache: Cache,
         renderer: &mut Renderer,
+    ) -> Self {
+        Self::build_with_direction(
+            root,
+            bounds,
+            layout::Direction::default(),
+            cache,
+            renderer,
+        )
+    }
+
+    /// Builds a user interface for an [`Element`], laying it out in the
+    /// given [`layout::Direction`].
+    ///
+    /// The [`i18n::Catalog`] that is current while the [`UserInterface`] is
+    /// built is made current again whenever it is updated, drawn or laid
+    /// out.
+    ///
+    /// See [`UserInterface::build`] for more details.
+    pub fn build_with_direction<
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    >(
+        root: E,
+        bounds: Size,
+        direction: layout::Direction,
+        cache: Cache,
+        renderer: &mut Renderer,
     ) -> Self {
         let root = root.into();
 
@@ -98,7 +130,7 @@ where
         let base = root.as_widget().layout(
             &mut state,
             renderer,
-            &layout::Limits::new(Size::ZERO, bounds),
+            &layout::Limits::new(Size::ZERO, bounds).with_direction(direction),
         );
 
         UserInterface {
@@ -107,6 +139,8 @@ where
             state,
             overlay: None,
             bounds,
+            direction,
+            catalog: i18n::current(),
         }
     }
 
@@ -185,6 +219,8 @@ where
     ) -> (State, Vec<event::Status>) {
         use std::mem::ManuallyDrop;
 
+        let _catalog = i18n::enter(&self.catalog);
+
         let mut outdated = false;
         let mut redraw_request = None;
 
@@ -204,7 +240,7 @@ where
             let bounds = self.bounds;
 
             let mut overlay = manual_overlay.as_mut().unwrap();
-            let mut layout = overlay.layout(renderer, bounds);
+            let mut layout = overlay.layout(renderer, bounds, self.direction);
             let mut event_statuses = Vec::new();
 
             for event in events.iter().cloned() {
@@ -237,7 +273,8 @@ where
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
                         renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
+                        &layout::Limits::new(Size::ZERO, self.bounds)
+                            .with_direction(self.direction),
                     );
 
                     manual_overlay = ManuallyDrop::new(
@@ -259,7 +296,8 @@ where
                     overlay = manual_overlay.as_mut().unwrap();
 
                     shell.revalidate_layout(|| {
-                        layout = overlay.layout(renderer, bounds);
+                        layout =
+                            overlay.layout(renderer, bounds, self.direction);
                     });
                 }
 
@@ -308,6 +346,20 @@ where
 
                 let mut shell = Shell::new(messages);
 
+                let traversal = match &event {
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                        modifiers,
+                        ..
+                    }) if !(modifiers.control()
+                        || modifiers.alt()
+                        || modifiers.logo()) =>
+                    {
+                        Some(modifiers.shift())
+                    }
+                    _ => None,
+                };
+
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
                     event,
@@ -323,6 +375,24 @@ where
                     self.overlay = None;
                 }
 
+                // Tab and Shift+Tab move the focus by default, unless some
+                // widget decided to handle them on its own. They are only
+                // captured if there is any focus to move.
+                let is_focus_moved = match traversal {
+                    Some(backwards)
+                        if matches!(event_status, event::Status::Ignored) =>
+                    {
+                        self.traverse_focus(renderer, backwards)
+                    }
+                    _ => false,
+                };
+
+                let event_status = if is_focus_moved {
+                    event::Status::Captured
+                } else {
+                    event_status
+                };
+
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
                         redraw_request = Some(at);
@@ -337,12 +407,26 @@ where
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
                         renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
+                        &layout::Limits::new(Size::ZERO, self.bounds)
+                            .with_direction(self.direction),
                     );
 
                     self.overlay = None;
                 });
 
+                // Moving the focus with the keyboard scrolls the newly
+                // focused widget into view.
+                if is_focus_moved {
+                    let _ = self.run_operation::<()>(
+                        renderer,
+                        Box::new(
+                            widget::operation::scrollable::scroll_focused_into_view(
+                                widget::operation::scrollable::Placement::Nearest,
+                            ),
+                        ),
+                    );
+                }
+
                 if shell.are_widgets_invalid() {
                     outdated = true;
                 }
@@ -441,6 +525,8 @@ where
         style: &renderer::Style,
         cursor: mouse::Cursor,
     ) -> mouse::Interaction {
+        let _catalog = i18n::enter(&self.catalog);
+
         // TODO: Move to shell level (?)
         renderer.clear();
 
@@ -457,10 +543,9 @@ where
             )
             .map(overlay::Nested::new)
         {
-            let overlay_layout = self
-                .overlay
-                .take()
-                .unwrap_or_else(|| overlay.layout(renderer, self.bounds));
+            let overlay_layout = self.overlay.take().unwrap_or_else(|| {
+                overlay.layout(renderer, self.bounds, self.direction)
+            });
 
             let cursor = if cursor
                 .position()
@@ -563,11 +648,19 @@ where
     }
 
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
-    pub fn operate(
+    ///
+    /// The output of the [`widget::Operation`] does not need to match the
+    /// `Message` of the [`UserInterface`].
+    pub fn operate<T>(
         &mut self,
         renderer: &Renderer,
-        operation: &mut dyn widget::Operation<Message>,
+        operation: &mut dyn widget::Operation<T>,
     ) {
+        let _catalog = i18n::enter(&self.catalog);
+
+        let operation: &mut dyn widget::Operation<Message> =
+            &mut MapOperation { operation };
+
         self.root.as_widget().operate(
             &mut self.state,
             Layout::new(&self.base),
@@ -587,7 +680,8 @@ where
             .map(overlay::Nested::new)
         {
             if self.overlay.is_none() {
-                self.overlay = Some(overlay.layout(renderer, self.bounds));
+                self.overlay =
+                    Some(overlay.layout(renderer, self.bounds, self.direction));
             }
 
             overlay.operate(
@@ -598,10 +692,69 @@ where
         }
     }
 
+    /// Moves the focus to the next focusable widget of the [`UserInterface`],
+    /// or to the previous one if `backwards` is true.
+    ///
+    /// Returns false, leaving the focus untouched, if there are no focusable
+    /// widgets.
+    fn traverse_focus(&mut self, renderer: &Renderer, backwards: bool) -> bool {
+        use widget::operation::focusable;
+
+        let count = self
+            .run_operation(renderer, Box::new(focusable::count(Counted)))
+            .unwrap_or_default();
+
+        if count.total == 0 {
+            return false;
+        }
+
+        let operation: Box<dyn widget::Operation<focusable::Count>> =
+            if backwards {
+                Box::new(focusable::focus_previous())
+            } else {
+                Box::new(focusable::focus_next())
+            };
+
+        let _ = self.run_operation(renderer, operation);
+
+        true
+    }
+
+    /// Runs the given [`widget::Operation`] on the [`UserInterface`],
+    /// following any chained operations until it finishes, and returns its
+    /// output, if any.
+    fn run_operation<T>(
+        &mut self,
+        renderer: &Renderer,
+        mut operation: Box<dyn widget::Operation<T>>,
+    ) -> Option<T> {
+        loop {
+            self.operate(renderer, operation.as_mut());
+
+            match operation.finish() {
+                widget::operation::Outcome::None => return None,
+                widget::operation::Outcome::Some(output) => {
+                    return Some(output);
+                }
+                widget::operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+            }
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        let _catalog = i18n::enter(&self.catalog);
+
+        Self::build_with_direction(
+            self.root,
+            bounds,
+            self.direction,
+            Cache { state: self.state },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -648,3 +801,76 @@ pub enum State {
         redraw_request: Option<window::RedrawRequest>,
     },
 }
+
+/// An [`widget::Operation`] that outputs the focusable widgets counted
+/// before it.
+struct Counted(widget::operation::focusable::Count);
+
+impl widget::Operation<widget::operation::focusable::Count> for Counted {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(
+            &mut dyn widget::Operation<widget::operation::focusable::Count>,
+        ),
+    ) {
+    }
+
+    fn finish(
+        &self,
+    ) -> widget::operation::Outcome<widget::operation::focusable::Count> {
+        widget::operation::Outcome::Some(self.0)
+    }
+}
+
+struct MapOperation<'a, B> {
+    operation: &'a mut dyn widget::Operation<B>,
+}
+
+impl<'a, T, B> widget::Operation<T> for MapOperation<'a, B> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn widget::Operation<T>),
+    ) {
+        self.operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
+    fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        self.operation.bounds(id, bounds);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn widget::operation::Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn widget::operation::Scrollable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn widget::operation::TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+        self.operation.custom(state, id);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::core::window::{Icon, Id, Level, Mode, Settings, UserAttention};
 use crate::core::{Point, Size};
-use crate::futures::MaybeSend;
+use crate::futures::{MaybeSend, MaybeSync};
 use crate::window::Screenshot;
 
 use raw_window_handle::WindowHandle;
@@ -115,7 +115,7 @@ impl<T> Action<T> {
     /// Maps the output of a window [`Action`] using the provided closure.
     pub fn map<A>(
         self,
-        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+        f: impl Fn(T) -> A + 'static + MaybeSend + MaybeSync,
     ) -> Action<A>
     where
         T: 'static,
@@ -1,6 +1,6 @@
 //! Build interactive cross-platform applications.
 use crate::shell::application;
-use crate::{Command, Element, Executor, Settings, Subscription};
+use crate::{layout, Command, Element, Executor, Settings, Subscription};
 
 pub use application::{Appearance, DefaultStyle};
 
@@ -181,6 +181,32 @@ where
         1.0
     }
 
+    /// Returns the layout [`Direction`] of the [`Application`].
+    ///
+    /// Right-to-left layouts mirror rows, alignments, paddings and
+    /// scrollbars.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    ///
+    /// [`Direction`]: layout::Direction
+    /// [`Direction::LeftToRight`]: layout::Direction::LeftToRight
+    fn layout_direction(&self) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
+
+    /// Returns the [`Timeline`] of recorded messages of the [`Application`],
+    /// if it supports time-travel debugging.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Timeline`]: crate::runtime::recorder::Timeline
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        None
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -284,4 +310,15 @@ where
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
+
+    fn layout_direction(&self) -> layout::Direction {
+        self.0.layout_direction()
+    }
+
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        self.0.timeline()
+    }
 }
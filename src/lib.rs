@@ -200,6 +200,7 @@ pub mod advanced;
 pub mod multi_window;
 
 pub use crate::core::alignment;
+pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
@@ -217,6 +218,38 @@ pub mod clipboard {
     };
 }
 
+#[cfg(feature = "dialog")]
+pub mod dialog {
+    //! Show native file dialogs.
+    pub use crate::runtime::dialog::{
+        open_file, open_files, pick_folder, save_file, FileDialog, Filter,
+    };
+}
+
+#[cfg(feature = "notification")]
+pub mod notification {
+    //! Show desktop notifications.
+    pub use crate::runtime::notification::{
+        show, Error, Notification, Urgency,
+    };
+}
+
+#[cfg(feature = "time-travel")]
+pub mod recorder {
+    //! Record and replay the messages of a [`Program`](crate::Program).
+    pub use crate::runtime::recorder::{Error, Recording, Snapshot};
+}
+
+pub mod i18n {
+    //! Translate your application and format numbers and dates.
+    pub use crate::runtime::i18n::*;
+}
+
+pub mod layout {
+    //! Choose the direction in which your user interface flows.
+    pub use crate::core::layout::Direction;
+}
+
 pub mod executor {
     //! Choose your preferred executor to power your application.
     pub use iced_futures::Executor;
@@ -262,7 +295,7 @@ pub mod mouse {
 
 pub mod command {
     //! Run asynchronous actions.
-    pub use crate::runtime::command::{channel, Command};
+    pub use crate::runtime::command::{channel, Command, Handle};
 }
 
 pub mod subscription {
@@ -270,6 +303,12 @@ pub mod subscription {
     pub use iced_futures::subscription::{
         channel, run, run_with_id, unfold, Subscription,
     };
+
+    #[cfg(feature = "watch")]
+    pub use iced_futures::subscription::{watch, watch_path};
+
+    #[cfg(all(feature = "watch", feature = "theme-file"))]
+    pub use iced_futures::subscription::watch_theme;
 }
 
 #[cfg(feature = "system")]
@@ -1,6 +1,6 @@
 //! Leverage multi-window support in your application.
 use crate::window;
-use crate::{Command, Element, Executor, Settings, Subscription};
+use crate::{layout, Command, Element, Executor, Settings, Subscription};
 
 pub use crate::application::{Appearance, DefaultStyle};
 
@@ -160,6 +160,30 @@ where
         1.0
     }
 
+    /// Returns the layout [`Direction`] of the `window` of the [`Application`].
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    ///
+    /// [`Direction`]: layout::Direction
+    /// [`Direction::LeftToRight`]: layout::Direction::LeftToRight
+    #[allow(unused_variables)]
+    fn layout_direction(&self, window: window::Id) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
+
+    /// Returns the [`Timeline`] of recorded messages of the [`Application`],
+    /// if it supports time-travel debugging.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Timeline`]: crate::runtime::recorder::Timeline
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        None
+    }
+
     /// Runs the multi-window [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -251,4 +275,15 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         self.0.scale_factor(window)
     }
+
+    fn layout_direction(&self, window: window::Id) -> layout::Direction {
+        self.0.layout_direction(window)
+    }
+
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        self.0.timeline()
+    }
 }
@@ -33,11 +33,22 @@
 use crate::application::Application;
 use crate::executor::{self, Executor};
 use crate::window;
-use crate::{Command, Element, Font, Result, Settings, Size, Subscription};
+use crate::{
+    layout, Command, Element, Font, Result, Settings, Size, Subscription,
+};
 
 pub use crate::application::{Appearance, DefaultStyle};
 
+#[cfg(feature = "time-travel")]
+use crate::runtime::recorder::{self, Recorder, Recording, Snapshot, Timeline};
+#[cfg(feature = "time-travel")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "time-travel")]
+use serde::Serialize;
+
 use std::borrow::Cow;
+#[cfg(feature = "time-travel")]
+use std::path::Path;
 
 /// Creates an iced [`Program`] given its title, update, and view logic.
 ///
@@ -178,6 +189,8 @@ impl<P: Definition> Program<P> {
         struct Instance<P: Definition, I> {
             program: P,
             state: P::State,
+            #[cfg(feature = "time-travel")]
+            recorder: Option<Recorder<P::State, P::Message>>,
             _initialize: PhantomData<I>,
         }
 
@@ -193,10 +206,15 @@ impl<P: Definition> Program<P> {
                 let state = initialize();
                 let command = program.load();
 
+                #[cfg(feature = "time-travel")]
+                let recorder = program.recorder(&state);
+
                 (
                     Self {
                         program,
                         state,
+                        #[cfg(feature = "time-travel")]
+                        recorder,
                         _initialize: PhantomData,
                     },
                     command,
@@ -211,6 +229,15 @@ impl<P: Definition> Program<P> {
                 &mut self,
                 message: Self::Message,
             ) -> Command<Self::Message> {
+                #[cfg(feature = "time-travel")]
+                if let Some(recorder) = &mut self.recorder {
+                    if recorder.is_paused() {
+                        return Command::none();
+                    }
+
+                    recorder.record(&message);
+                }
+
                 self.program.update(&mut self.state, message)
             }
 
@@ -232,6 +259,67 @@ impl<P: Definition> Program<P> {
             fn style(&self, theme: &Self::Theme) -> Appearance {
                 self.program.style(&self.state, theme)
             }
+
+            fn layout_direction(&self) -> layout::Direction {
+                self.program.layout_direction(&self.state)
+            }
+
+            #[cfg(feature = "time-travel")]
+            fn timeline(&mut self) -> Option<&mut dyn Timeline> {
+                if self.recorder.is_some() {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[cfg(feature = "time-travel")]
+        impl<P: Definition, I> Timeline for Instance<P, I> {
+            fn position(&self) -> usize {
+                self.recorder.as_ref().map_or(0, Recorder::position)
+            }
+
+            fn len(&self) -> usize {
+                self.recorder.as_ref().map_or(0, Recorder::len)
+            }
+
+            fn step_back(&mut self) {
+                if let Some(recorder) = &mut self.recorder {
+                    let program = &self.program;
+
+                    let _ = recorder.step_back(
+                        &mut self.state,
+                        |state, message| {
+                            // Commands were already run when the message was
+                            // first processed
+                            let _ = program.update(state, message);
+                        },
+                    );
+                }
+            }
+
+            fn step_forward(&mut self) {
+                if let Some(recorder) = &mut self.recorder {
+                    let program = &self.program;
+
+                    let _ = recorder.step_forward(
+                        &mut self.state,
+                        |state, message| {
+                            let _ = program.update(state, message);
+                        },
+                    );
+                }
+            }
+
+            fn export(
+                &self,
+                path: &Path,
+            ) -> std::result::Result<(), recorder::Error> {
+                self.recorder
+                    .as_ref()
+                    .map_or(Ok(()), |recorder| recorder.export(path))
+            }
         }
 
         let Self { raw, settings } = self;
@@ -400,6 +488,65 @@ impl<P: Definition> Program<P> {
             settings: self.settings,
         }
     }
+
+    /// Sets the layout direction logic of the [`Program`].
+    ///
+    /// Right-to-left layouts mirror rows, alignments, paddings and
+    /// scrollbars.
+    pub fn layout_direction(
+        self,
+        f: impl Fn(&P::State) -> layout::Direction,
+    ) -> Program<
+        impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Program {
+            raw: with_layout_direction(self.raw, f),
+            settings: self.settings,
+        }
+    }
+
+    /// Enables time-travel debugging for the [`Program`].
+    ///
+    /// Every message processed by the [`Program`] will be recorded, and the
+    /// debug view will let you step through them (press F9 and F10) and
+    /// export them to a file (press F8).
+    #[cfg(feature = "time-travel")]
+    pub fn time_travel(
+        self,
+    ) -> Program<
+        impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::State: Snapshot,
+        P::Message: Clone + Serialize + DeserializeOwned,
+    {
+        Program {
+            raw: with_time_travel(self.raw, None),
+            settings: self.settings,
+        }
+    }
+
+    /// Replays the given [`Recording`] in the [`Program`].
+    ///
+    /// The [`Program`] will start paused at its initial state, and you can
+    /// step through the messages of the [`Recording`] like with
+    /// [`time_travel`](Self::time_travel).
+    #[cfg(feature = "time-travel")]
+    pub fn replay(
+        self,
+        recording: Recording<P::Message>,
+    ) -> Program<
+        impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::State: Snapshot,
+        P::Message: Clone + Serialize + DeserializeOwned,
+    {
+        Program {
+            raw: with_time_travel(self.raw, Some(recording)),
+            settings: self.settings,
+        }
+    }
 }
 
 /// The internal definition of a [`Program`].
@@ -451,6 +598,18 @@ pub trait Definition: Sized {
     fn style(&self, _state: &Self::State, theme: &Self::Theme) -> Appearance {
         DefaultStyle::default_style(theme)
     }
+
+    fn layout_direction(&self, _state: &Self::State) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
+
+    #[cfg(feature = "time-travel")]
+    fn recorder(
+        &self,
+        _state: &Self::State,
+    ) -> Option<Recorder<Self::State, Self::Message>> {
+        None
+    }
 }
 
 fn with_title<P: Definition>(
@@ -513,6 +672,18 @@ fn with_title<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
     }
 
     WithTitle { program, title }
@@ -577,6 +748,18 @@ fn with_load<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
     }
 
     WithLoad { program, load: f }
@@ -641,6 +824,18 @@ fn with_subscription<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
     }
 
     WithSubscription {
@@ -708,6 +903,18 @@ fn with_theme<P: Definition>(
         ) -> Appearance {
             self.program.style(state, theme)
         }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -772,11 +979,185 @@ fn with_style<P: Definition>(
         fn theme(&self, state: &Self::State) -> Self::Theme {
             self.program.theme(state)
         }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
     }
 
     WithStyle { program, style: f }
 }
 
+fn with_layout_direction<P: Definition>(
+    program: P,
+    f: impl Fn(&P::State) -> layout::Direction,
+) -> impl Definition<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithLayoutDirection<P, F> {
+        program: P,
+        layout_direction: F,
+    }
+
+    impl<P: Definition, F> Definition for WithLayoutDirection<P, F>
+    where
+        F: Fn(&P::State) -> layout::Direction,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Executor = P::Executor;
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            (self.layout_direction)(state)
+        }
+
+        fn load(&self) -> Command<Self::Message> {
+            self.program.load()
+        }
+
+        fn title(&self, state: &Self::State) -> String {
+            self.program.title(state)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Command<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+        ) -> Element<'a, Self::Message, Self::Theme> {
+            self.program.view(state)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(&self, state: &Self::State) -> Self::Theme {
+            self.program.theme(state)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+        #[cfg(feature = "time-travel")]
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            self.program.recorder(state)
+        }
+    }
+
+    WithLayoutDirection {
+        program,
+        layout_direction: f,
+    }
+}
+
+#[cfg(feature = "time-travel")]
+fn with_time_travel<P: Definition>(
+    program: P,
+    recording: Option<Recording<P::Message>>,
+) -> impl Definition<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P::State: Snapshot,
+    P::Message: Clone + Serialize + DeserializeOwned,
+{
+    struct WithTimeTravel<P: Definition> {
+        program: P,
+        recording: Option<Recording<P::Message>>,
+    }
+
+    impl<P: Definition> Definition for WithTimeTravel<P>
+    where
+        P::State: Snapshot,
+        P::Message: Clone + Serialize + DeserializeOwned,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Executor = P::Executor;
+
+        fn recorder(
+            &self,
+            state: &Self::State,
+        ) -> Option<Recorder<Self::State, Self::Message>> {
+            Some(match &self.recording {
+                Some(recording) => Recorder::replay(state, recording.clone()),
+                None => Recorder::new(state),
+            })
+        }
+
+        fn load(&self) -> Command<Self::Message> {
+            self.program.load()
+        }
+
+        fn title(&self, state: &Self::State) -> String {
+            self.program.title(state)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Command<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+        ) -> Element<'a, Self::Message, Self::Theme> {
+            self.program.view(state)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(&self, state: &Self::State) -> Self::Theme {
+            self.program.theme(state)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn layout_direction(&self, state: &Self::State) -> layout::Direction {
+            self.program.layout_direction(state)
+        }
+    }
+
+    WithTimeTravel { program, recording }
+}
+
 /// The title logic of some [`Program`].
 ///
 /// This trait is implemented both for `&static str` and
@@ -1,13 +1,17 @@
 //! Allow your users to perform actions by pressing a button.
+use crate::core::animation::{self, Interpolate, StatusTransition};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::theme::palette;
+use crate::core::theme::{self, palette, Class};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
@@ -51,6 +55,7 @@ where
     Renderer: crate::core::Renderer,
 {
     content: Element<'a, Message, Theme, Renderer>,
+    id: Option<Id>,
     on_press: Option<Message>,
     width: Length,
     height: Length,
@@ -75,6 +80,7 @@ where
 
         Button {
             content,
+            id: None,
             on_press: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
@@ -84,6 +90,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -128,17 +140,72 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`Button`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
     pub fn clip(mut self, clip: bool) -> Self {
         self.clip = clip;
         self
     }
+
+    fn status(
+        &self,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if is_mouse_over && state.is_pressed {
+            Status::Pressed
+        } else if state.is_focused {
+            Status::Focused
+        } else if is_mouse_over {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Default)]
 struct State {
     is_pressed: bool,
+    is_focused: bool,
+    transition: StatusTransition<Status>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -198,6 +265,16 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        if self.on_press.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.bounds(self.id.as_ref(), layout.bounds());
+
+            operation.focusable(state, self.id.as_ref());
+        } else {
+            tree.state.downcast_mut::<State>().is_focused = false;
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -219,6 +296,11 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let status = self.status(state, layout, cursor);
+
+        state.transition.update(&event, status, shell);
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
@@ -235,12 +317,14 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_focused = false;
+
                 if self.on_press.is_some() {
                     let bounds = layout.bounds();
 
                     if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
-
                         state.is_pressed = true;
 
                         return event::Status::Captured;
@@ -270,6 +354,20 @@ where
 
                 state.is_pressed = false;
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(on_press) = self.on_press.clone() {
+                    if state.is_focused {
+                        shell.publish(on_press);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -288,23 +386,15 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let is_mouse_over = cursor.is_over(bounds);
 
-        let status = if self.on_press.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            let state = tree.state.downcast_ref::<State>();
-
-            if state.is_pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
-            }
-        } else {
-            Status::Active
-        };
+        let state = tree.state.downcast_ref::<State>();
+        let status = self.status(state, layout, cursor);
 
-        let styling = (self.style)(theme, status);
+        let styling = state.transition.appearance(
+            status,
+            |status| (self.style)(theme, status),
+            |appearance| appearance.transition,
+        );
 
         if styling.background.is_some()
             || styling.border.width > 0.0
@@ -403,6 +493,8 @@ pub enum Status {
     Hovered,
     /// The [`Button`] is being pressed.
     Pressed,
+    /// The [`Button`] can be pressed and it has keyboard focus.
+    Focused,
     /// The [`Button`] cannot be pressed.
     Disabled,
 }
@@ -418,6 +510,10 @@ pub struct Appearance {
     pub border: Border,
     /// The [`Shadow`] of the butoon.
     pub shadow: Shadow,
+    /// The [`Transition`] used to animate into this [`Appearance`], if any.
+    ///
+    /// [`Transition`]: animation::Transition
+    pub transition: Option<animation::Transition>,
 }
 
 impl Appearance {
@@ -428,6 +524,16 @@ impl Appearance {
             ..self
         }
     }
+
+    /// Updates the [`Appearance`] with the given [`Transition`].
+    ///
+    /// [`Transition`]: animation::Transition
+    pub fn with_transition(self, transition: animation::Transition) -> Self {
+        Self {
+            transition: Some(transition),
+            ..self
+        }
+    }
 }
 
 impl std::default::Default for Appearance {
@@ -437,6 +543,19 @@ impl std::default::Default for Appearance {
             text_color: Color::BLACK,
             border: Border::default(),
             shadow: Shadow::default(),
+            transition: None,
+        }
+    }
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            text_color: self.text_color.interpolate(&other.text_color, t),
+            border: self.border.interpolate(&other.border, t),
+            shadow: self.shadow.interpolate(&other.shadow, t),
+            transition: other.transition,
         }
     }
 }
@@ -456,6 +575,36 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Button`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `primary`, `secondary`, `success`, `danger`,
+/// and `text` classes, on top of any [`Classes`] registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        let style: fn(&Theme, Status) -> Appearance = match class.as_str() {
+            "primary" => primary,
+            "secondary" => secondary,
+            "success" => success,
+            "danger" => danger,
+            "text" => text,
+            _ => return None,
+        };
+
+        Some(style(self, status))
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -473,14 +622,17 @@ pub fn primary(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
     let base = styled(palette.primary.strong);
 
-    match status {
+    let appearance = match status {
         Status::Active | Status::Pressed => base,
         Status::Hovered => Appearance {
             background: Some(Background::Color(palette.primary.base.color)),
             ..base
         },
+        Status::Focused => focused(base, palette.background.base.text),
         Status::Disabled => disabled(base),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A secondary button; denoting a complementary action.
@@ -488,14 +640,17 @@ pub fn secondary(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
     let base = styled(palette.secondary.base);
 
-    match status {
+    let appearance = match status {
         Status::Active | Status::Pressed => base,
         Status::Hovered => Appearance {
             background: Some(Background::Color(palette.secondary.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette.background.base.text),
         Status::Disabled => disabled(base),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A success button; denoting a good outcome.
@@ -503,14 +658,17 @@ pub fn success(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
     let base = styled(palette.success.base);
 
-    match status {
+    let appearance = match status {
         Status::Active | Status::Pressed => base,
         Status::Hovered => Appearance {
             background: Some(Background::Color(palette.success.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette.background.base.text),
         Status::Disabled => disabled(base),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A danger button; denoting a destructive action.
@@ -518,14 +676,17 @@ pub fn danger(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
     let base = styled(palette.danger.base);
 
-    match status {
+    let appearance = match status {
         Status::Active | Status::Pressed => base,
         Status::Hovered => Appearance {
             background: Some(Background::Color(palette.danger.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette.background.base.text),
         Status::Disabled => disabled(base),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A text button; useful for links.
@@ -537,14 +698,17 @@ pub fn text(theme: &Theme, status: Status) -> Appearance {
         ..Appearance::default()
     };
 
-    match status {
+    let appearance = match status {
         Status::Active | Status::Pressed => base,
         Status::Hovered => Appearance {
             text_color: palette.background.base.text.scale_alpha(0.8),
             ..base
         },
+        Status::Focused => focused(base, palette.background.base.text),
         Status::Disabled => disabled(base),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 fn styled(pair: palette::Pair) -> Appearance {
@@ -556,6 +720,17 @@ fn styled(pair: palette::Pair) -> Appearance {
     }
 }
 
+fn focused(appearance: Appearance, ring: Color) -> Appearance {
+    Appearance {
+        border: Border {
+            color: ring,
+            width: 2.0,
+            ..appearance.border
+        },
+        ..appearance
+    }
+}
+
 fn disabled(appearance: Appearance) -> Appearance {
     Appearance {
         background: appearance
@@ -565,3 +740,33 @@ fn disabled(appearance: Appearance) -> Appearance {
         ..appearance
     }
 }
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => theme::Status::Active,
+            Status::Hovered => theme::Status::Hovered,
+            Status::Pressed => theme::Status::Pressed,
+            Status::Focused => theme::Status::Focused,
+            Status::Disabled => theme::Status::Disabled,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.button.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            background: properties
+                .background
+                .map(Background::Color)
+                .or(self.background),
+            text_color: properties.text_color(self.text_color),
+            border: properties.border(self.border),
+            ..self
+        }
+    }
+}
@@ -1,14 +1,19 @@
 //! Show toggle controls using checkboxes.
 use crate::core::alignment;
+use crate::core::animation::{self, Interpolate, StatusTransition};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
-use crate::core::theme::palette;
+use crate::core::theme::{self, palette, Class};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
@@ -40,6 +45,7 @@ pub struct Checkbox<
 > where
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     is_checked: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: String,
@@ -74,6 +80,7 @@ where
         Theme: DefaultStyle + 'a,
     {
         Checkbox {
+            id: None,
             is_checked,
             on_toggle: None,
             label: label.into(),
@@ -95,6 +102,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Checkbox`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the function that will be called when the [`Checkbox`] is toggled.
     /// It will receive the new state of the [`Checkbox`] and must produce a
     /// `Message`.
@@ -181,6 +194,64 @@ where
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`Checkbox`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn status<P: text::Paragraph>(
+        &self,
+        state: &State<P>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_checked = self.is_checked;
+
+        if self.on_toggle.is_none() {
+            Status::Disabled { is_checked }
+        } else if state.is_focused {
+            Status::Focused { is_checked }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_checked }
+        } else {
+            Status::Active { is_checked }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+    transition: StatusTransition<Status>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -189,11 +260,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -214,12 +285,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -236,9 +306,26 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if self.on_toggle.is_some() {
+            operation.bounds(self.id.as_ref(), layout.bounds());
+            operation.focusable(state, self.id.as_ref());
+        } else {
+            state.is_focused = false;
+        }
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -247,9 +334,16 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.status(state, layout, cursor);
+
+        state.transition.update(&event, status, shell);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over {
@@ -259,6 +353,15 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish((on_toggle)(!self.is_checked));
+                    return event::Status::Captured;
+                }
+            }
             _ => {}
         }
 
@@ -290,21 +393,16 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let is_mouse_over = cursor.is_over(layout.bounds());
-        let is_disabled = self.on_toggle.is_none();
-        let is_checked = self.is_checked;
-
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let mut children = layout.children();
 
-        let status = if is_disabled {
-            Status::Disabled { is_checked }
-        } else if is_mouse_over {
-            Status::Hovered { is_checked }
-        } else {
-            Status::Active { is_checked }
-        };
+        let status = self.status(state, layout, cursor);
 
-        let appearance = (self.style)(theme, status);
+        let appearance = state.transition.appearance(
+            status,
+            |status| (self.style)(theme, status),
+            |appearance| appearance.transition,
+        );
 
         {
             let layout = children.next().unwrap();
@@ -354,7 +452,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance {
                     color: appearance.text_color,
                 },
@@ -406,6 +504,11 @@ pub enum Status {
         /// Indicates if the [`Checkbox`] is currently checked.
         is_checked: bool,
     },
+    /// The [`Checkbox`] can be interacted with and it has keyboard focus.
+    Focused {
+        /// Indicates if the [`Checkbox`] is currently checked.
+        is_checked: bool,
+    },
     /// The [`Checkbox`] cannot be interacted with.
     Disabled {
         /// Indicates if the [`Checkbox`] is currently checked.
@@ -424,6 +527,22 @@ pub struct Appearance {
     pub border: Border,
     /// The text [`Color`] of the checkbox.
     pub text_color: Option<Color>,
+    /// The [`Transition`] used to animate into this [`Appearance`], if any.
+    ///
+    /// [`Transition`]: animation::Transition
+    pub transition: Option<animation::Transition>,
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            icon_color: self.icon_color.interpolate(&other.icon_color, t),
+            border: self.border.interpolate(&other.border, t),
+            text_color: self.text_color.interpolate(&other.text_color, t),
+            transition: other.transition,
+        }
+    }
 }
 
 /// The style of a [`Checkbox`].
@@ -441,6 +560,35 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Checkbox`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `primary`, `secondary`, `success`, and
+/// `danger` classes, on top of any [`Classes`] registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        let style: fn(&Theme, Status) -> Appearance = match class.as_str() {
+            "primary" => primary,
+            "secondary" => secondary,
+            "success" => success,
+            "danger" => danger,
+            _ => return None,
+        };
+
+        Some(style(self, status))
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -451,7 +599,7 @@ impl DefaultStyle for Appearance {
 pub fn primary(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    match status {
+    let appearance = match status {
         Status::Active { is_checked } => styled(
             palette.primary.strong.text,
             palette.background.base,
@@ -464,20 +612,31 @@ pub fn primary(theme: &Theme, status: Status) -> Appearance {
             palette.primary.base,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.primary.strong.text,
+                palette.background.weak,
+                palette.primary.base,
+                is_checked,
+            ),
+            palette.background.base.text,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.primary.strong.text,
             palette.background.weak,
             palette.background.strong,
             is_checked,
         ),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A secondary checkbox; denoting a complementary toggle.
 pub fn secondary(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    match status {
+    let appearance = match status {
         Status::Active { is_checked } => styled(
             palette.background.base.text,
             palette.background.base,
@@ -490,20 +649,31 @@ pub fn secondary(theme: &Theme, status: Status) -> Appearance {
             palette.background.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.background.base.text,
+                palette.background.weak,
+                palette.background.strong,
+                is_checked,
+            ),
+            palette.background.base.text,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.background.strong.color,
             palette.background.weak,
             palette.background.weak,
             is_checked,
         ),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A success checkbox; denoting a positive toggle.
 pub fn success(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    match status {
+    let appearance = match status {
         Status::Active { is_checked } => styled(
             palette.success.base.text,
             palette.background.base,
@@ -516,20 +686,31 @@ pub fn success(theme: &Theme, status: Status) -> Appearance {
             palette.success.base,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.success.base.text,
+                palette.background.weak,
+                palette.success.base,
+                is_checked,
+            ),
+            palette.background.base.text,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.success.base.text,
             palette.background.weak,
             palette.success.weak,
             is_checked,
         ),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A danger checkbox; denoting a negaive toggle.
 pub fn danger(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    match status {
+    let appearance = match status {
         Status::Active { is_checked } => styled(
             palette.danger.base.text,
             palette.background.base,
@@ -542,13 +723,24 @@ pub fn danger(theme: &Theme, status: Status) -> Appearance {
             palette.danger.base,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.danger.base.text,
+                palette.background.weak,
+                palette.danger.base,
+                is_checked,
+            ),
+            palette.background.base.text,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.danger.base.text,
             palette.background.weak,
             palette.danger.weak,
             is_checked,
         ),
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 fn styled(
@@ -570,5 +762,43 @@ fn styled(
             color: accent.color,
         },
         text_color: None,
+        transition: None,
+    }
+}
+
+fn focused(appearance: Appearance, ring: Color) -> Appearance {
+    Appearance {
+        border: Border {
+            color: ring,
+            width: 2.0,
+            ..appearance.border
+        },
+        ..appearance
+    }
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => theme::Status::Active,
+            Status::Hovered { .. } => theme::Status::Hovered,
+            Status::Focused { .. } => theme::Status::Focused,
+            Status::Disabled { .. } => theme::Status::Disabled,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.checkbox.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            background: properties.background(self.background),
+            border: properties.border(self.border),
+            text_color: properties.text_color.or(self.text_color),
+            ..self
+        }
     }
 }
@@ -0,0 +1,285 @@
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::theme::{self, Classes};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A widget that layers some style [`Classes`] on top of the ones of the
+/// current `Theme` for its contents.
+///
+/// The [`Classes`] are kept in scope while the contents and their overlays
+/// are processed, so the `Theme` itself is left untouched.
+#[allow(missing_debug_implementations)]
+pub struct Classed<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    classes: Classes,
+}
+
+impl<'a, Message, Theme, Renderer> Classed<'a, Message, Theme, Renderer> {
+    /// Creates a [`Classed`] widget that layers the given [`Classes`] for
+    /// the provided `content`.
+    pub fn new(
+        classes: Classes,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            classes,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Classed<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        theme::with_classes(&self.classes, || {
+            self.content.as_widget().layout(tree, renderer, limits)
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        theme::with_classes(&self.classes, || {
+            self.content
+                .as_widget()
+                .operate(tree, layout, renderer, operation);
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let content = &mut self.content;
+
+        theme::with_classes(&self.classes, || {
+            content.as_widget_mut().on_event(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            )
+        })
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        theme::with_classes(&self.classes, || {
+            self.content
+                .as_widget()
+                .mouse_interaction(tree, layout, cursor, viewport, renderer)
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        theme::with_classes(&self.classes, || {
+            self.content
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let classes = &self.classes;
+
+        theme::with_classes(classes, || {
+            self.content.as_widget_mut().overlay(
+                tree,
+                layout,
+                renderer,
+                translation,
+            )
+        })
+        .map(|content| Overlay { classes, content })
+        .map(|overlay| overlay::Element::new(Box::new(overlay)))
+    }
+}
+
+struct Overlay<'a, Message, Theme, Renderer> {
+    classes: &'a Classes,
+    content: overlay::Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        let content = &mut self.content;
+
+        theme::with_classes(self.classes, || {
+            content.layout(renderer, bounds, direction)
+        })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        theme::with_classes(self.classes, || {
+            self.content.draw(renderer, theme, style, layout, cursor);
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content = &mut self.content;
+
+        theme::with_classes(self.classes, || {
+            content.on_event(event, layout, cursor, renderer, clipboard, shell)
+        })
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let content = &mut self.content;
+
+        theme::with_classes(self.classes, || {
+            content.operate(layout, renderer, operation);
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        theme::with_classes(self.classes, || {
+            self.content
+                .mouse_interaction(layout, cursor, viewport, renderer)
+        })
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        self.content.is_over(layout, renderer, cursor_position)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let classes = self.classes;
+
+        theme::with_classes(classes, || self.content.overlay(layout, renderer))
+            .map(|content| Overlay { classes, content })
+            .map(|overlay| overlay::Element::new(Box::new(overlay)))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Classed<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        classed: Classed<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(classed)
+    }
+}
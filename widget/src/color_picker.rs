@@ -0,0 +1,906 @@
+//! Pick a color with a hex field and a saturation, value, hue and alpha
+//! panel.
+use crate::core::event::{self, Event};
+use crate::core::gradient::{self, Gradient};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Border, Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Radians, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::picker::{self, Status};
+use crate::text_input::{self, TextInput, Value};
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// A field to edit a [`Color`] as hexadecimal text, with a swatch that
+/// opens a panel to pick the [`Color`] visually.
+///
+/// The panel has a square to pick the saturation and the value of the
+/// [`Color`], and sliders for its hue and its alpha. The hex field accepts
+/// `#rgb`, `#rrggbb` and `#rrggbbaa` notations; text that does not parse is
+/// displayed in an invalid state until it is fixed or the field loses
+/// focus.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::color_picker;
+/// use iced::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AccentChanged(Color),
+/// }
+///
+/// fn view<'a>(accent: Color) -> Element<'a, Message> {
+///     color_picker(accent, Message::AccentChanged).width(200).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    text_input: TextInput<'a, Edit, Theme, Renderer>,
+    width: Length,
+    style: picker::Style<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The spacing between the swatch and the hex field of a
+    /// [`ColorPicker`].
+    pub const SPACING: f32 = 4.0;
+
+    /// Creates a new [`ColorPicker`] with the given [`Color`] and the
+    /// function that produces a message when it changes.
+    pub fn new(color: Color, on_change: impl Fn(Color) -> Message + 'a) -> Self
+    where
+        Theme: picker::DefaultStyle + text_input::DefaultStyle + 'a,
+    {
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            text_input: TextInput::new("#rrggbb", "").on_input(Edit),
+            width: Length::Fill,
+            style: Box::new(<Theme as picker::DefaultStyle>::default_style),
+        }
+    }
+
+    /// Sets the [`text_input::Id`] of the hex field of the [`ColorPicker`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the hex field of the [`ColorPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`ColorPicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the font of the [`ColorPicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the hex field of the [`ColorPicker`].
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Appearance + 'a,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the swatch and the panel of the [`ColorPicker`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> picker::Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`ColorPicker`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: picker::Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            picker::Catalog::resolve(theme, &class, status).unwrap_or_else(
+                || picker::DefaultStyle::default_style(theme, status),
+            )
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Edit(String);
+
+/// A part of the panel of a [`ColorPicker`] that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Component {
+    Square,
+    Hue,
+    Alpha,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    text: String,
+    is_invalid: bool,
+    is_open: bool,
+    hsv: Hsv,
+    alpha: f32,
+    drag: Option<Component>,
+}
+
+impl State {
+    fn pick(&mut self, color: Color) {
+        self.hsv = Hsv::from_color(color);
+        self.alpha = color.a;
+    }
+}
+
+fn is_focused<P: text::Paragraph + 'static>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<P>>()
+        .is_focused()
+}
+
+/// Returns the layouts of the swatch and the hex field.
+fn parts(layout: Layout<'_>) -> (Rectangle, Layout<'_>) {
+    let mut children = layout.children();
+
+    let swatch = children.next().expect("Swatch layout");
+    let input = children.next().expect("Hex field layout");
+
+    (swatch.bounds(), input)
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            text: self.color.to_hex(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // Keep the text in sync with the color unless the user is editing it
+        if !is_focused::<Renderer::Paragraph>(&tree.children[0]) {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.text = self.color.to_hex();
+            state.is_invalid = false;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let value = Value::new(&tree.state.downcast_ref::<State>().text);
+        let limits = limits.width(self.width);
+
+        // The swatch is a square as tall as the hex field
+        let height = self
+            .text_input
+            .layout(&mut tree.children[0], renderer, &limits, Some(&value))
+            .size()
+            .height;
+
+        let offset = height + Self::SPACING;
+
+        let input = self
+            .text_input
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(Size::new(offset, 0.0)),
+                Some(&value),
+            )
+            .move_to(Point::new(offset, 0.0));
+
+        let size = Size::new(offset + input.size().width, height);
+
+        layout::Node::with_children(
+            size,
+            vec![layout::Node::new(Size::new(height, height)), input],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        // The hex field holds the focus of the whole picker
+        self.text_input.operate_state(
+            &mut tree.children[0],
+            layout.bounds(),
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let (swatch, input) = parts(layout);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor.is_over(swatch) {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_open = !state.is_open;
+                state.drag = None;
+                state.pick(self.color);
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+        }
+
+        let was_focused = is_focused::<Renderer::Paragraph>(&tree.children[0]);
+
+        self.text_input
+            .set_value(Value::new(&tree.state.downcast_ref::<State>().text));
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event,
+            input,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        let is_focused = is_focused::<Renderer::Paragraph>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        for Edit(text) in edits {
+            if let Some(color) = Color::parse(&text) {
+                state.is_invalid = false;
+                state.pick(color);
+
+                if color != self.color {
+                    shell.publish((self.on_change)(color));
+                    self.color = color;
+                }
+            } else {
+                state.is_invalid = true;
+            }
+
+            state.text = text;
+            shell.invalidate_layout();
+        }
+
+        if was_focused && !is_focused {
+            state.text = self.color.to_hex();
+            state.is_invalid = false;
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (swatch, input) = parts(layout);
+
+        if cursor.is_over(swatch) {
+            mouse::Interaction::Pointer
+        } else {
+            self.text_input.mouse_interaction(
+                &tree.children[0],
+                input,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let (swatch, input) = parts(layout);
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if is_focused::<Renderer::Paragraph>(&tree.children[0]) {
+            Status::Focused
+        } else if cursor.is_over(swatch) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: swatch,
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            self.color,
+        );
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input,
+            cursor,
+            Some(&Value::new(&state.text)),
+            viewport,
+        );
+
+        if state.is_invalid {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: input.bounds(),
+                    border: Border {
+                        color: appearance.invalid_color,
+                        ..appearance.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Palette {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+/// The padding of the panel of a [`ColorPicker`].
+const PANEL_PADDING: f32 = 8.0;
+
+/// The size of the saturation and value square of a [`ColorPicker`].
+const SQUARE_SIZE: f32 = 160.0;
+
+/// The height of the hue and alpha sliders of a [`ColorPicker`].
+const SLIDER_HEIGHT: f32 = 14.0;
+
+/// The panel of an open [`ColorPicker`].
+struct Palette<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    picker: &'b mut ColorPicker<'a, Message, Theme, Renderer>,
+    state: &'b mut State,
+    field: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Palette<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Returns the bounds of the given component of the panel.
+    fn bounds(panel: Rectangle, component: Component) -> Rectangle {
+        let x = panel.x + PANEL_PADDING;
+        let y = panel.y + PANEL_PADDING;
+
+        match component {
+            Component::Square => Rectangle {
+                x,
+                y,
+                width: SQUARE_SIZE,
+                height: SQUARE_SIZE,
+            },
+            Component::Hue | Component::Alpha => {
+                let index = if component == Component::Hue {
+                    0.0
+                } else {
+                    1.0
+                };
+
+                Rectangle {
+                    x,
+                    y: y + SQUARE_SIZE
+                        + PANEL_PADDING
+                        + index * (SLIDER_HEIGHT + PANEL_PADDING),
+                    width: SQUARE_SIZE,
+                    height: SLIDER_HEIGHT,
+                }
+            }
+        }
+    }
+
+    fn component(panel: Rectangle, cursor: mouse::Cursor) -> Option<Component> {
+        [Component::Square, Component::Hue, Component::Alpha]
+            .into_iter()
+            .find(|&component| cursor.is_over(Self::bounds(panel, component)))
+    }
+
+    /// Updates the dragged component with the given position and publishes
+    /// the resulting [`Color`].
+    fn drag(
+        &mut self,
+        panel: Rectangle,
+        component: Component,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = Self::bounds(panel, component);
+        let x = ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let y = ((position.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+        match component {
+            Component::Square => {
+                self.state.hsv.saturation = x;
+                self.state.hsv.value = 1.0 - y;
+            }
+            Component::Hue => {
+                self.state.hsv.hue = x * 360.0;
+            }
+            Component::Alpha => {
+                self.state.alpha = x;
+            }
+        }
+
+        let color = Color {
+            a: self.state.alpha,
+            ..self.state.hsv.to_color()
+        };
+
+        self.state.text = color.to_hex();
+        self.state.is_invalid = false;
+
+        if color != self.picker.color {
+            shell.publish((self.picker.on_change)(color));
+            self.picker.color = color;
+        }
+
+        shell.invalidate_layout();
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Palette<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        _renderer: &Renderer,
+        bounds: Size,
+        _direction: layout::Direction,
+    ) -> layout::Node {
+        let size = Size::new(
+            SQUARE_SIZE + 2.0 * PANEL_PADDING,
+            SQUARE_SIZE + 2.0 * SLIDER_HEIGHT + 4.0 * PANEL_PADDING,
+        );
+
+        layout::Node::new(size)
+            .move_to(picker::panel_position(self.field, size, bounds))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let panel = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(panel) {
+                    if cursor.is_over(self.field) {
+                        return event::Status::Ignored;
+                    }
+
+                    self.state.is_open = false;
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+
+                if let Some((component, position)) =
+                    Self::component(panel, cursor).zip(cursor.position())
+                {
+                    self.state.drag = Some(component);
+                    self.drag(panel, component, position, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(component) = self.state.drag {
+                    self.drag(panel, component, position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(
+                touch::Event::FingerLifted { .. }
+                | touch::Event::FingerLost { .. },
+            ) if self.state.drag.is_some() => {
+                self.state.drag = None;
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.is_open = false;
+                self.state.drag = None;
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if cursor.is_over(panel) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if Self::component(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.picker.style)(theme, Status::Opened);
+        let panel = layout.bounds();
+        let Hsv {
+            hue,
+            saturation,
+            value,
+        } = self.state.hsv;
+
+        picker::draw_panel(renderer, &appearance, panel);
+
+        let quad = |bounds| renderer::Quad {
+            bounds,
+            ..renderer::Quad::default()
+        };
+
+        let linear = |angle, stops: &[(f32, Color)]| {
+            Gradient::Linear(stops.iter().fold(
+                gradient::Linear::new(Radians(angle)),
+                |linear, &(offset, color)| linear.add_stop(offset, color),
+            ))
+        };
+
+        // The saturation grows from left to right, and the value decreases
+        // from top to bottom
+        let square = Self::bounds(panel, Component::Square);
+        let hue_color = Hsv {
+            hue,
+            saturation: 1.0,
+            value: 1.0,
+        }
+        .to_color();
+
+        renderer.fill_quad(quad(square), hue_color);
+        renderer.fill_quad(
+            quad(square),
+            linear(
+                FRAC_PI_2,
+                &[(0.0, Color::WHITE), (1.0, Color::WHITE.scale_alpha(0.0))],
+            ),
+        );
+        renderer.fill_quad(
+            quad(square),
+            linear(
+                PI,
+                &[(0.0, Color::BLACK.scale_alpha(0.0)), (1.0, Color::BLACK)],
+            ),
+        );
+
+        let hue_stops: Vec<_> = (0..=6)
+            .map(|i| {
+                let color = Hsv {
+                    hue: i as f32 * 60.0,
+                    saturation: 1.0,
+                    value: 1.0,
+                }
+                .to_color();
+
+                (i as f32 / 6.0, color)
+            })
+            .collect();
+
+        let hue_bar = Self::bounds(panel, Component::Hue);
+        renderer.fill_quad(quad(hue_bar), linear(FRAC_PI_2, &hue_stops));
+
+        let color = Hsv {
+            hue,
+            saturation,
+            value,
+        }
+        .to_color();
+
+        let alpha_bar = Self::bounds(panel, Component::Alpha);
+        renderer.fill_quad(
+            quad(alpha_bar),
+            linear(FRAC_PI_2, &[(0.0, color.scale_alpha(0.0)), (1.0, color)]),
+        );
+
+        let marker = |center: Point, size: Size| renderer::Quad {
+            bounds: Rectangle {
+                x: center.x - size.width / 2.0,
+                y: center.y - size.height / 2.0,
+                width: size.width,
+                height: size.height,
+            },
+            border: Border {
+                color: appearance.text_color,
+                width: 2.0,
+                radius: (size.width.min(size.height) / 2.0).into(),
+            },
+            ..renderer::Quad::default()
+        };
+
+        renderer.fill_quad(
+            marker(
+                Point::new(
+                    square.x + saturation * square.width,
+                    square.y + (1.0 - value) * square.height,
+                ),
+                Size::new(10.0, 10.0),
+            ),
+            Color::TRANSPARENT,
+        );
+
+        for (bar, x) in [(hue_bar, hue / 360.0), (alpha_bar, self.state.alpha)]
+        {
+            renderer.fill_quad(
+                marker(
+                    Point::new(bar.x + x * bar.width, bar.center_y()),
+                    Size::new(6.0, bar.height + 4.0),
+                ),
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+/// A [`Color`] in the hue, saturation and value color space.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Hsv {
+    /// The hue, in degrees.
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    fn from_color(color: Color) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Self {
+            hue,
+            saturation: if max == 0.0 { 0.0 } else { delta / max },
+            value: max,
+        }
+    }
+
+    fn to_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = (self.hue / 60.0).rem_euclid(6.0);
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.value - chroma;
+
+        Color::from_rgb(r + m, g + m, b + m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(
+            Color::parse("#ff8000"),
+            Some(Color::from_rgb8(255, 128, 0))
+        );
+        assert_eq!(Color::parse("0f0"), Some(Color::from_rgb8(0, 255, 0)));
+        assert_eq!(Color::parse("#12345g"), None);
+        assert_eq!(Color::parse("#1234"), None);
+
+        for hex in ["#ff8000", "#336699", "#11223380"] {
+            assert_eq!(
+                Color::parse(hex).map(Color::to_hex).as_deref(),
+                Some(hex)
+            );
+        }
+
+        let color = Color::from_rgb8(51, 102, 153);
+        let hsv = Hsv::from_color(color);
+
+        assert_eq!(hsv.to_color().to_hex(), color.to_hex());
+    }
+}
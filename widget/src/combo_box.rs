@@ -7,6 +7,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::theme::Class;
 use crate::core::time::Instant;
 use crate::core::widget::{self, Widget};
 use crate::core::{
@@ -136,6 +137,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`TextInput`] and the [`Menu`] of
+    /// the [`ComboBox`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    ///
+    /// [`Menu`]: menu::Menu
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: text_input::Catalog + menu::Catalog + 'a,
+    {
+        let class = class.into();
+
+        self.text_input = self.text_input.class(class.clone());
+        self.menu_style = menu::DefaultStyle::default_style().class(class);
+        self
+    }
+
     /// Sets the [`Renderer::Font`] of the [`ComboBox`].
     ///
     /// [`Renderer::Font`]: text::Renderer
@@ -6,6 +6,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::theme::{self, Class};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
@@ -145,6 +146,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`Container`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
     /// Sets whether the contents of the [`Container`] should be clipped on
     /// overflow.
     pub fn clip(mut self, clip: bool) -> Self {
@@ -577,7 +596,35 @@ pub trait DefaultStyle {
 
 impl DefaultStyle for Theme {
     fn default_style(&self, status: Status) -> Appearance {
-        transparent(self, status)
+        self.overridden(transparent(self, status), status)
+    }
+}
+
+/// The styles of a [`Container`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `transparent`, `rounded_box`, and
+/// `bordered_box` classes, on top of any [`Classes`] registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        let style: fn(&Theme, Status) -> Appearance = match class.as_str() {
+            "transparent" => transparent,
+            "rounded_box" => rounded_box,
+            "bordered_box" => bordered_box,
+            _ => return None,
+        };
+
+        Some(style(self, status))
     }
 }
 
@@ -611,21 +658,23 @@ pub fn transparent<Theme>(_theme: &Theme, _status: Status) -> Appearance {
 }
 
 /// A rounded [`Container`] with a background.
-pub fn rounded_box(theme: &Theme, _status: Status) -> Appearance {
+pub fn rounded_box(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    Appearance {
+    let appearance = Appearance {
         background: Some(palette.background.weak.color.into()),
         border: Border::rounded(2),
         ..Appearance::default()
-    }
+    };
+
+    theme.overridden(appearance, status)
 }
 
 /// A bordered [`Container`] with a background.
-pub fn bordered_box(theme: &Theme, _status: Status) -> Appearance {
+pub fn bordered_box(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
-    Appearance {
+    let appearance = Appearance {
         background: Some(palette.background.weak.color.into()),
         border: Border {
             width: 1.0,
@@ -633,5 +682,34 @@ pub fn bordered_box(theme: &Theme, _status: Status) -> Appearance {
             color: palette.background.strong.color,
         },
         ..Appearance::default()
+    };
+
+    theme.overridden(appearance, status)
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Idle => theme::Status::Active,
+            Status::Hovered => theme::Status::Hovered,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.container.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            text_color: properties.text_color.or(self.text_color),
+            background: properties
+                .background
+                .map(Background::Color)
+                .or(self.background),
+            border: properties.border(self.border),
+            ..self
+        }
     }
 }
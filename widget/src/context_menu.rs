@@ -0,0 +1,419 @@
+//! Open a menu of actions on right click.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+use crate::menu::{self, Dropdown, Item};
+use crate::MouseArea;
+
+/// A container that opens a menu at the cursor position when its content
+/// is right clicked.
+///
+/// The content is wrapped in a [`MouseArea`], so right clicks captured by
+/// the content itself do not open the menu.
+///
+/// The items of a [`ContextMenu`] may contain nested submenus and support
+/// the same keyboard navigation as the menus of a
+/// [`MenuBar`](crate::MenuBar).
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::menu::Item;
+/// use iced::widget::{context_menu, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     context_menu(
+///         text("Right click me!"),
+///         [
+///             Item::new("&Copy").on_select(Message::Copy),
+///             Item::new("&Paste").on_select(Message::Paste),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Internal<Message>, Theme, Renderer>,
+    root: Item<Message>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: menu::Style<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the items of a [`ContextMenu`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`ContextMenu`] with the given content and items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self
+    where
+        Message: Clone + 'a,
+        Theme: menu::DefaultStyle + 'a,
+        Renderer: 'a,
+    {
+        let content = content.into().map(Internal::Content);
+
+        Self {
+            content: MouseArea::new(content)
+                .on_right_press(Internal::Open)
+                .into(),
+            root: Item::submenu("", items),
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, menu::Status) -> menu::Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`ContextMenu`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: menu::Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            menu::Catalog::resolve(theme, &class, status).unwrap_or_else(|| {
+                menu::DefaultStyle::default_style(theme, status)
+            })
+        });
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    position: Point,
+    menu: menu::State,
+}
+
+/// The messages produced by the content of a [`ContextMenu`].
+#[derive(Debug, Clone)]
+enum Internal<Message> {
+    Content(Message),
+    Open,
+}
+
+impl<Message> Internal<Message> {
+    /// Only the [`MouseArea`] wrapping the content of a [`ContextMenu`]
+    /// produces [`Internal::Open`], so the messages of the overlays of the
+    /// content always map back.
+    fn into_content(self) -> Message {
+        match self {
+            Self::Content(message) => message,
+            Self::Open => {
+                unreachable!("only the mouse area of a context menu opens it")
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut MapOperation { operation },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        local_shell.revalidate_layout(|| shell.invalidate_layout());
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for message in messages {
+            match message {
+                Internal::Content(message) => shell.publish(message),
+                Internal::Open => {
+                    let state = tree.state.downcast_mut::<State>();
+
+                    state.position = cursor.position().unwrap_or_default();
+                    state.menu.open(0);
+
+                    shell.invalidate_layout();
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open {
+            return self
+                .content
+                .as_widget_mut()
+                .overlay(&mut tree.children[0], layout, renderer, translation)
+                .map(|overlay| overlay.map(&Internal::into_content));
+        }
+
+        Some(
+            Dropdown {
+                roots: std::slice::from_ref(&self.root),
+                state: &mut state.menu,
+                position: state.position + translation,
+                exclusion: None,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+                underlines: Vec::new(),
+            }
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
+
+/// An [`Operation`] on the content of a [`ContextMenu`], which produces
+/// [`Internal`] messages.
+struct MapOperation<'a, B> {
+    operation: &'a mut dyn Operation<B>,
+}
+
+impl<'a, T, B> Operation<T> for MapOperation<'a, B> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
+    fn bounds(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        self.operation.bounds(id, bounds);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        id: Option<&Id>,
+    ) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable(state, id, bounds, translation);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+}
@@ -0,0 +1,965 @@
+//! Pick a date from a calendar.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::i18n;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::time::SystemTime;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::menu::Metrics;
+use crate::picker::{self, Status};
+
+use std::fmt;
+
+/// A date of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from the given year, month and day.
+    ///
+    /// Returns `None` if the month or the day are out of range.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+        {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days(seconds as i64 / 86_400)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the number of days since Monday of the [`Date`].
+    pub fn weekday(&self) -> u32 {
+        // The 1st of January of 1970 was a Thursday
+        (self.days() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the [`Date`] the given amount of days away.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    /// Returns the [`Date`] the given amount of months away, keeping the
+    /// day within the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    fn clamp_to(self, min: Option<Date>, max: Option<Date>) -> Self {
+        let date = min.map_or(self, |min| self.max(min));
+
+        max.map_or(date, |max| date.min(max))
+    }
+
+    /// Returns the number of days since the 1st of January of 1970.
+    fn days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = (i64::from(self.month) + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+fn month_name(month: u32) -> String {
+    let fallback = MONTHS[month as usize - 1];
+
+    i18n::translate_or(&format!("iced-month-{month}"), fallback).into_owned()
+}
+
+fn weekday_name(weekday: usize) -> String {
+    let fallback = WEEKDAYS[weekday];
+
+    i18n::translate_or(&format!("iced-weekday-{}", weekday + 1), fallback)
+        .into_owned()
+}
+
+/// A field that opens a calendar to pick a [`Date`] when pressed.
+///
+/// The calendar displays a month at a time, starting on Monday. Dates
+/// outside of the [`min`](Self::min) and [`max`](Self::max) bounds cannot
+/// be picked.
+///
+/// While open, the arrow keys move the focused date, `PageUp` and
+/// `PageDown` change the month (or the year, with `Shift`), `Enter` picks
+/// the focused date and `Escape` closes the calendar. Once focused, the
+/// calendar can be opened with `Down`, `Enter` or `Space`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::date_picker;
+/// use iced::widget::date_picker::Date;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DueDateSelected(Date),
+/// }
+///
+/// fn view<'a>(due_date: Option<Date>) -> Element<'a, Message> {
+///     date_picker(due_date, Message::DueDateSelected)
+///         .placeholder("Due date")
+///         .min(Date::today())
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    selected: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    placeholder: String,
+    min: Option<Date>,
+    max: Option<Date>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: picker::Style<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the field of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = crate::button::DEFAULT_PADDING;
+
+    /// Creates a new [`DatePicker`] with the selected [`Date`], if any,
+    /// and the function that produces a message when a [`Date`] is picked.
+    pub fn new(
+        selected: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: picker::DefaultStyle + 'a,
+    {
+        Self {
+            id: None,
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: String::new(),
+            min: None,
+            max: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`DatePicker`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the placeholder of the [`DatePicker`], displayed when no
+    /// [`Date`] is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the width of the field of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the field of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> picker::Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`DatePicker`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: picker::Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            picker::Catalog::resolve(theme, &class, status).unwrap_or_else(
+                || picker::DefaultStyle::default_style(theme, status),
+            )
+        });
+        self
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        date.clamp_to(self.min, self.max) == date
+    }
+
+    /// Opens the calendar, focusing the selected [`Date`] or today.
+    fn open(&self, state: &mut State) {
+        let focused = self
+            .selected
+            .unwrap_or_else(Date::today)
+            .clamp_to(self.min, self.max);
+
+        state.is_open = true;
+        state.month = focused.first_of_month();
+        state.focused = focused;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    is_open: bool,
+    is_focused: bool,
+    month: Date,
+    focused: Date,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let today = Date::today();
+
+        tree::State::new(State {
+            is_open: false,
+            is_focused: false,
+            month: today.first_of_month(),
+            focused: today,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let sample = i18n::format_date(8888, 12, 28);
+
+        picker::layout_field::<Renderer>(
+            limits,
+            self.width,
+            self.padding,
+            &metrics,
+            &[&sample, &self.placeholder],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Presses over the calendar leave the cursor unavailable
+                // and keep the focus
+                if let Some(position) = cursor.position() {
+                    state.is_focused = layout.bounds().contains(position);
+                }
+
+                if cursor.is_over(layout.bounds()) {
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        self.open(state);
+                    }
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        key::Named::ArrowDown
+                        | key::Named::Enter
+                        | key::Named::Space,
+                    ),
+                ..
+            }) if state.is_focused && !state.is_open => {
+                self.open(state);
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let selected = self.selected.map(|date| {
+            i18n::format_date(date.year(), date.month(), date.day())
+        });
+
+        picker::draw_field(
+            renderer,
+            &(self.style)(theme, status),
+            bounds,
+            self.padding,
+            &Metrics::new(renderer, self.text_size, self.font),
+            selected.as_deref().unwrap_or(&self.placeholder),
+            selected.is_none(),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Calendar {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+/// The padding of the calendar of a [`DatePicker`].
+const PANEL_PADDING: f32 = 6.0;
+
+/// The calendar of an open [`DatePicker`].
+struct Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    picker: &'b DatePicker<'a, Message, Theme, Renderer>,
+    state: &'b mut State,
+    field: Rectangle,
+}
+
+/// A part of a [`Calendar`] that can be pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        Metrics::new(renderer, self.picker.text_size, self.picker.font)
+    }
+
+    /// Returns the size of a cell of the calendar.
+    fn cell(metrics: &Metrics<Renderer::Font>) -> f32 {
+        (metrics.line_height * 1.75).round()
+    }
+
+    /// Returns the first day displayed by the calendar.
+    fn first_day(&self) -> Date {
+        self.state
+            .month
+            .add_days(-i64::from(self.state.month.weekday()))
+    }
+
+    /// Returns the bounds of the cell at the given row and column, where
+    /// the first row is the header.
+    fn cell_bounds(
+        panel: Rectangle,
+        cell: f32,
+        row: usize,
+        column: usize,
+    ) -> Rectangle {
+        Rectangle {
+            x: panel.x + PANEL_PADDING + column as f32 * cell,
+            y: panel.y + PANEL_PADDING + row as f32 * cell,
+            width: cell,
+            height: cell,
+        }
+    }
+
+    fn target(
+        &self,
+        panel: Rectangle,
+        cell: f32,
+        cursor: mouse::Cursor,
+    ) -> Option<Target> {
+        let position = cursor.position_over(panel)?;
+
+        let column = ((position.x - panel.x - PANEL_PADDING) / cell).floor();
+        let row = ((position.y - panel.y - PANEL_PADDING) / cell).floor();
+
+        if !(0.0..7.0).contains(&column) {
+            return None;
+        }
+
+        match row as i64 {
+            0 if column == 0.0 => Some(Target::Previous),
+            0 if column == 6.0 => Some(Target::Next),
+            row @ 2..=7 => {
+                let date =
+                    self.first_day().add_days((row - 2) * 7 + column as i64);
+
+                self.picker.is_enabled(date).then_some(Target::Day(date))
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the focused date, showing its month.
+    fn focus(&mut self, date: Date) {
+        let date = date.clamp_to(self.picker.min, self.picker.max);
+
+        self.state.focused = date;
+        self.state.month = date.first_of_month();
+    }
+
+    fn select(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        self.state.is_open = false;
+        shell.publish((self.picker.on_select)(date));
+        shell.invalidate_layout();
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _direction: layout::Direction,
+    ) -> layout::Node {
+        let cell = Self::cell(&self.metrics(renderer));
+
+        let size = Size::new(
+            7.0 * cell + 2.0 * PANEL_PADDING,
+            8.0 * cell + 2.0 * PANEL_PADDING,
+        );
+
+        layout::Node::new(size)
+            .move_to(picker::panel_position(self.field, size, bounds))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let panel = layout.bounds();
+        let cell = Self::cell(&self.metrics(renderer));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(panel) {
+                    if cursor.is_over(self.field) {
+                        return event::Status::Ignored;
+                    }
+
+                    self.state.is_open = false;
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+
+                match self.target(panel, cell, cursor) {
+                    Some(Target::Previous) => {
+                        self.state.month = self.state.month.add_months(-1);
+                    }
+                    Some(Target::Next) => {
+                        self.state.month = self.state.month.add_months(1);
+                    }
+                    Some(Target::Day(date)) => {
+                        self.select(date, shell);
+                    }
+                    None => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(panel) =>
+            {
+                let (mouse::ScrollDelta::Lines { y, .. }
+                | mouse::ScrollDelta::Pixels { y, .. }) = delta;
+
+                if y != 0.0 {
+                    self.state.month = self
+                        .state
+                        .month
+                        .add_months(if y > 0.0 { -1 } else { 1 });
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) => {
+                let focused = self.state.focused;
+                let months = if modifiers.shift() { 12 } else { 1 };
+
+                match key {
+                    key::Named::ArrowLeft => self.focus(focused.add_days(-1)),
+                    key::Named::ArrowRight => self.focus(focused.add_days(1)),
+                    key::Named::ArrowUp => self.focus(focused.add_days(-7)),
+                    key::Named::ArrowDown => self.focus(focused.add_days(7)),
+                    key::Named::PageUp => {
+                        self.focus(focused.add_months(-months));
+                    }
+                    key::Named::PageDown => {
+                        self.focus(focused.add_months(months));
+                    }
+                    key::Named::Enter | key::Named::Space => {
+                        self.select(focused, shell);
+                    }
+                    key::Named::Escape => {
+                        self.state.is_open = false;
+                        shell.invalidate_layout();
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if cursor.is_over(panel) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cell = Self::cell(&self.metrics(renderer));
+
+        if self.target(layout.bounds(), cell, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.picker.style)(theme, Status::Opened);
+        let metrics = self.metrics(renderer);
+        let cell = Self::cell(&metrics);
+        let panel = layout.bounds();
+        let hovered = self.target(panel, cell, cursor);
+        let month = self.state.month;
+
+        picker::draw_panel(renderer, &appearance, panel);
+
+        let header = Self::cell_bounds(panel, cell, 0, 0);
+
+        metrics.draw(
+            renderer,
+            &format!("{} {}", month_name(month.month()), month.year()),
+            Point::new(panel.center_x(), header.center_y()),
+            alignment::Horizontal::Center,
+            appearance.text_color,
+            panel,
+        );
+
+        for (target, column, arrow) in
+            [(Target::Previous, 0, "‹"), (Target::Next, 6, "›")]
+        {
+            let bounds = Self::cell_bounds(panel, cell, 0, column);
+
+            if hovered == Some(target) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    appearance.hovered_background,
+                );
+            }
+
+            metrics.draw(
+                renderer,
+                arrow,
+                bounds.center(),
+                alignment::Horizontal::Center,
+                appearance.text_color,
+                panel,
+            );
+        }
+
+        for column in 0..WEEKDAYS.len() {
+            metrics.draw(
+                renderer,
+                &weekday_name(column),
+                Self::cell_bounds(panel, cell, 1, column).center(),
+                alignment::Horizontal::Center,
+                appearance.disabled_text_color,
+                panel,
+            );
+        }
+
+        let first_day = self.first_day();
+
+        for index in 0..42 {
+            let date = first_day.add_days(index as i64);
+            let bounds =
+                Self::cell_bounds(panel, cell, 2 + index / 7, index % 7);
+
+            let is_selected = self.picker.selected == Some(date);
+            let is_enabled = self.picker.is_enabled(date);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if date == self.state.focused
+                || hovered == Some(Target::Day(date))
+            {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            metrics.draw(
+                renderer,
+                &date.day().to_string(),
+                bounds.center(),
+                alignment::Horizontal::Center,
+                if is_selected {
+                    appearance.selected_text_color
+                } else if !is_enabled || date.month() != month.month() {
+                    appearance.disabled_text_color
+                } else {
+                    appearance.text_color
+                },
+                panel,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_arithmetic() {
+        let date = |year, month, day| Date::from_ymd(year, month, day).unwrap();
+
+        assert_eq!(Date::from_days(0), date(1970, 1, 1));
+        assert_eq!(date(2000, 3, 1).days(), 11_017);
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        assert_eq!(date(2024, 6, 3).weekday(), 0);
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+    }
+}
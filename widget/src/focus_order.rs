@@ -0,0 +1,243 @@
+//! Override the order in which widgets are focused with the keyboard.
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{tree, Id, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A container that moves the keyboard focus between the widgets of its
+/// content in a custom order when Tab or Shift+Tab is pressed.
+///
+/// Focusable widgets without an [`Id`] in the order are skipped.
+#[allow(missing_debug_implementations)]
+pub struct FocusOrder<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    order: Vec<Id>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusOrder<'a, Message, Theme, Renderer> {
+    /// Creates a [`FocusOrder`] with the given content and the [`Id`] of
+    /// its focusable widgets, in the order they should be focused.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        order: impl IntoIterator<Item = impl Into<Id>>,
+    ) -> Self {
+        FocusOrder {
+            content: content.into(),
+            order: order.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusOrder<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let backwards = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) => Some(modifiers.shift()),
+            _ => None,
+        };
+
+        let status = self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+
+        let Some(backwards) = backwards else {
+            return status;
+        };
+
+        if status == event::Status::Captured || self.order.is_empty() {
+            return status;
+        }
+
+        let mut find_focused = FindFocused { focused: None };
+
+        self.content.as_widget().operate(
+            tree,
+            layout,
+            renderer,
+            &mut find_focused,
+        );
+
+        let current = find_focused
+            .focused
+            .and_then(|id| self.order.iter().position(|other| *other == id));
+
+        let last = self.order.len() - 1;
+
+        let next = match (current, backwards) {
+            (None, false) => 0,
+            (None, true) => last,
+            (Some(current), false) if current == last => 0,
+            (Some(current), false) => current + 1,
+            (Some(0), true) => last,
+            (Some(current), true) => current - 1,
+        };
+
+        self.content.as_widget().operate(
+            tree,
+            layout,
+            renderer,
+            &mut operation::focusable::focus(self.order[next].clone()),
+        );
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<FocusOrder<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        focus_order: FocusOrder<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(focus_order)
+    }
+}
+
+/// Finds the [`Id`] of the focused widget, if it has one.
+struct FindFocused {
+    focused: Option<Id>,
+}
+
+impl<T> Operation<T> for FindFocused {
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        id: Option<&Id>,
+    ) {
+        if state.is_focused() {
+            self.focused = id.cloned();
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
@@ -0,0 +1,778 @@
+//! Distribute content on a two-dimensional grid.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+use std::ops::Range;
+
+/// A container that distributes its contents on a grid of rows and columns.
+///
+/// The size of every row and column of a [`Grid`] is defined by a track:
+///
+/// - A [`Length::Fixed`] track has a fixed size.
+/// - A [`Length::Shrink`] track is as big as the biggest cell in it.
+/// - A [`Length::Fill`] or [`Length::FillPortion`] track takes a share of
+///   the space left by the other tracks, proportional to its factor.
+///
+/// Cells are placed in order, from left to right and top to bottom, unless
+/// their position is set explicitly with a [`Cell`]. Rows and columns
+/// without a track behave as [`Length::Shrink`] tracks.
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    slots: Vec<Slot>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            slots: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the column tracks of the [`Grid`].
+    ///
+    /// The amount of tracks determines the amount of columns used to place
+    /// cells automatically.
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self.width = self
+            .columns
+            .iter()
+            .fold(self.width, |width, column| width.enclose(*column));
+        self
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.rows = rows.into_iter().map(Into::into).collect();
+        self.height = self
+            .rows
+            .iter()
+            .fold(self.height, |height, row| height.enclose(*row));
+        self
+    }
+
+    /// Sets both the horizontal and vertical spacing _between_ cells.
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.column_spacing(amount).row_spacing(amount)
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Adds an element to the next free cell of the [`Grid`].
+    pub fn push(
+        self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_cell(Cell::new(child))
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push_cell(
+        mut self,
+        cell: Cell<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        self.slots.push(cell.slot);
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Extends the [`Grid`] with the given children, placing them in order.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Computes the rows and columns covered by every cell.
+    fn placements(&self) -> Vec<Placement> {
+        let columns = self.columns.len().max(1);
+        let (mut row, mut column) = (0, 0);
+
+        let mut placements: Vec<Placement> =
+            Vec::with_capacity(self.slots.len());
+
+        for slot in &self.slots {
+            match (slot.row, slot.column) {
+                (Some(r), Some(c)) => (row, column) = (r, c),
+                (Some(r), None) => (row, column) = (r, 0),
+                (None, Some(c)) => {
+                    if c < column {
+                        row += 1;
+                    }
+
+                    column = c;
+                }
+                (None, None) => loop {
+                    if column > 0 && column + slot.column_span > columns {
+                        (row, column) = (row + 1, 0);
+                    }
+
+                    // Skip the cells covered by the spans of previous cells
+                    let is_free = placements.iter().all(|placement| {
+                        !placement.rows.contains(&row)
+                            || placement.columns.end <= column
+                            || placement.columns.start
+                                >= column + slot.column_span
+                    });
+
+                    if is_free {
+                        break;
+                    }
+
+                    column += 1;
+                },
+            }
+
+            placements.push(Placement {
+                rows: row..row + slot.row_span,
+                columns: column..column + slot.column_span,
+            });
+
+            column += slot.column_span;
+
+            if column >= columns {
+                (row, column) = (row + 1, 0);
+            }
+        }
+
+        placements
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Default
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+        let max = limits.max();
+
+        let placements = self.placements();
+
+        let column_tracks = tracks(
+            &self.columns,
+            placements.iter().map(|placement| placement.columns.end),
+        );
+        let row_tracks = tracks(
+            &self.rows,
+            placements.iter().map(|placement| placement.rows.end),
+        );
+
+        // Columns are measured first, so rows can be measured with the
+        // final width of their cells.
+        let widths: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&placements)
+            .map(|((child, tree), placement)| {
+                let width = if child.as_widget().size().width.is_fill() {
+                    0.0
+                } else {
+                    child
+                        .as_widget()
+                        .layout(
+                            tree,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, max)
+                                .with_direction(limits.direction()),
+                        )
+                        .size()
+                        .width
+                };
+
+                (placement.columns.clone(), width)
+            })
+            .collect();
+
+        let columns = resolve(
+            &column_tracks,
+            self.column_spacing,
+            space(self.width, max.width),
+            &widths,
+        );
+
+        let mut nodes: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&placements)
+            .map(|((child, tree), placement)| {
+                let width =
+                    columns.span(&placement.columns, self.column_spacing);
+
+                child.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, max.height),
+                    )
+                    .with_direction(limits.direction()),
+                )
+            })
+            .collect();
+
+        let heights: Vec<_> = self
+            .children
+            .iter()
+            .zip(&nodes)
+            .zip(&placements)
+            .map(|((child, node), placement)| {
+                let height = if child.as_widget().size().height.is_fill() {
+                    0.0
+                } else {
+                    node.size().height
+                };
+
+                (placement.rows.clone(), height)
+            })
+            .collect();
+
+        let rows = resolve(
+            &row_tracks,
+            self.row_spacing,
+            space(self.height, max.height),
+            &heights,
+        );
+
+        for ((((child, tree), slot), placement), node) in self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&self.slots)
+            .zip(&placements)
+            .zip(&mut nodes)
+        {
+            let area = Rectangle::new(
+                Point::new(
+                    self.padding.left
+                        + columns.offset(
+                            placement.columns.start,
+                            self.column_spacing,
+                        ),
+                    self.padding.top
+                        + rows.offset(placement.rows.start, self.row_spacing),
+                ),
+                Size::new(
+                    columns.span(&placement.columns, self.column_spacing),
+                    rows.span(&placement.rows, self.row_spacing),
+                ),
+            );
+
+            if child.as_widget().size().height.is_fill() {
+                *node = child.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, area.size())
+                        .with_direction(limits.direction()),
+                );
+            }
+
+            node.move_to_mut(area.position());
+            node.align_mut(
+                slot.align_x.into(),
+                slot.align_y.into(),
+                area.size(),
+            );
+        }
+
+        let intrinsic_size = Size::new(
+            columns.total(self.column_spacing),
+            rows.total(self.row_spacing),
+        );
+
+        let size = limits
+            .resolve(self.width, self.height, intrinsic_size)
+            .expand(self.padding);
+
+        // Columns start on the right in a right-to-left layout
+        if limits.direction().is_rtl() {
+            for node in &mut nodes {
+                node.mirror_mut(size.width);
+            }
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+/// An element of a [`Grid`] with an explicit position, span, or alignment.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    slot: Slot,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] with the given content.
+    ///
+    /// By default, the [`Cell`] spans a single row and column and is placed
+    /// right after the previous cell of the [`Grid`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            slot: Slot {
+                row: None,
+                column: None,
+                row_span: 1,
+                column_span: 1,
+                align_x: alignment::Horizontal::Left,
+                align_y: alignment::Vertical::Top,
+            },
+        }
+    }
+
+    /// Places the [`Cell`] at the given row.
+    ///
+    /// If no column is set, the [`Cell`] is placed at the first column.
+    pub fn row(mut self, row: usize) -> Self {
+        self.slot.row = Some(row);
+        self
+    }
+
+    /// Places the [`Cell`] at the given column.
+    ///
+    /// If no row is set, the [`Cell`] is placed at the current row of the
+    /// [`Grid`], or at the next one if the column has already been passed.
+    pub fn column(mut self, column: usize) -> Self {
+        self.slot.column = Some(column);
+        self
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.slot.row_span = rows.max(1);
+        self
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.slot.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`Cell`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.slot.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`Cell`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.slot.align_y = alignment;
+        self
+    }
+}
+
+/// The position, span, and alignment of a [`Cell`].
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    row: Option<usize>,
+    column: Option<usize>,
+    row_span: usize,
+    column_span: usize,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+}
+
+/// The rows and columns covered by a [`Cell`].
+#[derive(Debug, Clone)]
+struct Placement {
+    rows: Range<usize>,
+    columns: Range<usize>,
+}
+
+/// Returns the track of every row or column covered by some cells, given
+/// their defined tracks and where the cells end.
+fn tracks(
+    definitions: &[Length],
+    ends: impl Iterator<Item = usize>,
+) -> Vec<Length> {
+    let count = ends.fold(definitions.len(), usize::max);
+
+    (0..count)
+        .map(|i| definitions.get(i).copied().unwrap_or(Length::Shrink))
+        .collect()
+}
+
+/// Returns the space that can be filled by the tracks of an axis, if any.
+fn space(length: Length, max: f32) -> Option<f32> {
+    (length != Length::Shrink && max.is_finite()).then_some(max)
+}
+
+/// Computes the sizes of the given tracks, so they fit the cells spanning
+/// them and fill the available space.
+fn resolve(
+    definitions: &[Length],
+    spacing: f32,
+    space: Option<f32>,
+    cells: &[(Range<usize>, f32)],
+) -> Tracks {
+    // Fill tracks behave as shrink tracks when there is no space to fill
+    let is_flexible = |track: &Length| match track {
+        Length::Shrink => true,
+        Length::Fill | Length::FillPortion(_) => space.is_none(),
+        Length::Fixed(_) => false,
+    };
+
+    let mut sizes: Vec<f32> = definitions
+        .iter()
+        .map(|track| match track {
+            Length::Fixed(amount) => *amount,
+            _ => 0.0,
+        })
+        .collect();
+
+    for (range, size) in cells.iter().filter(|(range, _)| range.len() == 1) {
+        if is_flexible(&definitions[range.start]) {
+            sizes[range.start] = sizes[range.start].max(*size);
+        }
+    }
+
+    // Cells spanning several tracks grow their flexible tracks evenly, once
+    // every single track has been sized
+    for (range, size) in cells.iter().filter(|(range, _)| range.len() > 1) {
+        let spans_fill = space.is_some()
+            && definitions[range.clone()].iter().any(Length::is_fill);
+
+        let flexible: Vec<usize> = range
+            .clone()
+            .filter(|&i| is_flexible(&definitions[i]))
+            .collect();
+
+        if spans_fill || flexible.is_empty() {
+            continue;
+        }
+
+        let current = Tracks::sum(&sizes[range.clone()], spacing);
+        let extra = (size - current) / flexible.len() as f32;
+
+        if extra > 0.0 {
+            for i in flexible {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    if let Some(space) = space {
+        let fill_sum: u32 = definitions
+            .iter()
+            .map(|track| u32::from(track.fill_factor()))
+            .sum();
+
+        if fill_sum > 0 {
+            let remaining = (space - Tracks::sum(&sizes, spacing)).max(0.0);
+
+            for (size, track) in sizes.iter_mut().zip(definitions) {
+                if track.is_fill() {
+                    *size = remaining * f32::from(track.fill_factor())
+                        / fill_sum as f32;
+                }
+            }
+        }
+    }
+
+    Tracks(sizes)
+}
+
+/// The resolved sizes of the rows or columns of a [`Grid`].
+struct Tracks(Vec<f32>);
+
+impl Tracks {
+    fn sum(sizes: &[f32], spacing: f32) -> f32 {
+        sizes.iter().sum::<f32>()
+            + spacing * sizes.len().saturating_sub(1) as f32
+    }
+
+    fn offset(&self, index: usize, spacing: f32) -> f32 {
+        self.0[..index].iter().sum::<f32>() + spacing * index as f32
+    }
+
+    fn span(&self, range: &Range<usize>, spacing: f32) -> f32 {
+        Self::sum(&self.0[range.clone()], spacing)
+    }
+
+    fn total(&self, spacing: f32) -> f32 {
+        Self::sum(&self.0, spacing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink_tracks_fit_their_cells() {
+        let tracks = resolve(
+            &[Length::Shrink, Length::Fixed(50.0), Length::Shrink],
+            10.0,
+            None,
+            &[(0..1, 30.0), (0..1, 40.0), (1..2, 100.0), (2..3, 20.0)],
+        );
+
+        assert_eq!(tracks.0, vec![40.0, 50.0, 20.0]);
+        assert_eq!(tracks.total(10.0), 130.0);
+        assert_eq!(tracks.offset(2, 10.0), 110.0);
+    }
+
+    #[test]
+    fn spanning_cells_grow_shrink_tracks_evenly() {
+        let tracks = resolve(
+            &[Length::Shrink, Length::Fixed(20.0), Length::Shrink],
+            10.0,
+            None,
+            &[(0..1, 10.0), (0..3, 100.0)],
+        );
+
+        assert_eq!(tracks.0, vec![35.0, 20.0, 25.0]);
+        assert_eq!(tracks.span(&(0..3), 10.0), 100.0);
+    }
+
+    #[test]
+    fn fill_tracks_share_the_remaining_space() {
+        let tracks = resolve(
+            &[Length::Fixed(40.0), Length::Fill, Length::FillPortion(3)],
+            10.0,
+            Some(200.0),
+            &[(1..2, 500.0)],
+        );
+
+        assert_eq!(tracks.0, vec![40.0, 35.0, 105.0]);
+    }
+
+    #[test]
+    fn fill_tracks_shrink_without_space() {
+        let tracks = resolve(
+            &[Length::Fill, Length::FillPortion(2)],
+            0.0,
+            None,
+            &[(0..1, 15.0), (1..2, 25.0)],
+        );
+
+        assert_eq!(tracks.0, vec![15.0, 25.0]);
+    }
+}
@@ -1,26 +1,38 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::color_picker::ColorPicker;
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
+use crate::core::layout;
 use crate::core::widget::operation;
-use crate::core::{Element, Length, Pixels};
+use crate::core::{Color, Element, Length, Pixels};
+use crate::date_picker::{self, DatePicker};
 use crate::keyed;
+use crate::menu;
+use crate::number_input::{self, NumberInput};
 use crate::pick_list::{self, PickList};
+use crate::picker;
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
 use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::tabs::{self, TabBar, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{self, TimePicker};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Themer};
+use crate::{
+    Classed, Column, ContextMenu, Directional, FocusOrder, Grid, List, MenuBar,
+    MouseArea, Row, Space, Stack, Sticky, Themer,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -51,6 +63,19 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Stack`] with the given children.
+///
+/// [`Stack`]: crate::Stack
+#[macro_export]
+macro_rules! stack {
+    () => (
+        $crate::Stack::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Stack::with_children([$($crate::core::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: crate::Container
@@ -97,6 +122,30 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new [`Stack`] with the given children.
+///
+/// [`Stack`]: crate::Stack
+pub fn stack<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Stack<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Stack::with_children(children)
+}
+
+/// Creates a new [`Grid`] with the given column tracks.
+///
+/// [`Grid`]: crate::Grid
+pub fn grid<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = impl Into<Length>>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Grid::new().columns(columns)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: crate::Scrollable
@@ -110,6 +159,19 @@ where
     Scrollable::new(content)
 }
 
+/// Keeps the given contents in view while the rest of the [`Scrollable`]
+/// containing them scrolls, like a section header.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn sticky<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Sticky::new(content)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: crate::Button
@@ -140,6 +202,22 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: crate::TreeView
+pub fn tree_view<'a, Id, Message, Theme, Renderer>(
+    roots: impl IntoIterator<
+        Item = tree_view::Node<'a, Id, Message, Theme, Renderer>,
+    >,
+) -> TreeView<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Theme: tree_view::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    TreeView::new(roots)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 ///
 /// [`Text`]: core::widget::Text
@@ -215,6 +293,26 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: crate::NumberInput
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy
+        + From<u8>
+        + PartialOrd
+        + std::str::FromStr
+        + std::fmt::Display
+        + 'a,
+    Theme: number_input::DefaultStyle + text_input::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -229,6 +327,48 @@ where
     TextEditor::new(content)
 }
 
+/// Creates a new [`DatePicker`].
+///
+/// [`DatePicker`]: crate::DatePicker
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    selected: Option<date_picker::Date>,
+    on_select: impl Fn(date_picker::Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: picker::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(selected, on_select)
+}
+
+/// Creates a new [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    selected: Option<time_picker::Time>,
+    on_select: impl Fn(time_picker::Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: picker::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(selected, on_select)
+}
+
+/// Creates a new [`ColorPicker`].
+///
+/// [`ColorPicker`]: crate::ColorPicker
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: Color,
+    on_change: impl Fn(Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: picker::DefaultStyle + text_input::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: crate::Slider
@@ -411,12 +551,25 @@ where
     crate::Shader::new(program)
 }
 
+/// Focuses the widget with the given [`Id`](core::widget::Id).
+pub fn focus<Message>(id: impl Into<core::widget::Id>) -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus(id.into()))
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<Message>() -> Command<Message>
 where
     Message: 'static,
 {
-    Command::widget(operation::focusable::focus_previous())
+    Command::batch([
+        Command::widget(operation::focusable::focus_previous()),
+        Command::widget(operation::scrollable::scroll_focused_into_view(
+            operation::scrollable::Placement::Nearest,
+        )),
+    ])
 }
 
 /// Focuses the next focusable widget.
@@ -424,7 +577,24 @@ pub fn focus_next<Message>() -> Command<Message>
 where
     Message: 'static,
 {
-    Command::widget(operation::focusable::focus_next())
+    Command::batch([
+        Command::widget(operation::focusable::focus_next()),
+        Command::widget(operation::scrollable::scroll_focused_into_view(
+            operation::scrollable::Placement::Nearest,
+        )),
+    ])
+}
+
+/// A container that focuses the widgets with the given ids in order when
+/// Tab or Shift+Tab is pressed.
+pub fn focus_order<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    order: impl IntoIterator<Item = impl Into<core::widget::Id>>,
+) -> FocusOrder<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    FocusOrder::new(content, order)
 }
 
 /// A container intercepting mouse events.
@@ -437,6 +607,118 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`List`] with the given items and a closure that produces
+/// the [`Element`] of an item given its index.
+///
+/// [`List`]: crate::List
+pub fn list<'a, T, Message, Theme, Renderer>(
+    items: &'a [T],
+    view: impl Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> List<'a, T, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    List::new(items, view)
+}
+
+/// Creates a new [`Markdown`] with the given items and a function to produce
+/// a message when a link is clicked.
+///
+/// [`Markdown`]: crate::Markdown
+#[cfg(feature = "markdown")]
+pub fn markdown<'a, Message, Theme, Renderer>(
+    items: &'a [crate::markdown::Item],
+    on_link: impl Fn(crate::markdown::Url) -> Message + 'a,
+) -> crate::Markdown<'a, Message, Theme, Renderer>
+where
+    Theme: crate::markdown::DefaultStyle + 'a,
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    crate::Markdown::new(items, on_link)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu::Item<Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`ContextMenu`] that opens the given items when the
+/// content is right clicked.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: menu::DefaultStyle + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`TabBar`] with the identifier of the active tab and a
+/// closure that produces a message when a tab is selected.
+///
+/// [`TabBar`]: crate::TabBar
+pub fn tab_bar<'a, Id, Message, Theme, Renderer>(
+    active: Id,
+    on_select: impl Fn(Id) -> Message + 'a,
+) -> TabBar<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Theme: tabs::DefaultStyle + 'a,
+    Renderer: core::text::Renderer,
+{
+    TabBar::new(active, on_select)
+}
+
+/// Creates new [`Tabs`] with the given [`TabBar`] and the content of its
+/// active tab.
+///
+/// [`Tabs`]: crate::Tabs
+pub fn tabs<'a, Id, Message, Theme, Renderer>(
+    bar: TabBar<'a, Id, Message, Theme, Renderer>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Tabs<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(bar, content)
+}
+
+/// Lays out the given content in the given [`layout::Direction`].
+pub fn directional<'a, Message, Theme, Renderer>(
+    direction: layout::Direction,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directional<'a, Message, Theme, Renderer> {
+    Directional::new(direction, content)
+}
+
+/// Lays out the given content from right to left.
+pub fn rtl<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directional<'a, Message, Theme, Renderer> {
+    Directional::new(layout::Direction::RightToLeft, content)
+}
+
+/// Lays out the given content from left to right.
+pub fn ltr<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directional<'a, Message, Theme, Renderer> {
+    Directional::new(layout::Direction::LeftToRight, content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -455,3 +737,15 @@ where
 {
     Themer::new(move |_| new_theme.clone(), content)
 }
+
+/// A widget that layers the given style [`Classes`] on top of the ones of
+/// the current [`Theme`] for its contents.
+///
+/// [`Classes`]: core::theme::Classes
+/// [`Theme`]: core::Theme
+pub fn classes<'a, Message, Theme, Renderer>(
+    classes: core::theme::Classes,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Classed<'a, Message, Theme, Renderer> {
+    Classed::new(classes, content)
+}
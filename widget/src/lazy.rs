@@ -336,9 +336,16 @@ impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
 where
     Renderer: core::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        self.with_overlay_maybe(|overlay| overlay.layout(renderer, bounds))
-            .unwrap_or_default()
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        self.with_overlay_maybe(|overlay| {
+            overlay.layout(renderer, bounds, direction)
+        })
+        .unwrap_or_default()
     }
 
     fn draw(
@@ -380,6 +380,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+                self.operation.bounds(id, bounds);
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -590,9 +594,16 @@ where
     Renderer: core::Renderer,
     S: 'static + Default,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        self.with_overlay_maybe(|overlay| overlay.layout(renderer, bounds))
-            .unwrap_or_default()
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        self.with_overlay_maybe(|overlay| {
+            overlay.layout(renderer, bounds, direction)
+        })
+        .unwrap_or_default()
     }
 
     fn draw(
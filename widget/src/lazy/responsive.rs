@@ -49,6 +49,7 @@ where
             view: Box::new(view),
             content: RefCell::new(Content {
                 size: Size::ZERO,
+                direction: layout::Direction::default(),
                 layout: None,
                 element: Element::new(horizontal_space().width(0)),
             }),
@@ -58,6 +59,7 @@ where
 
 struct Content<'a, Message, Theme, Renderer> {
     size: Size,
+    direction: layout::Direction,
     layout: Option<layout::Node>,
     element: Element<'a, Message, Theme, Renderer>,
 }
@@ -68,11 +70,14 @@ where
 {
     fn layout(&mut self, tree: &mut Tree, renderer: &Renderer) {
         if self.layout.is_none() {
-            self.layout = Some(self.element.as_widget().layout(
-                tree,
-                renderer,
-                &layout::Limits::new(Size::ZERO, self.size),
-            ));
+            self.layout = Some(
+                self.element.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, self.size)
+                        .with_direction(self.direction),
+                ),
+            );
         }
     }
 
@@ -153,6 +158,13 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let mut content = self.content.borrow_mut();
+
+        if content.direction != limits.direction() {
+            content.direction = limits.direction();
+            content.layout = None;
+        }
+
         layout::Node::new(limits.max())
     }
 
@@ -378,9 +390,16 @@ impl<'a, 'b, Message, Theme, Renderer>
 where
     Renderer: core::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        self.with_overlay_maybe(|overlay| overlay.layout(renderer, bounds))
-            .unwrap_or_default()
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        self.with_overlay_maybe(|overlay| {
+            overlay.layout(renderer, bounds, direction)
+        })
+        .unwrap_or_default()
     }
 
     fn draw(
@@ -2,8 +2,9 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
-#![forbid(unsafe_code, rust_2018_idioms)]
+#![forbid(unsafe_code)]
 #![deny(
+    rust_2018_idioms,
     missing_debug_implementations,
     missing_docs,
     unused_results,
@@ -15,30 +16,47 @@ pub use iced_renderer::graphics;
 pub use iced_runtime as runtime;
 pub use iced_runtime::core;
 
+mod classed;
 mod column;
+mod directional;
+mod focus_order;
 mod mouse_area;
-mod row;
 mod space;
+mod stack;
+mod sticky;
 mod themer;
 
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod grid;
 pub mod keyed;
+pub mod list;
+pub mod menu;
+pub mod menu_bar;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod picker;
 pub mod progress_bar;
 pub mod radio;
+pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
 pub mod vertical_slider;
 
 mod helpers;
@@ -59,14 +77,34 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use classed::Classed;
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use directional::Directional;
+#[doc(no_inline)]
+pub use focus_order::FocusOrder;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -85,6 +123,12 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
+pub use sticky::Sticky;
+#[doc(no_inline)]
+pub use tabs::{TabBar, Tabs};
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -93,10 +137,14 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "wgpu")]
@@ -134,5 +182,12 @@ pub mod qr_code;
 #[doc(no_inline)]
 pub use qr_code::QRCode;
 
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+
 pub use crate::core::theme::{self, Theme};
 pub use renderer::Renderer;
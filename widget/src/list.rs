@@ -0,0 +1,698 @@
+//! Display very large collections of items efficiently.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::scrollable;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A vertical list that only builds the items inside the visible viewport.
+///
+/// Unlike a [`Column`], a [`List`] does not build nor lay out every one of
+/// its items. Instead, it produces the [`Element`] of an item lazily, only
+/// when the item is visible (or near to visible) in the viewport of its
+/// parent—normally, a [`Scrollable`]. This makes it suitable for
+/// collections of thousands of items.
+///
+/// Items can have different heights. The height of an item is measured
+/// once it has been displayed and cached by its key, while an estimation is
+/// used for the items that have never been displayed.
+///
+/// The widget state of the displayed items is kept by key, so items can
+/// move around freely. Additionally, inserting items above the viewport of
+/// a [`Scrollable`] will not move the visible ones.
+///
+/// [`Column`]: crate::Column
+/// [`Scrollable`]: crate::Scrollable
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::{list, scrollable, text};
+///
+/// struct Entry {
+///     id: u64,
+///     line: String,
+/// }
+///
+/// fn view<'a, Message: 'a>(log: &'a [Entry]) -> Element<'a, Message> {
+///     scrollable(
+///         list(log, |_index, entry| text(&entry.line).into())
+///             .key(|entry| entry.id),
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct List<
+    'a,
+    T,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    items: &'a [T],
+    key: Box<dyn Fn(usize, &T) -> Key + 'a>,
+    view:
+        Box<dyn Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    spacing: f32,
+    estimated_height: Option<f32>,
+    overscan: f32,
+    elements: RefCell<Vec<Element<'a, Message, Theme, Renderer>>>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    List<'a, T, usize, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`List`] with the given items and a closure that
+    /// produces the [`Element`] of an item given its index.
+    ///
+    /// By default, the items are identified by their index. Use
+    /// [`List::key`] to identify them with a stable key instead.
+    pub fn new(
+        items: &'a [T],
+        view: impl Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            items,
+            key: Box::new(|index, _| index),
+            view: Box::new(view),
+            width: Length::Fill,
+            spacing: 0.0,
+            estimated_height: None,
+            overscan: Self::DEFAULT_OVERSCAN,
+            elements: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<'a, T, Key, Message, Theme, Renderer>
+    List<'a, T, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: crate::core::Renderer,
+{
+    /// The default distance outside of the viewport where items are built.
+    pub const DEFAULT_OVERSCAN: f32 = 200.0;
+
+    /// The height assumed for the viewport before the [`List`] knows it.
+    const INITIAL_VIEWPORT_HEIGHT: f32 = 1_000.0;
+
+    /// The height assumed for an item when nothing has been measured.
+    const FALLBACK_HEIGHT: f32 = 20.0;
+
+    /// The amount of widget trees kept for items that are no longer built.
+    const RETAINED_TREES: usize = 100;
+
+    /// Sets the closure that produces the key of an item.
+    ///
+    /// The key identifies an item across updates, so its widget state and
+    /// measured height are preserved when the collection changes.
+    pub fn key<K>(
+        self,
+        key: impl Fn(&T) -> K + 'a,
+    ) -> List<'a, T, K, Message, Theme, Renderer> {
+        List {
+            items: self.items,
+            key: Box::new(move |_, item| key(item)),
+            view: self.view,
+            width: self.width,
+            spacing: self.spacing,
+            estimated_height: self.estimated_height,
+            overscan: self.overscan,
+            elements: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the items of the [`List`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the estimated height of the items that have not been measured.
+    ///
+    /// By default, the average height of the measured items is used.
+    pub fn estimated_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.estimated_height = Some(height.into().0);
+        self
+    }
+
+    /// Sets the distance outside of the viewport where items are still
+    /// built.
+    ///
+    /// A bigger overscan builds more items, but rebuilds them less often
+    /// while scrolling.
+    pub fn overscan(mut self, overscan: impl Into<Pixels>) -> Self {
+        self.overscan = overscan.into().0;
+        self
+    }
+
+    /// Returns the height of the items that have not been measured.
+    fn estimate(&self, heights: &HashMap<Key, f32>) -> f32 {
+        self.estimated_height.unwrap_or_else(|| {
+            if heights.is_empty() {
+                Self::FALLBACK_HEIGHT
+            } else {
+                heights.values().sum::<f32>() / heights.len() as f32
+            }
+        })
+    }
+
+    /// Takes the tree of the item with the given key, if it has one.
+    fn take_tree(
+        state: &mut State<Key>,
+        trees: &mut Vec<Tree>,
+        key: &Key,
+    ) -> Option<Tree> {
+        if let Some(index) = state.keys.iter().position(|k| k == key) {
+            let _ = state.keys.swap_remove(index);
+
+            return Some(trees.swap_remove(index));
+        }
+
+        let index = state.retained.iter().position(|(k, _)| k == key)?;
+
+        state.retained.remove(index).map(|(_, tree)| tree)
+    }
+}
+
+#[derive(Debug)]
+struct State<Key> {
+    /// The visible part of the list, in local coordinates.
+    visible: Option<Range<f32>>,
+    /// The part of the list where items are built, in local coordinates.
+    built: Range<f32>,
+    /// The measured height of the items.
+    heights: HashMap<Key, f32>,
+    /// The width the items were measured with.
+    width: f32,
+    /// The keys of the built items, matching the children of the tree.
+    keys: Vec<Key>,
+    /// The trees of some items that are no longer built.
+    retained: VecDeque<(Key, Tree)>,
+    /// The first visible item and its position.
+    anchor: Option<(Key, f32)>,
+    /// The shift of the visible items during the last layout.
+    shift: scrollable::Anchor,
+}
+
+impl<Key> Default for State<Key> {
+    fn default() -> Self {
+        Self {
+            visible: None,
+            built: 0.0..0.0,
+            heights: HashMap::new(),
+            width: 0.0,
+            keys: Vec::new(),
+            retained: VecDeque::new(),
+            anchor: None,
+            shift: scrollable::Anchor::default(),
+        }
+    }
+}
+
+impl<'a, T, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, T, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // The items are built and diffed lazily during layout
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+        let item_limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+                .with_direction(limits.direction());
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let mut trees = std::mem::take(&mut tree.children);
+
+        if state.width != width {
+            state.heights.clear();
+            state.width = width;
+        }
+
+        let keys: Vec<Key> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (self.key)(index, item))
+            .collect();
+
+        if state.heights.len() > keys.len() * 2 {
+            let heights = std::mem::take(&mut state.heights);
+
+            state.heights = keys
+                .iter()
+                .filter_map(|key| Some((key.clone(), *heights.get(key)?)))
+                .collect();
+        }
+
+        let estimated_height = self.estimate(&state.heights);
+
+        let height = |key: &Key| {
+            state.heights.get(key).copied().unwrap_or(estimated_height)
+        };
+
+        // The position of every item, using the known heights
+        let mut positions = Vec::with_capacity(keys.len() + 1);
+        let mut y = 0.0;
+
+        for key in &keys {
+            positions.push(y);
+            y += height(key) + self.spacing;
+        }
+
+        positions.push(y);
+
+        // Keep the first visible item in place
+        let anchor = state.anchor.take().and_then(|(key, y)| {
+            let index = keys.iter().position(|k| *k == key)?;
+
+            Some((index, y))
+        });
+
+        let mut visible = state.visible.clone().unwrap_or(
+            0.0..limits.max().height.min(Self::INITIAL_VIEWPORT_HEIGHT),
+        );
+
+        let mut shift = 0.0;
+
+        if let Some((index, y)) = anchor {
+            shift = positions[index] - y;
+        }
+
+        visible.start += shift;
+        visible.end += shift;
+
+        let window = (visible.start - self.overscan).max(0.0)
+            ..visible.end + self.overscan;
+
+        // Build and lay out the items in the window
+        let first = positions[..keys.len()]
+            .partition_point(|y| *y <= window.start)
+            .saturating_sub(1);
+
+        let mut elements = Vec::new();
+        let mut nodes = Vec::new();
+        let mut children = Vec::new();
+        let mut built_keys = Vec::new();
+        let mut measured_shift = 0.0;
+        let mut y = positions[first];
+        let mut index = first;
+
+        while index < keys.len() && (y < window.end || elements.is_empty()) {
+            let key = &keys[index];
+            let element = (self.view)(index, &self.items[index]);
+
+            let mut tree = match Self::take_tree(state, &mut trees, key) {
+                Some(mut tree) => {
+                    tree.diff(&element);
+                    tree
+                }
+                None => Tree::new(&element),
+            };
+
+            let node = element
+                .as_widget()
+                .layout(&mut tree, renderer, &item_limits)
+                .move_to(Point::new(0.0, y));
+
+            let item_height = node.size().height;
+
+            if anchor.is_some_and(|(anchor, _)| anchor == index) {
+                measured_shift = y - positions[index];
+            }
+
+            let _ = state.heights.insert(key.clone(), item_height);
+
+            y += item_height + self.spacing;
+            index += 1;
+
+            elements.push(element);
+            nodes.push(node);
+            children.push(tree);
+            built_keys.push(key.clone());
+        }
+
+        // The items after the built ones are estimated again, since more
+        // items may have been measured
+        let estimated_height = self.estimate(&state.heights);
+
+        let rest = keys[index..]
+            .iter()
+            .map(|key| {
+                state.heights.get(key).copied().unwrap_or(estimated_height)
+                    + self.spacing
+            })
+            .sum::<f32>();
+
+        let total = (y + rest - self.spacing).max(0.0);
+
+        // The items built above the first visible one may have been
+        // measured for the first time
+        shift += measured_shift;
+        visible.start += measured_shift;
+        visible.end += measured_shift;
+
+        state.anchor = nodes
+            .iter()
+            .zip(&built_keys)
+            .find(|(node, _)| {
+                let bounds = node.bounds();
+
+                bounds.y + bounds.height > visible.start
+            })
+            .map(|(node, key)| (key.clone(), node.bounds().y));
+
+        state.shift.shift = shift;
+
+        if shift != 0.0 {
+            scrollable::Anchor::register();
+        }
+        state.visible = state.visible.as_ref().map(|_| visible);
+        state.built = Range {
+            start: if first == 0 {
+                f32::NEG_INFINITY
+            } else {
+                positions[first]
+            },
+            end: if index == keys.len() {
+                f32::INFINITY
+            } else {
+                y
+            },
+        };
+
+        // Retain the trees of the items that are no longer built
+        for (key, tree) in
+            std::mem::take(&mut state.keys).into_iter().zip(trees)
+        {
+            state.retained.push_front((key, tree));
+        }
+
+        state.retained.truncate(Self::RETAINED_TREES);
+        state.keys = built_keys;
+        tree.children = children;
+
+        *self.elements.borrow_mut() = elements;
+
+        let content_width = nodes
+            .iter()
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max);
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(content_width, total),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        operation.custom(&mut state.shift, None);
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.elements
+                .borrow()
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((element, tree), layout)| {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let bounds = layout.bounds();
+
+        let visible = (viewport.y - bounds.y).max(0.0)
+            ..(viewport.y + viewport.height - bounds.y).min(bounds.height);
+
+        if visible.start < state.built.start || visible.end > state.built.end {
+            shell.invalidate_layout();
+        }
+
+        state.visible = Some(visible);
+
+        self.elements
+            .get_mut()
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.elements
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((element, tree), layout) in self
+            .elements
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            element
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            self.elements.get_mut(),
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Key, Message, Theme, Renderer>
+    From<List<'a, T, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Key: Clone + Eq + Hash + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, T, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    type Item<'a> = Element<'a, (), (), Null>;
+
+    fn list(items: &[u64]) -> List<'_, u64, u64, (), (), Null> {
+        List::new(items, |_, _| Item::from(Space::with_height(10)))
+            .key(|item| *item)
+    }
+
+    fn layout(
+        list: &List<'_, u64, u64, (), (), Null>,
+        tree: &mut Tree,
+    ) -> layout::Node {
+        list.layout(
+            tree,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::MAX)),
+        )
+    }
+
+    fn scroll(
+        list: &mut List<'_, u64, u64, (), (), Null>,
+        tree: &mut Tree,
+        node: &layout::Node,
+        y: f32,
+    ) -> bool {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = list.on_event(
+            tree,
+            Event::Mouse(mouse::Event::CursorLeft),
+            Layout::new(node),
+            mouse::Cursor::Unavailable,
+            &Null,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(Point::new(0.0, y), Size::new(100.0, 100.0)),
+        );
+
+        shell.is_layout_invalid()
+    }
+
+    #[test]
+    fn builds_visible_items_only() {
+        let items: Vec<u64> = (0..10_000).collect();
+        let mut list = list(&items);
+        let mut tree = Tree::new(&list as &dyn Widget<_, _, _>);
+
+        let node = layout(&list, &mut tree);
+
+        assert_eq!(node.size().height, 100_000.0);
+        assert!(node.children().len() < 200);
+
+        assert!(scroll(&mut list, &mut tree, &node, 50_000.0));
+
+        let node = layout(&list, &mut tree);
+        let first = node.children()[0].bounds();
+
+        assert!(
+            first.y
+                <= 50_000.0 - List::<u64, u64, (), (), Null>::DEFAULT_OVERSCAN
+        );
+        assert!(node.children().len() < 200);
+        assert!(!scroll(&mut list, &mut tree, &node, 50_050.0));
+    }
+
+    #[test]
+    fn keeps_visible_items_in_place() {
+        let items: Vec<u64> = (0..1_000).collect();
+        let mut list = list(&items);
+        let mut tree = Tree::new(&list as &dyn Widget<_, _, _>);
+
+        let node = layout(&list, &mut tree);
+        let _ = scroll(&mut list, &mut tree, &node, 5_000.0);
+        let _ = layout(&list, &mut tree);
+
+        let items: Vec<u64> = (1_000..1_005).chain(0..1_000).collect();
+        let list = self::list(&items);
+        let node = layout(&list, &mut tree);
+
+        let state = tree.state.downcast_ref::<State<u64>>();
+
+        assert_eq!(node.size().height, 10_050.0);
+        assert_eq!(state.shift.shift, 50.0);
+    }
+}
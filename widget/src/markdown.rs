@@ -0,0 +1,738 @@
+//! Parse and display Markdown.
+//!
+//! Markdown is parsed by [`pulldown-cmark`] following CommonMark, together
+//! with the tables and strikethrough of GitHub Flavored Markdown. This
+//! module is only available with the `markdown` feature.
+//!
+//! [`pulldown-cmark`]: https://docs.rs/pulldown-cmark
+//!
+//! When the `highlighter` feature is enabled, fenced code blocks are
+//! highlighted with the language of their info string.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+//! use iced::widget::markdown;
+//!
+//! struct State {
+//!     notes: markdown::Content,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     LinkClicked(markdown::Url),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     markdown(state.notes.items(), Message::LinkClicked).into()
+//! }
+//! ```
+mod parse;
+mod rich;
+
+use crate::core::alignment;
+use crate::core::text;
+use crate::core::{
+    Background, Border, Color, Element, Font, Length, Pixels, Theme,
+};
+use crate::grid::{self, Grid};
+use crate::scrollable::{self, Scrollable};
+use crate::{container, Column, Container, Row, Space};
+
+use rich::Rich;
+
+use std::fmt;
+use std::rc::Rc;
+
+/// The URL of a link.
+pub type Url = String;
+
+/// Parses the given Markdown into a list of [`Item`].
+pub fn parse(markdown: &str) -> Vec<Item> {
+    Content::parse(markdown).items
+}
+
+/// Some parsed Markdown that can be extended incrementally.
+///
+/// Appending text with [`push_str`](Self::push_str) only parses again the
+/// last two blocks of the [`Content`], which makes it cheap to display text
+/// that is streamed in chunks.
+#[derive(Debug, Clone)]
+pub struct Content {
+    source: String,
+    items: Vec<Item>,
+    /// The offset of the blocks that are parsed again when appending.
+    last: usize,
+    /// The amount of items parsed from the `last` offset.
+    pending: usize,
+    #[cfg(feature = "highlighter")]
+    code_theme: iced_highlighter::Theme,
+}
+
+impl Content {
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self {
+            source: String::new(),
+            items: Vec::new(),
+            last: 0,
+            pending: 0,
+            #[cfg(feature = "highlighter")]
+            code_theme: iced_highlighter::Theme::SolarizedDark,
+        }
+    }
+
+    /// Creates a [`Content`] by parsing the given Markdown.
+    pub fn parse(markdown: &str) -> Self {
+        let mut content = Self::new();
+        content.push_str(markdown);
+        content
+    }
+
+    /// Appends some Markdown to the [`Content`].
+    pub fn push_str(&mut self, markdown: &str) {
+        if markdown.is_empty() {
+            return;
+        }
+
+        self.source.push_str(markdown);
+
+        let blocks = parse::blocks(&self.source[self.last..]);
+        self.items.truncate(self.items.len() - self.pending);
+
+        // Appended text may join the last two blocks, like a table and a
+        // row that was incomplete
+        let reparsed = blocks.len().saturating_sub(2);
+
+        if let Some((offset, _)) = blocks.get(reparsed) {
+            self.last += offset;
+        }
+
+        self.pending = blocks.len() - reparsed;
+
+        for (_, mut item) in blocks {
+            self.highlight(&mut item);
+            self.items.push(item);
+        }
+    }
+
+    /// Returns the parsed items of the [`Content`].
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns the Markdown source of the [`Content`].
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Sets the theme used to highlight the code blocks of the [`Content`].
+    #[cfg(feature = "highlighter")]
+    pub fn set_code_theme(&mut self, theme: iced_highlighter::Theme) {
+        self.code_theme = theme;
+
+        let mut items = std::mem::take(&mut self.items);
+
+        for item in &mut items {
+            self.highlight(item);
+        }
+
+        self.items = items;
+    }
+
+    #[cfg(feature = "highlighter")]
+    fn highlight(&self, item: &mut Item) {
+        use crate::core::text::highlighter::Highlighter as _;
+
+        match item {
+            Item::CodeBlock { language, code } => {
+                let source = code.to_string();
+
+                let mut highlighter = iced_highlighter::Highlighter::new(
+                    &iced_highlighter::Settings {
+                        theme: self.code_theme,
+                        extension: language
+                            .as_deref()
+                            .unwrap_or("txt")
+                            .to_owned(),
+                    },
+                );
+
+                let mut spans = Vec::new();
+
+                for (i, line) in source.split('\n').enumerate() {
+                    if i > 0 {
+                        spans.push(Span::code("\n".to_owned()));
+                    }
+
+                    for (range, highlight) in highlighter.highlight_line(line) {
+                        spans.push(Span {
+                            color: highlight.color(),
+                            ..Span::code(line[range].to_owned())
+                        });
+                    }
+                }
+
+                *code = Text { spans };
+            }
+            Item::List { items, .. } => {
+                for item in items.iter_mut().flatten() {
+                    self.highlight(item);
+                }
+            }
+            Item::Quote(items) => {
+                for item in items {
+                    self.highlight(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(not(feature = "highlighter"))]
+    fn highlight(&self, _item: &mut Item) {}
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A block of Markdown.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    /// A heading with its level, from 1 to 6.
+    Heading(u8, Text),
+    /// A paragraph.
+    Paragraph(Text),
+    /// A fenced code block.
+    CodeBlock {
+        /// The language of the code, if any.
+        language: Option<String>,
+        /// The code.
+        code: Text,
+    },
+    /// An ordered or unordered list.
+    List {
+        /// The number of the first item, if the list is ordered.
+        start: Option<u64>,
+        /// The blocks of every item of the list.
+        items: Vec<Vec<Item>>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A table.
+    Table {
+        /// The alignment of every column.
+        alignments: Vec<alignment::Horizontal>,
+        /// The header cells.
+        header: Vec<Text>,
+        /// The cells of every row.
+        rows: Vec<Vec<Text>>,
+    },
+    /// An image on its own paragraph.
+    Image {
+        /// The URL of the image.
+        url: Url,
+        /// The alternative text of the image.
+        alt: Text,
+    },
+    /// A thematic break.
+    Rule,
+}
+
+/// Some styled text of Markdown.
+///
+/// Its [`Display`](fmt::Display) implementation produces the plain text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Text {
+    spans: Vec<Span>,
+}
+
+impl Text {
+    fn code(code: String) -> Self {
+        Self {
+            spans: vec![Span::code(code)],
+        }
+    }
+}
+
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for span in &self.spans {
+            f.write_str(&span.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A run of text with the same style.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Span {
+    text: String,
+    strong: bool,
+    emphasis: bool,
+    strikethrough: bool,
+    code: bool,
+    link: Option<Url>,
+    color: Option<Color>,
+}
+
+impl Span {
+    fn code(text: String) -> Self {
+        Self {
+            text,
+            code: true,
+            ..Self::default()
+        }
+    }
+
+    fn has_style_of(&self, other: &Self) -> bool {
+        self.strong == other.strong
+            && self.emphasis == other.emphasis
+            && self.strikethrough == other.strikethrough
+            && self.code == other.code
+            && self.link == other.link
+            && self.color == other.color
+    }
+}
+
+/// Displays some parsed Markdown.
+///
+/// A [`Markdown`] is built from existing widgets: a [`Column`] of blocks,
+/// [`Grid`] tables and [`Scrollable`] code blocks, where every piece of
+/// text wraps word by word.
+#[allow(missing_debug_implementations)]
+pub struct Markdown<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    items: &'a [Item],
+    on_link: Box<dyn Fn(Url) -> Message + 'a>,
+    width: Length,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    image: Option<Box<dyn Fn(&str) -> Element<'a, Url, Theme, Renderer> + 'a>>,
+    style: Rc<Style<'a, Theme>>,
+}
+
+impl<'a, Message, Theme, Renderer> Markdown<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    /// The default spacing between the blocks of a [`Markdown`].
+    pub const DEFAULT_SPACING: f32 = 12.0;
+
+    /// Creates a new [`Markdown`] with the given items and a function to
+    /// produce a message when a link is clicked.
+    pub fn new(items: &'a [Item], on_link: impl Fn(Url) -> Message + 'a) -> Self
+    where
+        Theme: DefaultStyle + 'a,
+    {
+        Self {
+            items,
+            on_link: Box::new(on_link),
+            width: Length::Fill,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            image: None,
+            style: Rc::new(Box::new(Theme::default_style)),
+        }
+    }
+
+    /// Sets the width of the [`Markdown`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the blocks of the [`Markdown`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the size of the text of the [`Markdown`].
+    ///
+    /// Headings are scaled relative to it.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Displays images with the [`image::Handle`] produced by the given
+    /// function for their URL.
+    ///
+    /// Images are displayed as their alternative text otherwise.
+    ///
+    /// [`image::Handle`]: crate::image::Handle
+    #[cfg(feature = "image")]
+    pub fn images(
+        mut self,
+        handle: impl Fn(&str) -> crate::image::Handle + 'a,
+    ) -> Self
+    where
+        Theme: 'a,
+        Renderer:
+            crate::core::image::Renderer<Handle = crate::image::Handle> + 'a,
+    {
+        self.image = Some(Box::new(move |url| {
+            crate::Image::<crate::image::Handle>::new(handle(url)).into()
+        }));
+        self
+    }
+
+    /// Sets the style of the [`Markdown`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self {
+        self.style = Rc::new(Box::new(style));
+        self
+    }
+
+    fn view(&self, item: &'a Item) -> Element<'a, Url, Theme, Renderer>
+    where
+        Theme: scrollable::DefaultStyle + 'a,
+        Renderer: 'a,
+    {
+        let rich = |text: &'a Text| {
+            Rich::new(&text.spans[..], self.style.clone()).size(self.text_size)
+        };
+
+        match item {
+            Item::Heading(level, text) => rich(text)
+                .scale(match level {
+                    1 => 2.0,
+                    2 => 1.6,
+                    3 => 1.3,
+                    4 => 1.15,
+                    _ => 1.0,
+                })
+                .strong()
+                .into(),
+            Item::Paragraph(text) => rich(text).into(),
+            Item::CodeBlock { code, .. } => {
+                let style = self.style.clone();
+
+                Container::with_style(
+                    Scrollable::with_direction(
+                        Column::new()
+                            .push(rich(code).no_wrap())
+                            .padding([0, 0, 8, 0]),
+                        scrollable::Direction::Horizontal(
+                            scrollable::Properties::new()
+                                .width(4)
+                                .scroller_width(4),
+                        ),
+                    ),
+                    move |theme, _status| {
+                        let appearance = style(theme);
+
+                        container::Appearance {
+                            background: Some(appearance.code_block_background),
+                            border: appearance.code_block_border,
+                            ..container::Appearance::default()
+                        }
+                    },
+                )
+                .width(Length::Fill)
+                .padding([8, 12, 0, 12])
+                .into()
+            }
+            Item::List { start, items } => Column::with_children(
+                items.iter().enumerate().map(|(i, blocks)| {
+                    let marker = match start {
+                        Some(start) => format!("{}.", start + i as u64),
+                        None => String::from("•"),
+                    };
+
+                    Row::new()
+                        .push(
+                            Rich::new(
+                                vec![Span {
+                                    text: marker,
+                                    ..Span::default()
+                                }],
+                                self.style.clone(),
+                            )
+                            .size(self.text_size),
+                        )
+                        .push(self.blocks(blocks))
+                        .spacing(6)
+                        .into()
+                }),
+            )
+            .spacing(self.spacing / 2.0)
+            .into(),
+            Item::Quote(items) => {
+                let style = self.style.clone();
+
+                Container::with_style(
+                    self.blocks(items),
+                    move |theme, _status| {
+                        let appearance = style(theme);
+
+                        container::Appearance {
+                            background: Some(appearance.quote_background),
+                            border: appearance.quote_border,
+                            ..container::Appearance::default()
+                        }
+                    },
+                )
+                .width(Length::Fill)
+                .padding([6, 12])
+                .into()
+            }
+            Item::Table {
+                alignments,
+                header,
+                rows,
+            } => {
+                let style = self.style.clone();
+
+                let cells = header
+                    .iter()
+                    .map(|text| (rich(text).strong(), 0))
+                    .chain(rows.iter().enumerate().flat_map(|(row, cells)| {
+                        cells.iter().map(move |text| (rich(text), row + 1))
+                    }))
+                    .enumerate()
+                    .map(|(i, (content, row))| {
+                        grid::Cell::new(content)
+                            .row(row)
+                            .column(i % alignments.len())
+                            .align_x(alignments[i % alignments.len()])
+                    });
+
+                Container::with_style(
+                    cells
+                        .fold(Grid::new(), Grid::push_cell)
+                        .columns(vec![Length::Shrink; alignments.len()])
+                        .column_spacing(16)
+                        .row_spacing(6),
+                    move |theme, _status| container::Appearance {
+                        border: style(theme).table_border,
+                        ..container::Appearance::default()
+                    },
+                )
+                .padding([6, 10])
+                .into()
+            }
+            Item::Image { url, alt } => match &self.image {
+                Some(image) => image(url),
+                None => rich(alt).into(),
+            },
+            Item::Rule => {
+                let style = self.style.clone();
+
+                Container::with_style(
+                    Space::new(Length::Fill, Length::Fixed(1.0)),
+                    move |theme, _status| container::Appearance {
+                        background: Some(style(theme).rule.into()),
+                        ..container::Appearance::default()
+                    },
+                )
+                .into()
+            }
+        }
+    }
+
+    fn blocks(&self, items: &'a [Item]) -> Element<'a, Url, Theme, Renderer>
+    where
+        Theme: scrollable::DefaultStyle + 'a,
+        Renderer: 'a,
+    {
+        Column::with_children(items.iter().map(|item| self.view(item)))
+            .spacing(self.spacing / 2.0)
+            .into()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Markdown<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: scrollable::DefaultStyle + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(markdown: Markdown<'a, Message, Theme, Renderer>) -> Self {
+        let content: Element<'a, Url, Theme, Renderer> = Column::with_children(
+            markdown.items.iter().map(|item| markdown.view(item)),
+        )
+        .spacing(markdown.spacing)
+        .width(markdown.width)
+        .into();
+
+        content.map(markdown.on_link)
+    }
+}
+
+/// The appearance of some [`Markdown`].
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of links.
+    pub link_color: Color,
+    /// The [`Background`] of inline code.
+    pub inline_code_background: Background,
+    /// The [`Background`] of code blocks.
+    pub code_block_background: Background,
+    /// The [`Border`] of code blocks.
+    pub code_block_border: Border,
+    /// The [`Background`] of block quotes.
+    pub quote_background: Background,
+    /// The [`Border`] of block quotes.
+    pub quote_border: Border,
+    /// The [`Border`] of tables.
+    pub table_border: Border,
+    /// The [`Color`] of thematic breaks.
+    pub rule: Color,
+}
+
+/// The style of some [`Markdown`].
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The default style of some [`Markdown`].
+pub trait DefaultStyle {
+    /// Returns the default style of some [`Markdown`].
+    fn default_style(&self) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self) -> Appearance {
+        default(self)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of some [`Markdown`].
+pub fn default(theme: &Theme) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        link_color: palette.primary.base.color,
+        inline_code_background: palette.background.weak.color.into(),
+        code_block_background: palette.background.weak.color.into(),
+        code_block_border: Border {
+            radius: 4.0.into(),
+            ..Border::default()
+        },
+        quote_background: palette.background.weak.color.into(),
+        quote_border: Border {
+            color: palette.background.strong.color,
+            width: 0.0,
+            radius: 4.0.into(),
+        },
+        table_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        rule: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "# Release *notes*
+
+Some **bold**, `code` and a [link](https://iced.rs).
+
+- First
+- Second
+  1. Nested
+
+> Quoted
+lazily
+
+| Name | Size |
+|:-----|-----:|
+| a    | 1    |
+
+```rust
+fn main() {}
+```
+
+---
+";
+
+    #[test]
+    fn parses_blocks_and_inlines() {
+        let items = parse(SOURCE);
+
+        assert_eq!(items.len(), 7);
+        assert!(
+            matches!(&items[0], Item::Heading(1, text) if text.to_string() == "Release notes")
+        );
+
+        let Item::Paragraph(text) = &items[1] else {
+            panic!("expected a paragraph");
+        };
+
+        assert_eq!(text.to_string(), "Some bold, code and a link.");
+        assert!(text.spans[1].strong);
+        assert!(text.spans[3].code);
+        assert_eq!(text.spans[5].link.as_deref(), Some("https://iced.rs"));
+
+        let Item::List {
+            start: None,
+            items: list,
+        } = &items[2]
+        else {
+            panic!("expected a list");
+        };
+
+        assert_eq!(list.len(), 2);
+        assert!(matches!(&list[1][1], Item::List { start: Some(1), .. }));
+
+        assert!(
+            matches!(&items[3], Item::Quote(quote) if matches!(&quote[0], Item::Paragraph(text) if text.to_string() == "Quoted lazily"))
+        );
+        assert!(
+            matches!(&items[4], Item::Table { alignments, rows, .. } if alignments == &[alignment::Horizontal::Left, alignment::Horizontal::Right] && rows.len() == 1)
+        );
+        assert!(
+            matches!(&items[5], Item::CodeBlock { language: Some(language), code } if language == "rust" && code.to_string() == "fn main() {}")
+        );
+        assert_eq!(items[6], Item::Rule);
+    }
+
+    #[test]
+    fn parses_images_and_autolinks() {
+        let items =
+            parse("![Logo](logo.png)\n\n<iced@iced.rs> and ![icon](icon.png)");
+
+        assert!(
+            matches!(&items[0], Item::Image { url, alt } if url == "logo.png" && alt.to_string() == "Logo")
+        );
+
+        let Item::Paragraph(text) = &items[1] else {
+            panic!("expected a paragraph");
+        };
+
+        assert_eq!(text.spans[0].link.as_deref(), Some("mailto:iced@iced.rs"));
+        assert_eq!(text.spans[2].link.as_deref(), Some("icon.png"));
+    }
+
+    #[test]
+    fn pushing_text_matches_parsing_at_once() {
+        for size in 1..=16 {
+            let mut content = Content::new();
+
+            for chunk in SOURCE.as_bytes().chunks(size) {
+                content.push_str(std::str::from_utf8(chunk).unwrap());
+            }
+
+            assert_eq!(content.items(), parse(SOURCE).as_slice());
+        }
+    }
+}
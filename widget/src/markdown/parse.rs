@@ -0,0 +1,380 @@
+//! Parse the blocks and inlines of some Markdown with `pulldown-cmark`.
+use super::{Item, Span, Text, Url};
+use crate::core::alignment;
+
+use pulldown_cmark::{
+    CodeBlockKind, Event, LinkType, Options, Parser, Tag, TagEnd,
+};
+
+/// Parses the top-level blocks of the given Markdown, returning every item
+/// together with the byte offset where its block starts.
+pub(super) fn blocks(source: &str) -> Vec<(usize, Item)> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut builder = Builder::default();
+
+    for (event, range) in Parser::new_ext(source, options).into_offset_iter() {
+        if builder.is_top_level()
+            && matches!(event, Event::Start(_) | Event::Rule)
+        {
+            builder.offsets.push(range.start);
+        }
+
+        builder.push(event);
+    }
+
+    builder.offsets.into_iter().zip(builder.items).collect()
+}
+
+/// Builds the items of some Markdown out of its events.
+#[derive(Debug, Default)]
+struct Builder {
+    items: Vec<Item>,
+    offsets: Vec<usize>,
+    containers: Vec<Container>,
+    leaf: Option<Leaf>,
+    spans: Vec<Span>,
+    styles: Vec<Style>,
+    code: String,
+    /// The URL of the image that opens the current leaf, as long as nothing
+    /// else follows it.
+    image: Option<Url>,
+    is_image_open: bool,
+}
+
+/// A block containing other blocks.
+#[derive(Debug)]
+enum Container {
+    Quote(Vec<Item>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Item>>,
+    },
+    Item(Vec<Item>),
+    Table {
+        alignments: Vec<alignment::Horizontal>,
+        header: Vec<Text>,
+        rows: Vec<Vec<Text>>,
+        row: Vec<Text>,
+    },
+}
+
+/// A block containing inlines.
+#[derive(Debug)]
+enum Leaf {
+    Paragraph,
+    Heading(u8),
+    CodeBlock(Option<String>),
+    Html,
+    Cell,
+}
+
+/// The style of the inlines being built.
+#[derive(Debug, Clone, Default)]
+struct Style {
+    strong: bool,
+    emphasis: bool,
+    strikethrough: bool,
+    link: Option<Url>,
+}
+
+impl Builder {
+    fn is_top_level(&self) -> bool {
+        self.containers.is_empty() && self.leaf.is_none()
+    }
+
+    fn push(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some(Leaf::CodeBlock(_)) = self.leaf {
+                    self.code.push_str(&text);
+                } else {
+                    self.text(&text, false);
+                }
+            }
+            Event::Code(code) => self.text(&code, true),
+            Event::Html(html) => self.code.push_str(&html),
+            Event::InlineHtml(html) => self.text(&html, false),
+            Event::SoftBreak => self.text(" ", false),
+            Event::HardBreak => self.text("\n", false),
+            Event::Rule => {
+                self.close_leaf();
+                self.push_item(Item::Rule);
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.open_leaf(Leaf::Paragraph),
+            Tag::HtmlBlock => self.open_leaf(Leaf::Html),
+            Tag::Heading { level, .. } => {
+                self.open_leaf(Leaf::Heading(level as u8));
+            }
+            Tag::CodeBlock(kind) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().map(str::to_owned)
+                    }
+                    CodeBlockKind::Indented => None,
+                };
+
+                self.open_leaf(Leaf::CodeBlock(language));
+            }
+            Tag::BlockQuote(_) => self.open(Container::Quote(Vec::new())),
+            Tag::List(start) => self.open(Container::List {
+                start,
+                items: Vec::new(),
+            }),
+            Tag::Item => self.open(Container::Item(Vec::new())),
+            Tag::Table(alignments) => self.open(Container::Table {
+                alignments: alignments
+                    .into_iter()
+                    .map(|alignment| match alignment {
+                        pulldown_cmark::Alignment::Center => {
+                            alignment::Horizontal::Center
+                        }
+                        pulldown_cmark::Alignment::Right => {
+                            alignment::Horizontal::Right
+                        }
+                        _ => alignment::Horizontal::Left,
+                    })
+                    .collect(),
+                header: Vec::new(),
+                rows: Vec::new(),
+                row: Vec::new(),
+            }),
+            Tag::TableCell => self.open_leaf(Leaf::Cell),
+            Tag::Emphasis => self.style(|style| style.emphasis = true),
+            Tag::Strong => self.style(|style| style.strong = true),
+            Tag::Strikethrough => {
+                self.style(|style| style.strikethrough = true);
+            }
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            } => {
+                let url = if link_type == LinkType::Email {
+                    format!("mailto:{dest_url}")
+                } else {
+                    dest_url.into_string()
+                };
+
+                self.style(|style| style.link = Some(url));
+            }
+            Tag::Image { dest_url, .. } => {
+                let url = dest_url.into_string();
+
+                self.image = self.spans.is_empty().then(|| url.clone());
+                self.is_image_open = true;
+                self.style(|style| style.link = Some(url));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph
+            | TagEnd::HtmlBlock
+            | TagEnd::Heading(_)
+            | TagEnd::CodeBlock
+            | TagEnd::TableCell => self.close_leaf(),
+            TagEnd::TableHead => {
+                if let Some(Container::Table { header, row, .. }) =
+                    self.containers.last_mut()
+                {
+                    *header = std::mem::take(row);
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(Container::Table {
+                    alignments,
+                    rows,
+                    row,
+                    ..
+                }) = self.containers.last_mut()
+                {
+                    let mut row = std::mem::take(row);
+                    row.resize_with(alignments.len(), Text::default);
+
+                    rows.push(row);
+                }
+            }
+            TagEnd::BlockQuote(_)
+            | TagEnd::List(_)
+            | TagEnd::Item
+            | TagEnd::Table => self.close(),
+            TagEnd::Image => {
+                self.is_image_open = false;
+                let _ = self.styles.pop();
+            }
+            TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Link => {
+                let _ = self.styles.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn open(&mut self, container: Container) {
+        self.close_leaf();
+        self.containers.push(container);
+    }
+
+    fn close(&mut self) {
+        self.close_leaf();
+
+        let Some(container) = self.containers.pop() else {
+            return;
+        };
+
+        match container {
+            Container::Quote(items) => self.push_item(Item::Quote(items)),
+            Container::List { start, items } => {
+                self.push_item(Item::List { start, items });
+            }
+            Container::Item(blocks) => {
+                if let Some(Container::List { items, .. }) =
+                    self.containers.last_mut()
+                {
+                    items.push(blocks);
+                }
+            }
+            Container::Table {
+                alignments,
+                header,
+                rows,
+                ..
+            } => self.push_item(Item::Table {
+                alignments,
+                header,
+                rows,
+            }),
+        }
+    }
+
+    fn open_leaf(&mut self, leaf: Leaf) {
+        self.close_leaf();
+        self.leaf = Some(leaf);
+    }
+
+    /// Closes the current leaf, if any, pushing its contents to its parent.
+    fn close_leaf(&mut self) {
+        let Some(leaf) = self.leaf.take() else {
+            return;
+        };
+
+        let text = Text {
+            spans: std::mem::take(&mut self.spans),
+        };
+
+        self.styles.clear();
+
+        let image = self.image.take();
+
+        let item = match leaf {
+            Leaf::Paragraph => match image {
+                Some(url) => Item::Image { url, alt: text },
+                None => Item::Paragraph(text),
+            },
+            Leaf::Heading(level) => Item::Heading(level, text),
+            Leaf::CodeBlock(language) => {
+                let mut code = std::mem::take(&mut self.code);
+
+                if code.ends_with('\n') {
+                    let _ = code.pop();
+                }
+
+                Item::CodeBlock {
+                    language,
+                    code: Text::code(code),
+                }
+            }
+            Leaf::Html => {
+                let html = std::mem::take(&mut self.code);
+
+                Item::Paragraph(Text {
+                    spans: vec![Span {
+                        text: html.trim_end().to_owned(),
+                        ..Span::default()
+                    }],
+                })
+            }
+            Leaf::Cell => {
+                if let Some(Container::Table { row, .. }) =
+                    self.containers.last_mut()
+                {
+                    row.push(text);
+                }
+
+                return;
+            }
+        };
+
+        self.push_item(item);
+    }
+
+    fn push_item(&mut self, item: Item) {
+        match self.containers.last_mut() {
+            Some(Container::Quote(items) | Container::Item(items)) => {
+                items.push(item);
+            }
+            Some(Container::List { .. } | Container::Table { .. }) => {}
+            None => self.items.push(item),
+        }
+    }
+
+    /// Opens a paragraph for the inlines of the current block, unless one is
+    /// open already.
+    ///
+    /// The items of tight lists contain their inlines directly.
+    fn inline(&mut self) {
+        if self.leaf.is_none() {
+            self.leaf = Some(Leaf::Paragraph);
+        }
+    }
+
+    fn style(&mut self, f: impl FnOnce(&mut Style)) {
+        self.inline();
+
+        let mut style = self.styles.last().cloned().unwrap_or_default();
+        f(&mut style);
+
+        self.styles.push(style);
+    }
+
+    /// Pushes some text with the current style, merging it with the last
+    /// span when they share the same style.
+    fn text(&mut self, text: &str, is_code: bool) {
+        self.inline();
+
+        if !self.is_image_open {
+            self.image = None;
+        }
+
+        let style = self.styles.last().cloned().unwrap_or_default();
+
+        let span = Span {
+            text: text.to_owned(),
+            strong: style.strong,
+            emphasis: style.emphasis,
+            strikethrough: style.strikethrough,
+            code: is_code,
+            link: style.link,
+            ..Span::default()
+        };
+
+        match self.spans.last_mut() {
+            Some(last) if last.has_style_of(&span) => {
+                last.text.push_str(&span.text);
+            }
+            _ => self.spans.push(span),
+        }
+    }
+}
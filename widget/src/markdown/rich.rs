@@ -0,0 +1,468 @@
+//! Display spans of styled text that wrap word by word.
+use super::{Span, Style, Url};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::font;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Font, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// Some spans of styled text.
+pub(super) struct Rich<'a, Theme, Renderer> {
+    spans: Cow<'a, [Span]>,
+    size: Option<Pixels>,
+    scale: f32,
+    is_strong: bool,
+    wraps: bool,
+    style: Rc<Style<'a, Theme>>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Theme, Renderer> Rich<'a, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    pub fn new(
+        spans: impl Into<Cow<'a, [Span]>>,
+        style: Rc<Style<'a, Theme>>,
+    ) -> Self {
+        Self {
+            spans: spans.into(),
+            size: None,
+            scale: 1.0,
+            is_strong: false,
+            wraps: true,
+            style,
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    pub fn size(mut self, size: Option<Pixels>) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn strong(mut self) -> Self {
+        self.is_strong = true;
+        self
+    }
+
+    pub fn no_wrap(mut self) -> Self {
+        self.wraps = false;
+        self
+    }
+
+    fn font(&self, span: &Span, base: Font) -> Font {
+        let mut font = if span.code { Font::MONOSPACE } else { base };
+
+        if span.strong || self.is_strong {
+            font.weight = font::Weight::Bold;
+        }
+
+        if span.emphasis {
+            font.style = font::Style::Italic;
+        }
+
+        font
+    }
+
+    /// Returns the index of the span with a link under the given position.
+    fn link_at(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        position: Point,
+    ) -> Option<usize> {
+        state
+            .decorations
+            .iter()
+            .find(|decoration| {
+                decoration.bounds.contains(position)
+                    && self
+                        .spans
+                        .get(decoration.span)
+                        .is_some_and(|span| span.link.is_some())
+            })
+            .map(|decoration| decoration.span)
+    }
+}
+
+/// A word of a span, laid out.
+struct Fragment<P> {
+    span: usize,
+    position: Point,
+    paragraph: P,
+}
+
+/// The bounds of the words of a span in a single line.
+struct Decoration {
+    span: usize,
+    bounds: Rectangle,
+}
+
+struct State<P> {
+    fragments: Vec<Fragment<P>>,
+    decorations: Vec<Decoration>,
+    pressed: Option<usize>,
+}
+
+/// A piece of the text of a span.
+enum Piece<'a> {
+    Word(&'a str),
+    Spaces(usize),
+    Break,
+}
+
+fn pieces(text: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\n' => {
+                pieces.push(Piece::Break);
+                rest = &rest[1..];
+            }
+            ' ' | '\t' => {
+                let end = rest
+                    .find(|c: char| !matches!(c, ' ' | '\t'))
+                    .unwrap_or(rest.len());
+
+                pieces.push(Piece::Spaces(
+                    rest[..end]
+                        .chars()
+                        .map(|c| if c == '\t' { 4 } else { 1 })
+                        .sum(),
+                ));
+                rest = &rest[end..];
+            }
+            _ => {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+                pieces.push(Piece::Word(&rest[..end]));
+                rest = &rest[end..];
+            }
+        }
+    }
+
+    pieces
+}
+
+impl<'a, Theme, Renderer> Widget<Url, Theme, Renderer>
+    for Rich<'a, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            fragments: Vec::new(),
+            decorations: Vec::new(),
+            pressed: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let size = Pixels(
+            self.size.unwrap_or_else(|| renderer.default_size()).0 * self.scale,
+        );
+        let line_height =
+            f32::from(text::LineHeight::default().to_absolute(size));
+        let base = renderer.default_font();
+
+        let max_width = if self.wraps {
+            limits.max().width
+        } else {
+            f32::INFINITY
+        };
+
+        let paragraph = |content: &str, font: Font| {
+            Renderer::Paragraph::with_text(text::Text {
+                content,
+                bounds: Size::new(f32::INFINITY, line_height),
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            })
+        };
+
+        state.fragments.clear();
+        state.decorations.clear();
+
+        let mut spaces: Vec<(Font, f32)> = Vec::new();
+        let (mut x, mut y, mut width) = (0.0, 0.0, 0.0f32);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let font = self.font(span, base);
+
+            let space = match spaces.iter().find(|(other, _)| *other == font) {
+                Some((_, space)) => *space,
+                None => {
+                    let space = paragraph("a a", font).min_width()
+                        - paragraph("aa", font).min_width();
+
+                    spaces.push((font, space));
+                    space
+                }
+            };
+
+            for piece in pieces(&span.text) {
+                match piece {
+                    Piece::Break => {
+                        x = 0.0;
+                        y += line_height;
+                    }
+                    Piece::Spaces(count) => {
+                        if x > 0.0 || !self.wraps {
+                            x += count as f32 * space;
+                        }
+                    }
+                    Piece::Word(word) => {
+                        let paragraph = paragraph(word, font);
+                        let advance = paragraph.min_width();
+
+                        if x > 0.0 && x + advance > max_width {
+                            x = 0.0;
+                            y += line_height;
+                        }
+
+                        match state.decorations.last_mut() {
+                            Some(decoration)
+                                if decoration.span == index
+                                    && decoration.bounds.y == y =>
+                            {
+                                decoration.bounds.width =
+                                    x + advance - decoration.bounds.x;
+                            }
+                            _ => state.decorations.push(Decoration {
+                                span: index,
+                                bounds: Rectangle::new(
+                                    Point::new(x, y),
+                                    Size::new(advance, line_height),
+                                ),
+                            }),
+                        }
+
+                        state.fragments.push(Fragment {
+                            span: index,
+                            position: Point::new(x, y),
+                            paragraph,
+                        });
+
+                        x += advance;
+                        width = width.max(x);
+                    }
+                }
+            }
+        }
+
+        layout::Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(width, y + line_height),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Url>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let link = cursor
+            .position_in(bounds)
+            .and_then(|position| self.link_at(state, position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if link.is_some() =>
+            {
+                state.pressed = link;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(pressed) = state.pressed.take() {
+                    if link == Some(pressed) {
+                        if let Some(url) = self
+                            .spans
+                            .get(pressed)
+                            .and_then(|span| span.link.as_ref())
+                        {
+                            shell.publish(url.clone());
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if cursor
+            .position_in(layout.bounds())
+            .and_then(|position| self.link_at(state, position))
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let appearance = (self.style)(theme);
+        let translation = layout.bounds().position() - Point::ORIGIN;
+
+        for decoration in &state.decorations {
+            let span = &self.spans[decoration.span];
+            let bounds = decoration.bounds + translation;
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            if span.code && self.wraps {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x - 2.0,
+                            width: bounds.width + 4.0,
+                            ..bounds
+                        },
+                        border: Border {
+                            radius: 2.0.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.inline_code_background,
+                );
+            }
+
+            let mut line = |offset: f32, color| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: (bounds.y + bounds.height * offset).round(),
+                            height: 1.0,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            };
+
+            if span.link.is_some() {
+                line(0.85, appearance.link_color);
+            }
+
+            if span.strikethrough {
+                line(0.55, span.color.unwrap_or(style.text_color));
+            }
+        }
+
+        for fragment in &state.fragments {
+            let span = &self.spans[fragment.span];
+            let position = fragment.position + translation;
+
+            if !Rectangle::new(position, fragment.paragraph.min_bounds())
+                .intersects(viewport)
+            {
+                continue;
+            }
+
+            let color = match (span.color, &span.link) {
+                (Some(color), _) => color,
+                (None, Some(_)) => appearance.link_color,
+                (None, None) => style.text_color,
+            };
+
+            renderer.fill_paragraph(
+                &fragment.paragraph,
+                position,
+                color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Theme, Renderer> From<Rich<'a, Theme, Renderer>>
+    for Element<'a, Url, Theme, Renderer>
+where
+    Theme: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(rich: Rich<'a, Theme, Renderer>) -> Self {
+        Element::new(rich)
+    }
+}
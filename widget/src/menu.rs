@@ -0,0 +1,1075 @@
+//! Build nested menus of actions.
+//!
+//! A menu is a tree of [`Item`]s that can be displayed by a
+//! [`MenuBar`](crate::MenuBar) or a [`ContextMenu`](crate::ContextMenu).
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::{
+    Background, Border, Clipboard, Color, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Theme,
+};
+
+/// An entry of a menu.
+///
+/// An [`Item`] can be an action, a submenu, or a separator.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    access_key: Option<AccessKey>,
+    shortcut: Option<String>,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new action [`Item`] with the given label.
+    ///
+    /// The [`Item`] will be disabled until a message is set with
+    /// [`Item::on_select`].
+    ///
+    /// A character of the label preceded by `&` becomes the access key of
+    /// the [`Item`], which selects it when pressed while its menu is open.
+    /// Use `&&` to display a literal `&`.
+    pub fn new(label: impl AsRef<str>) -> Self {
+        let (label, access_key) = parse(label.as_ref());
+
+        Self {
+            label,
+            access_key,
+            shortcut: None,
+            kind: Kind::Action(None),
+        }
+    }
+
+    /// Creates a new submenu [`Item`] with the given label and items.
+    ///
+    /// The label may contain an access key, like in [`Item::new`].
+    pub fn submenu(
+        label: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu(items.into_iter().collect()),
+            ..Self::new(label)
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            access_key: None,
+            shortcut: None,
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the action [`Item`] is
+    /// selected.
+    pub fn on_select(mut self, message: Message) -> Self {
+        if let Kind::Action(on_select) = &mut self.kind {
+            *on_select = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the message that will be produced when the action [`Item`] is
+    /// selected, if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_select_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Action(on_select) = &mut self.kind {
+            *on_select = message;
+        }
+
+        self
+    }
+
+    /// Sets the keyboard shortcut hint displayed next to the label of the
+    /// [`Item`].
+    ///
+    /// The hint is only displayed; the shortcut itself must be handled by
+    /// the application.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Returns the label of the [`Item`], without its access key marker.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn message(&self) -> Option<&Message> {
+        match &self.kind {
+            Kind::Action(message) => message.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn access_key(&self) -> Option<char> {
+        self.access_key.map(|access_key| access_key.character)
+    }
+
+    fn is_selectable(&self) -> bool {
+        match &self.kind {
+            Kind::Action(message) => message.is_some(),
+            Kind::Submenu(_) => true,
+            Kind::Separator => false,
+        }
+    }
+}
+
+/// The access key of an [`Item`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AccessKey {
+    /// The byte index of the key in the label.
+    index: usize,
+    /// The lowercase character of the key.
+    character: char,
+}
+
+/// Removes the access key markers of a label, returning the first access
+/// key found.
+fn parse(label: &str) -> (String, Option<AccessKey>) {
+    let mut text = String::with_capacity(label.len());
+    let mut access_key = None;
+    let mut chars = label.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('&') | None => text.push('&'),
+            Some(next) => {
+                if access_key.is_none() {
+                    access_key = Some(AccessKey {
+                        index: text.len(),
+                        character: next.to_lowercase().next().unwrap_or(next),
+                    });
+                }
+
+                text.push(next);
+            }
+        }
+    }
+
+    (text, access_key)
+}
+
+/// Returns the index of the next selectable item in the given direction,
+/// wrapping around.
+fn next<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|step| match (current, forward) {
+            (None, true) => step - 1,
+            (None, false) => count - step,
+            (Some(current), true) => (current + step) % count,
+            (Some(current), false) => (current + count - step) % count,
+        })
+        .find(|&i| items[i].is_selectable())
+}
+
+/// The local state of an open menu.
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the menu is open.
+    pub(crate) is_open: bool,
+    /// The root being displayed, for menus with several roots.
+    pub(crate) root: usize,
+    /// The highlighted item of every open level of the menu.
+    pub(crate) path: Vec<usize>,
+}
+
+impl State {
+    pub(crate) fn open(&mut self, root: usize) {
+        self.is_open = true;
+        self.root = root;
+        self.path.clear();
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+    }
+}
+
+/// The open levels of a menu, displayed as an overlay.
+pub(crate) struct Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The roots of the menu; only the current one is displayed.
+    pub(crate) roots: &'b [Item<Message>],
+    pub(crate) state: &'b mut State,
+    /// The top-left corner of the first level.
+    pub(crate) position: Point,
+    /// An area where presses do not close the menu.
+    pub(crate) exclusion: Option<Rectangle>,
+    pub(crate) padding: Padding,
+    pub(crate) text_size: Option<Pixels>,
+    pub(crate) font: Option<Renderer::Font>,
+    pub(crate) style: &'b Style<'a, Theme>,
+    /// The access key underline of every displayed item, as computed
+    /// during layout.
+    pub(crate) underlines: Vec<Vec<Option<(f32, f32)>>>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn items(&self) -> &'b [Item<Message>] {
+        self.roots
+            .get(self.state.root)
+            .and_then(Item::items)
+            .unwrap_or(&[])
+    }
+
+    /// Returns the items of every open level.
+    fn levels(&self) -> Vec<&'b [Item<Message>]> {
+        let mut levels = vec![self.items()];
+
+        for &index in &self.state.path {
+            let Some(items) = levels
+                .last()
+                .and_then(|items| items.get(index))
+                .and_then(Item::items)
+            else {
+                break;
+            };
+
+            levels.push(items);
+        }
+
+        levels
+    }
+
+    /// Returns the level and index of the item under the cursor, if any.
+    fn hovered(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, Option<usize>)> {
+        let position = cursor.position()?;
+
+        // Submenus may overlap their parents, so the last level wins
+        layout
+            .children()
+            .enumerate()
+            .filter(|(_, menu)| menu.bounds().contains(position))
+            .last()
+            .map(|(level, menu)| {
+                (
+                    level,
+                    menu.children()
+                        .position(|item| item.bounds().contains(position)),
+                )
+            })
+    }
+
+    fn select(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let Some(item) = self.levels().get(level).and_then(|l| l.get(index))
+        else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Action(Some(message)) => {
+                shell.publish(message.clone());
+                self.state.close();
+            }
+            Kind::Submenu(items) => {
+                self.state.path.truncate(level);
+                self.state.path.push(index);
+
+                if let Some(first) = next(items, None, true) {
+                    self.state.path.push(first);
+                }
+            }
+            Kind::Action(None) | Kind::Separator => return,
+        }
+
+        shell.invalidate_layout();
+    }
+
+    /// Moves to the previous or next root, skipping the ones without items.
+    fn shift(&mut self, forward: bool) -> bool {
+        let count = self.roots.len();
+
+        let root = (1..count)
+            .map(|step| {
+                if forward {
+                    (self.state.root + step) % count
+                } else {
+                    (self.state.root + count - step) % count
+                }
+            })
+            .find(|&root| self.roots[root].items().is_some());
+
+        if let Some(root) = root {
+            self.state.open(root);
+
+            if let Some(first) = next(self.items(), None, true) {
+                self.state.path.push(first);
+            }
+        }
+
+        root.is_some()
+    }
+
+    fn on_key_press(
+        &mut self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status
+    where
+        Message: Clone,
+    {
+        let levels = self.levels();
+        let level = self.state.path.len().saturating_sub(1);
+
+        let Some(items) = levels.get(level).copied() else {
+            return event::Status::Ignored;
+        };
+
+        let current = self.state.path.get(level).copied();
+
+        match key.as_ref() {
+            keyboard::Key::Named(
+                key @ (key::Named::ArrowDown | key::Named::ArrowUp),
+            ) => {
+                if let Some(index) =
+                    next(items, current, key == key::Named::ArrowDown)
+                {
+                    self.state.path.truncate(level);
+                    self.state.path.push(index);
+                }
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                let is_submenu =
+                    current.and_then(|index| items[index].items()).is_some();
+
+                if is_submenu {
+                    self.select(level, current.unwrap_or_default(), shell);
+                } else if !self.shift(true) {
+                    return event::Status::Captured;
+                }
+            }
+            keyboard::Key::Named(key::Named::ArrowLeft) => {
+                if self.state.path.len() > 1 {
+                    let _ = self.state.path.pop();
+                } else if !self.shift(false) {
+                    return event::Status::Captured;
+                }
+            }
+            keyboard::Key::Named(key::Named::Enter | key::Named::Space) => {
+                if let Some(index) = current {
+                    self.select(level, index, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                if self.state.path.len() > 1 {
+                    let _ = self.state.path.pop();
+                } else {
+                    self.state.close();
+                }
+            }
+            keyboard::Key::Character(c)
+                if !modifiers.command() && !modifiers.control() =>
+            {
+                let Some(index) = c.chars().next().and_then(|c| {
+                    let c = c.to_lowercase().next().unwrap_or(c);
+
+                    items.iter().position(|item| {
+                        item.is_selectable() && item.access_key() == Some(c)
+                    })
+                }) else {
+                    return event::Status::Captured;
+                };
+
+                self.select(level, index, shell);
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        shell.invalidate_layout();
+
+        event::Status::Captured
+    }
+
+    /// Lays out a level of the menu, computing the access key underlines of
+    /// its items.
+    fn layout_level(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> (layout::Node, Vec<Option<(f32, f32)>>) {
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+
+        let label_width = items
+            .iter()
+            .map(|item| metrics.measure::<Renderer::Paragraph>(&item.label))
+            .fold(0.0, f32::max);
+
+        let shortcut_width = items
+            .iter()
+            .filter_map(|item| item.shortcut.as_deref())
+            .map(|shortcut| {
+                metrics.line_height * 2.0
+                    + metrics.measure::<Renderer::Paragraph>(shortcut)
+            })
+            .fold(0.0, f32::max);
+
+        let arrow_width = if items.iter().any(|item| item.items().is_some()) {
+            metrics.line_height
+        } else {
+            0.0
+        };
+
+        let width = self.padding.horizontal()
+            + label_width
+            + shortcut_width
+            + arrow_width;
+
+        let mut y = 0.0;
+
+        let nodes = items
+            .iter()
+            .map(|item| {
+                let height = match item.kind {
+                    Kind::Separator => self.padding.vertical() + 1.0,
+                    _ => metrics.line_height + self.padding.vertical(),
+                };
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to((0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        let underlines = items
+            .iter()
+            .map(|item| {
+                let access_key = item.access_key?;
+                let rest = &item.label[access_key.index..];
+                let character = rest.chars().next()?;
+
+                Some((
+                    metrics.measure::<Renderer::Paragraph>(
+                        &item.label[..access_key.index],
+                    ),
+                    metrics.measure::<Renderer::Paragraph>(
+                        &rest[..character.len_utf8()],
+                    ),
+                ))
+            })
+            .collect();
+
+        (
+            layout::Node::with_children(Size::new(width, y), nodes),
+            underlines,
+        )
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _direction: layout::Direction,
+    ) -> layout::Node {
+        let levels = self.levels();
+
+        let mut position = self.position;
+        let mut parent: Option<Rectangle> = None;
+        let mut nodes = Vec::with_capacity(levels.len());
+
+        self.underlines.clear();
+
+        for (level, items) in levels.iter().enumerate() {
+            let (node, underlines) = self.layout_level(renderer, items);
+            let size = node.size();
+
+            // Submenus open to the right of their parent, unless there is
+            // not enough space
+            let x = match parent {
+                Some(parent) if position.x + size.width > bounds.width => {
+                    parent.x - size.width
+                }
+                _ => position.x.min(bounds.width - size.width),
+            }
+            .max(0.0);
+
+            let y = position.y.min(bounds.height - size.height).max(0.0);
+
+            let node = node.move_to(Point::new(x, y));
+
+            if let Some(item) = self
+                .state
+                .path
+                .get(level)
+                .and_then(|&index| node.children().get(index))
+            {
+                position = Point::new(
+                    x + size.width,
+                    y + item.bounds().y - self.padding.top,
+                );
+            }
+
+            parent = Some(node.bounds());
+
+            nodes.push(node);
+            self.underlines.push(underlines);
+        }
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((level, Some(index))) = self.hovered(layout, cursor)
+                {
+                    let is_selectable =
+                        self.levels()[level][index].is_selectable();
+
+                    if is_selectable
+                        && self.state.path.get(level) != Some(&index)
+                    {
+                        self.state.path.truncate(level);
+                        self.state.path.push(index);
+
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.hovered(layout, cursor) {
+                    Some((level, Some(index))) => {
+                        let is_open_submenu = self.state.path.len() > level + 1
+                            && self.state.path[level] == index;
+
+                        if !is_open_submenu {
+                            self.select(level, index, shell);
+                        }
+                    }
+                    Some((_, None)) => {}
+                    None => {
+                        let is_excluded = self
+                            .exclusion
+                            .zip(cursor.position())
+                            .is_some_and(|(exclusion, position)| {
+                                exclusion.contains(position)
+                            });
+
+                        if is_excluded {
+                            return event::Status::Ignored;
+                        }
+
+                        self.state.close();
+                        shell.invalidate_layout();
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                return self.on_key_press(&key, modifiers, shell);
+            }
+            _ => {}
+        }
+
+        if self.hovered(layout, cursor).is_some() {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.hovered(layout, cursor) {
+            Some((level, Some(index)))
+                if self.levels()[level][index].is_selectable() =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|menu| menu.bounds().contains(cursor_position))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.style)(theme, Status::Active);
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let viewport = layout.bounds();
+
+        for (level, ((items, menu), underlines)) in self
+            .levels()
+            .into_iter()
+            .zip(layout.children())
+            .zip(&self.underlines)
+            .enumerate()
+        {
+            // Every level is drawn on top of the previous ones
+            renderer.with_layer(viewport, |renderer| {
+                let bounds = menu.bounds();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: appearance.border,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+
+                for ((index, item), (layout, underline)) in items
+                    .iter()
+                    .enumerate()
+                    .zip(menu.children().zip(underlines))
+                {
+                    let bounds = layout.bounds();
+
+                    if let Kind::Separator = item.kind {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + self.padding.left,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width
+                                        - self.padding.horizontal(),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            appearance.separator,
+                        );
+
+                        continue;
+                    }
+
+                    let is_highlighted =
+                        self.state.path.get(level) == Some(&index);
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + appearance.border.width,
+                                    width: bounds.width
+                                        - appearance.border.width * 2.0,
+                                    ..bounds
+                                },
+                                border: Border::rounded(
+                                    appearance.border.radius,
+                                ),
+                                ..renderer::Quad::default()
+                            },
+                            appearance.selected_background,
+                        );
+                    }
+
+                    let color = if !item.is_selectable() {
+                        appearance.disabled_text_color
+                    } else if is_highlighted {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.text_color
+                    };
+
+                    let x = bounds.x + self.padding.left;
+
+                    metrics.draw(
+                        renderer,
+                        &item.label,
+                        Point::new(x, bounds.center_y()),
+                        alignment::Horizontal::Left,
+                        color,
+                        bounds,
+                    );
+
+                    if let Some((offset, width)) = underline {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: x + offset,
+                                    y: (bounds.center_y()
+                                        + f32::from(metrics.size) / 2.0)
+                                        .round(),
+                                    width: *width,
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            color,
+                        );
+                    }
+
+                    let right = bounds.x + bounds.width - self.padding.right;
+
+                    if item.items().is_some() {
+                        metrics.draw(
+                            renderer,
+                            "›",
+                            Point::new(right, bounds.center_y()),
+                            alignment::Horizontal::Right,
+                            color,
+                            bounds,
+                        );
+                    } else if let Some(shortcut) = &item.shortcut {
+                        let arrow_width = if items
+                            .iter()
+                            .any(|item| item.items().is_some())
+                        {
+                            metrics.line_height
+                        } else {
+                            0.0
+                        };
+
+                        metrics.draw(
+                            renderer,
+                            shortcut,
+                            Point::new(right - arrow_width, bounds.center_y()),
+                            alignment::Horizontal::Right,
+                            if item.is_selectable() && !is_highlighted {
+                                appearance.shortcut_text_color
+                            } else {
+                                color
+                            },
+                            bounds,
+                        );
+                    }
+                }
+            });
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'b,
+    Theme: 'b,
+    Renderer: text::Renderer + 'b,
+    'a: 'b,
+{
+    /// Turns the [`Dropdown`] into an overlay [`overlay::Element`].
+    pub(crate) fn overlay(
+        self,
+    ) -> overlay::Element<'b, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+/// The text metrics of a menu.
+pub(crate) struct Metrics<Font> {
+    pub(crate) size: Pixels,
+    pub(crate) line_height: f32,
+    pub(crate) font: Font,
+}
+
+impl<Font: Copy> Metrics<Font> {
+    pub(crate) fn new<Renderer>(
+        renderer: &Renderer,
+        size: Option<Pixels>,
+        font: Option<Font>,
+    ) -> Self
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let size = size.unwrap_or_else(|| renderer.default_size());
+
+        Self {
+            size,
+            line_height: f32::from(
+                text::LineHeight::default().to_absolute(size),
+            ),
+            font: font.unwrap_or_else(|| renderer.default_font()),
+        }
+    }
+
+    fn text<'a>(
+        &self,
+        content: &'a str,
+        horizontal_alignment: alignment::Horizontal,
+    ) -> Text<'a, Font> {
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, self.line_height),
+            size: self.size,
+            line_height: text::LineHeight::default(),
+            font: self.font,
+            horizontal_alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// Returns the width of the given text.
+    pub(crate) fn measure<Paragraph>(&self, content: &str) -> f32
+    where
+        Paragraph: text::Paragraph<Font = Font>,
+    {
+        if content.is_empty() {
+            return 0.0;
+        }
+
+        Paragraph::with_text(self.text(content, alignment::Horizontal::Left))
+            .min_width()
+    }
+
+    /// Draws the given text vertically centered at the given position.
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        position: Point,
+        horizontal_alignment: alignment::Horizontal,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        renderer.fill_text(
+            self.text(content, horizontal_alignment),
+            position,
+            color,
+            clip_bounds,
+        );
+    }
+}
+
+/// The possible status of a menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The menu can be interacted with.
+    Active,
+    /// The [`MenuBar`](crate::MenuBar) of the menu has keyboard focus.
+    Focused,
+}
+
+/// The appearance of a menu.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the menu.
+    pub background: Background,
+    /// The [`Border`] of the menu.
+    pub border: Border,
+    /// The text [`Color`] of the menu.
+    pub text_color: Color,
+    /// The text [`Color`] of a disabled item of the menu.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the shortcut hints of the menu.
+    pub shortcut_text_color: Color,
+    /// The text [`Color`] of a highlighted item of the menu.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a highlighted item of the menu.
+    pub selected_background: Background,
+    /// The [`Color`] of the separators of the menu.
+    pub separator: Color,
+}
+
+/// The style of a menu.
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The default style of a menu.
+pub trait DefaultStyle {
+    /// Returns the default style of a menu.
+    fn default_style(&self, status: Status) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self, status: Status) -> Appearance {
+        default(self, status)
+    }
+}
+
+/// The styles of a menu that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self, _status: Status) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of a menu.
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let base = Appearance {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 0.0.into(),
+            color: palette.background.strong.color,
+        },
+        text_color: palette.background.weak.text,
+        disabled_text_color: palette.background.strong.color,
+        shortcut_text_color: palette.secondary.strong.color,
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        separator: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Focused => Appearance {
+            selected_text_color: palette.primary.base.text,
+            selected_background: palette.primary.base.color.into(),
+            ..base
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_access_keys() {
+        assert_eq!(parse("Open"), (String::from("Open"), None));
+
+        assert_eq!(
+            parse("&File"),
+            (
+                String::from("File"),
+                Some(AccessKey {
+                    index: 0,
+                    character: 'f'
+                })
+            )
+        );
+
+        assert_eq!(
+            parse("Save &As"),
+            (
+                String::from("Save As"),
+                Some(AccessKey {
+                    index: 5,
+                    character: 'a'
+                })
+            )
+        );
+
+        assert_eq!(
+            parse("Fish && &Chips &Later"),
+            (
+                String::from("Fish & Chips Later"),
+                Some(AccessKey {
+                    index: 7,
+                    character: 'c'
+                })
+            )
+        );
+
+        assert_eq!(parse("Trailing &"), (String::from("Trailing &"), None));
+    }
+}
@@ -0,0 +1,510 @@
+//! Display a bar of dropdown menus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::menu::{self, Dropdown, Item, Metrics};
+
+/// A horizontal bar of menus that open a dropdown when pressed.
+///
+/// Every [`Item`] of a [`MenuBar`] is displayed as a label on the bar. A
+/// submenu [`Item`] opens a dropdown with its items, which may contain
+/// nested submenus that open on hover. An action [`Item`] produces its
+/// message directly.
+///
+/// Once a dropdown is open, hovering over another label switches to its
+/// menu. The arrow keys navigate the open menus, `Enter` selects an item,
+/// `Escape` closes the current level, and the access keys of the items can
+/// be pressed to select them. Pressing `Alt` together with the access key
+/// of a label opens its menu.
+///
+/// Once a [`MenuBar`] has been clicked or focused, `Left` and `Right` move
+/// between its labels, while `Down`, `Enter` and `Space` open the menu of
+/// the current one.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::menu::Item;
+/// use iced::widget::menu_bar;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     New,
+///     Open,
+///     Quit,
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     menu_bar([
+///         Item::submenu("&File", [
+///             Item::new("&New").shortcut("Ctrl+N").on_select(Message::New),
+///             Item::new("&Open").shortcut("Ctrl+O").on_select(Message::Open),
+///             Item::submenu("Open &Recent", []),
+///             Item::separator(),
+///             Item::new("&Quit").on_select(Message::Quit),
+///         ]),
+///     ])
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    menus: Vec<Item<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: menu::Style<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Item<Message>>) -> Self
+    where
+        Theme: menu::DefaultStyle + 'a,
+    {
+        Self {
+            id: None,
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`MenuBar`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the labels and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`] and its menus.
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, menu::Status) -> menu::Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`MenuBar`] and its menus.
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: menu::Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            menu::Catalog::resolve(theme, &class, status).unwrap_or_else(|| {
+                menu::DefaultStyle::default_style(theme, status)
+            })
+        });
+        self
+    }
+
+    fn hovered(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        layout
+            .children()
+            .position(|label| cursor.is_over(label.bounds()))
+    }
+
+    /// Opens the menu at the given index or publishes its message.
+    fn press(
+        &self,
+        state: &mut menu::State,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let item = &self.menus[index];
+
+        if item.items().is_some() {
+            if state.is_open && state.root == index {
+                state.close();
+            } else {
+                state.open(index);
+            }
+
+            shell.invalidate_layout();
+        } else if let Some(message) = item.message() {
+            state.close();
+            shell.publish(message.clone());
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let height = metrics.line_height + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let labels = self
+            .menus
+            .iter()
+            .map(|item| {
+                let width = metrics
+                    .measure::<Renderer::Paragraph>(item.label())
+                    + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, labels)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if let Some(index) = self.hovered(layout, cursor) {
+                    self.press(&mut state.menu, index, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.hovered(layout, cursor) {
+                    let menu = &mut state.menu;
+
+                    if menu.is_open
+                        && menu.root != index
+                        && self.menus[index].items().is_some()
+                    {
+                        menu.open(index);
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if modifiers.alt() => {
+                let index = c.chars().next().and_then(|c| {
+                    let c = c.to_lowercase().next().unwrap_or(c);
+
+                    self.menus
+                        .iter()
+                        .position(|item| item.access_key() == Some(c))
+                });
+
+                if let Some(index) = index {
+                    self.press(&mut state.menu, index, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused
+                && !state.menu.is_open
+                && !self.menus.is_empty() =>
+            {
+                let count = self.menus.len();
+                let root = state.menu.root.min(count - 1);
+
+                match key {
+                    key::Named::ArrowLeft => {
+                        state.menu.root = (root + count - 1) % count;
+                    }
+                    key::Named::ArrowRight => {
+                        state.menu.root = (root + 1) % count;
+                    }
+                    key::Named::ArrowDown
+                    | key::Named::Enter
+                    | key::Named::Space => {
+                        self.press(&mut state.menu, root, shell);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if state.is_focused {
+            menu::Status::Focused
+        } else {
+            menu::Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        let hovered = self.hovered(layout, cursor);
+
+        for (index, (item, layout)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+
+            let is_highlighted = if state.menu.is_open {
+                state.menu.root == index
+            } else {
+                hovered == Some(index)
+                    || state.is_focused && state.menu.root == index
+            };
+
+            if is_highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            metrics.draw(
+                renderer,
+                item.label(),
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                alignment::Horizontal::Left,
+                if is_highlighted {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = &mut tree.state.downcast_mut::<State>().menu;
+
+        if !state.is_open {
+            return None;
+        }
+
+        let label = layout.children().nth(state.root)?.bounds();
+
+        Some(
+            Dropdown {
+                roots: &self.menus,
+                state,
+                position: Point::new(label.x, label.y + label.height)
+                    + translation,
+                exclusion: Some(layout.bounds() + translation),
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+                underlines: Vec::new(),
+            }
+            .overlay(),
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: menu::State,
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
@@ -0,0 +1,818 @@
+//! Edit numeric values with validation and stepping.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Widget,
+};
+use crate::menu::Metrics;
+use crate::text_input::{self, TextInput, Value};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A field that edits a number, with buttons to increment and decrement it.
+///
+/// The text of a [`NumberInput`] is parsed on every edit. Valid values
+/// within its bounds produce a message, while text that does not parse is
+/// kept as is and displayed in an invalid state until it is fixed or the
+/// field loses focus.
+///
+/// The value can also be stepped with the arrow keys while focused, with
+/// the mouse wheel while focused and hovered, and with the buttons. Holding `Shift`
+/// uses the [`shift_step`](Self::shift_step), if any.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::number_input;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// fn view<'a>(quantity: u32) -> Element<'a, Message> {
+///     number_input(quantity, Message::QuantityChanged)
+///         .min(1)
+///         .max(99)
+///         .shift_step(10u32)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    shift_step: Option<T>,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    parse: Box<dyn Fn(&str) -> Option<T> + 'a>,
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    text_input: TextInput<'a, Edit, Theme, Renderer>,
+    width: Length,
+    button_width: f32,
+    style: Style<'a, Theme>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Renderer: text::Renderer,
+{
+    /// The default width of the step buttons of a [`NumberInput`].
+    pub const DEFAULT_BUTTON_WIDTH: f32 = 20.0;
+
+    /// Creates a new [`NumberInput`] with the given value and the
+    /// function that produces a message when it changes.
+    ///
+    /// By default, the text is parsed with [`FromStr`] and formatted with
+    /// [`Display`].
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self
+    where
+        T: FromStr + Display + 'a,
+        Theme: DefaultStyle + text_input::DefaultStyle + 'a,
+    {
+        Self {
+            value,
+            min: None,
+            max: None,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            parse: Box::new(|text| text.trim().parse().ok()),
+            format: Box::new(ToString::to_string),
+            text_input: TextInput::new("", "").on_input(Edit),
+            width: Length::Fill,
+            button_width: Self::DEFAULT_BUTTON_WIDTH,
+            style: Box::new(<Theme as DefaultStyle>::default_style),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`] when the shift modifier
+    /// is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the function used to parse the text of the [`NumberInput`].
+    ///
+    /// Text that produces `None` is considered invalid.
+    pub fn parse(mut self, parse: impl Fn(&str) -> Option<T> + 'a) -> Self {
+        self.parse = Box::new(parse);
+        self
+    }
+
+    /// Sets the function used to format the value of the [`NumberInput`].
+    pub fn format(mut self, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the [`text_input::Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the text of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the font of the [`NumberInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the step buttons of the [`NumberInput`].
+    pub fn button_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.button_width = width.into().0;
+        self
+    }
+
+    /// Sets the style of the text field of the [`NumberInput`].
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Appearance + 'a,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the step buttons and the invalid state of the
+    /// [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`NumberInput`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn is_in_range(&self, value: T) -> bool {
+        !matches!(self.min, Some(min) if value < min)
+            && !matches!(self.max, Some(max) if value > max)
+    }
+
+    fn can_increment(&self) -> bool {
+        !matches!(self.max, Some(max) if self.value >= max)
+    }
+
+    fn can_decrement(&self) -> bool {
+        !matches!(self.min, Some(min) if self.value <= min)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Edit(String);
+
+#[derive(Debug, Default)]
+struct State {
+    text: String,
+    is_invalid: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    /// The pixels scrolled that do not add up to a line yet.
+    scrolled: f32,
+}
+
+impl State {
+    /// The amount of pixels of a scroll that count as a line.
+    const PIXELS_PER_LINE: f32 = 60.0;
+
+    /// Returns the amount of steps of the given scroll.
+    ///
+    /// A step is taken for every scrolled line, while scrolled pixels are
+    /// accumulated until they add up to a line.
+    fn scroll_steps(&mut self, delta: mouse::ScrollDelta) -> f32 {
+        match delta {
+            mouse::ScrollDelta::Lines { y, .. } => {
+                self.scrolled = 0.0;
+
+                if y == 0.0 {
+                    0.0
+                } else {
+                    y.signum()
+                }
+            }
+            mouse::ScrollDelta::Pixels { y, .. } => {
+                self.scrolled += y;
+
+                let lines = (self.scrolled / Self::PIXELS_PER_LINE).trunc();
+                self.scrolled -= lines * Self::PIXELS_PER_LINE;
+
+                lines
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    /// Steps the value in the given direction and publishes the result.
+    fn step_by(
+        &mut self,
+        state: &mut State,
+        direction: f64,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let step = if state.keyboard_modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        };
+
+        let Some(value) =
+            stepped(self.value, step, self.min, self.max, direction)
+        else {
+            return;
+        };
+
+        state.text = (self.format)(&value);
+        state.is_invalid = false;
+        shell.invalidate_layout();
+
+        if value != self.value {
+            shell.publish((self.on_change)(value));
+            self.value = value;
+        }
+    }
+}
+
+/// Returns the value one step away from `value` in the given direction,
+/// snapped to a multiple of `step` and clamped to the bounds.
+fn stepped<T>(
+    value: T,
+    step: T,
+    min: Option<T>,
+    max: Option<T>,
+    direction: f64,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let step: f64 = step.into();
+    let steps = (value.into() / step).round() + direction;
+
+    // Dividing by the reciprocal of a fractional step (e.g. `0.1`) avoids
+    // accumulating rounding errors that would show up in the text.
+    let new_value = if step < 1.0 {
+        steps / step.recip()
+    } else {
+        steps * step
+    };
+
+    if let Some(max) = max {
+        if new_value > max.into() {
+            return Some(max);
+        }
+    }
+
+    if let Some(min) = min {
+        if new_value < min.into() {
+            return Some(min);
+        }
+    }
+
+    T::from_f64(new_value)
+}
+
+fn is_focused<P: text::Paragraph + 'static>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<P>>()
+        .is_focused()
+}
+
+/// Returns the layouts of the text field and the increment and decrement
+/// buttons.
+fn parts(layout: Layout<'_>) -> (Layout<'_>, Rectangle, Rectangle) {
+    let mut children = layout.children();
+
+    let input = children.next().expect("Text field layout");
+    let increment = children.next().expect("Increment button layout");
+    let decrement = children.next().expect("Decrement button layout");
+
+    (input, increment.bounds(), decrement.bounds())
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            text: (self.format)(&self.value),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // Keep the text in sync with the value unless the user is editing it
+        if !is_focused::<Renderer::Paragraph>(&tree.children[0]) {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.text = (self.format)(&self.value);
+            state.is_invalid = false;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let limits = limits.width(self.width);
+
+        let input = self.text_input.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(self.button_width, 0.0)),
+            Some(&Value::new(&state.text)),
+        );
+
+        let size = input.size();
+        let button = Size::new(self.button_width, size.height / 2.0);
+
+        let increment =
+            layout::Node::new(button).move_to(Point::new(size.width, 0.0));
+        let decrement = layout::Node::new(button)
+            .move_to(Point::new(size.width, button.height));
+
+        layout::Node::with_children(
+            Size::new(size.width + self.button_width, size.height),
+            vec![input, increment, decrement],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.text_input.operate_state(
+            &mut tree.children[0],
+            layout.bounds(),
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let (input, increment, decrement) = parts(layout);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let direction = if cursor.is_over(increment) {
+                Some(1.0)
+            } else if cursor.is_over(decrement) {
+                Some(-1.0)
+            } else {
+                None
+            };
+
+            if let Some(direction) = direction {
+                let state = tree.state.downcast_mut::<State>();
+                self.step_by(state, direction, shell);
+
+                return event::Status::Captured;
+            }
+        }
+
+        let was_focused = is_focused::<Renderer::Paragraph>(&tree.children[0]);
+
+        self.text_input
+            .set_value(Value::new(&tree.state.downcast_ref::<State>().text));
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            input,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        let is_focused = is_focused::<Renderer::Paragraph>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        for Edit(text) in edits {
+            match (self.parse)(&text) {
+                Some(value) if self.is_in_range(value) => {
+                    state.is_invalid = false;
+
+                    if value != self.value {
+                        shell.publish((self.on_change)(value));
+                        self.value = value;
+                    }
+                }
+                _ => {
+                    state.is_invalid = true;
+                }
+            }
+
+            state.text = text;
+            shell.invalidate_layout();
+        }
+
+        if was_focused && !is_focused {
+            state.text = (self.format)(&self.value);
+            state.is_invalid = false;
+            state.scrolled = 0.0;
+            shell.invalidate_layout();
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if is_focused => {
+                let direction = match key {
+                    key::Named::ArrowUp => 1.0,
+                    key::Named::ArrowDown => -1.0,
+                    _ => return event::Status::Ignored,
+                };
+
+                self.step_by(state, direction, shell);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_focused && cursor.is_over(layout.bounds()) =>
+            {
+                let steps = state.scroll_steps(delta);
+
+                if steps != 0.0 {
+                    self.step_by(state, steps.into(), shell);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (input, increment, decrement) = parts(layout);
+
+        if (cursor.is_over(increment) && self.can_increment())
+            || (cursor.is_over(decrement) && self.can_decrement())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            self.text_input.mouse_interaction(
+                &tree.children[0],
+                input,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let (input, increment, decrement) = parts(layout);
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input,
+            cursor,
+            Some(&Value::new(&state.text)),
+            viewport,
+        );
+
+        if state.is_invalid {
+            let appearance = (self.style)(theme, Status::Active);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: input.bounds(),
+                    border: appearance.invalid_border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let metrics =
+            Metrics::new(renderer, Some(Pixels(increment.height * 0.8)), None);
+
+        for (bounds, icon, is_enabled) in [
+            (increment, "▴", self.can_increment()),
+            (decrement, "▾", self.can_decrement()),
+        ] {
+            let status = if !is_enabled {
+                Status::Disabled
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let appearance = (self.style)(theme, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.button_background,
+            );
+
+            metrics.draw(
+                renderer,
+                icon,
+                bounds.center(),
+                alignment::Horizontal::Center,
+                appearance.button_icon,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy
+        + From<u8>
+        + PartialOrd
+        + Into<f64>
+        + num_traits::FromPrimitive
+        + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(number_input)
+    }
+}
+
+/// The possible status of a step button of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The button can be pressed.
+    Active,
+    /// The button is being hovered.
+    Hovered,
+    /// The value is at its bound and the button cannot be pressed.
+    Disabled,
+}
+
+/// The appearance of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of a step button.
+    pub button_background: Background,
+    /// The [`Color`] of the arrow of a step button.
+    pub button_icon: Color,
+    /// The [`Border`] drawn around the text field when its text is invalid.
+    pub invalid_border: Border,
+}
+
+/// The style of a [`NumberInput`].
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The default style of a [`NumberInput`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`NumberInput`].
+    fn default_style(&self, status: Status) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self, status: Status) -> Appearance {
+        default(self, status)
+    }
+}
+
+/// The styles of a [`NumberInput`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self, _status: Status) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of a [`NumberInput`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let active = Appearance {
+        button_background: Background::Color(palette.background.weak.color),
+        button_icon: palette.background.weak.text,
+        invalid_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.danger.base.color,
+        },
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Appearance {
+            button_background: Background::Color(
+                palette.background.strong.color,
+            ),
+            button_icon: palette.background.strong.text,
+            ..active
+        },
+        Status::Disabled => Appearance {
+            button_icon: palette.background.strong.color,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepped_snaps_and_clamps() {
+        assert_eq!(stepped(5u32, 1, None, None, 1.0), Some(6));
+        assert_eq!(stepped(7u32, 5, None, None, 1.0), Some(10));
+        assert_eq!(stepped(9u32, 5, Some(0), Some(12), 1.0), Some(12));
+        assert_eq!(stepped(0u32, 1, Some(0), None, -1.0), Some(0));
+        assert_eq!(stepped(0.2f64, 0.1, None, None, 1.0), Some(0.3));
+    }
+
+    #[test]
+    fn scrolled_pixels_add_up_to_lines() {
+        let mut state = State::default();
+        let pixels = |y| mouse::ScrollDelta::Pixels { x: 0.0, y };
+
+        assert_eq!(state.scroll_steps(pixels(25.0)), 0.0);
+        assert_eq!(state.scroll_steps(pixels(25.0)), 0.0);
+        assert_eq!(state.scroll_steps(pixels(25.0)), 1.0);
+        assert_eq!(state.scroll_steps(pixels(-100.0)), -1.0);
+        assert_eq!(state.scroll_steps(pixels(150.0)), 2.0);
+
+        assert_eq!(
+            state.scroll_steps(mouse::ScrollDelta::Lines { x: 0.0, y: -3.0 }),
+            -1.0
+        );
+        assert_eq!(state.scroll_steps(pixels(30.0)), 0.0);
+    }
+}
@@ -7,6 +7,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
+use crate::core::theme::Class;
 use crate::core::touch;
 use crate::core::widget::Tree;
 use crate::core::{
@@ -233,7 +234,12 @@ impl<'a, Message, Theme, Renderer>
 where
     Renderer: text::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
         let space_below =
             bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
@@ -249,7 +255,8 @@ where
                 },
             ),
         )
-        .width(self.width);
+        .width(self.width)
+        .with_direction(direction);
 
         let node = self.container.layout(self.state, renderer, &limits);
         let size = node.size();
@@ -594,6 +601,45 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of the list of a [`Menu`] that can be referred to by
+/// [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope. The list of a [`Menu`] has no status, so its [`Classes`] are
+/// registered with a `()` status; e.g. `|theme, ()| style(theme)`.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class) -> Option<Appearance> {
+        self.resolve_class(class, ())
+    }
+}
+
+impl<'a, Theme> Style<'a, Theme> {
+    /// Sets the style [`Class`] of the list of the [`Menu`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.list = Box::new(move |theme: &Theme| {
+            theme
+                .resolve(&class)
+                .unwrap_or_else(|| (Theme::default_style().list)(theme))
+        });
+        self
+    }
+}
+
 /// The default style of the list of a [`Menu`].
 pub fn default(theme: &Theme) -> Appearance {
     let palette = theme.extended_palette();
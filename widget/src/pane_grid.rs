@@ -14,6 +14,7 @@ mod direction;
 mod draggable;
 mod node;
 mod pane;
+mod snapshot;
 mod split;
 mod title_bar;
 
@@ -26,6 +27,7 @@ pub use direction::Direction;
 pub use draggable::Draggable;
 pub use node::Node;
 pub use pane::Pane;
+pub use snapshot::Snapshot;
 pub use split::Split;
 pub use state::State;
 pub use title_bar::TitleBar;
@@ -35,6 +37,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay::{self, Group};
 use crate::core::renderer;
+use crate::core::theme::Class;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
@@ -223,6 +226,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`PaneGrid`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme| {
+            theme
+                .resolve(&class)
+                .unwrap_or_else(|| theme.default_style())
+        });
+        self
+    }
+
     fn drag_enabled(&self) -> bool {
         (!self.contents.is_maximized())
             .then(|| self.on_drag.is_some())
@@ -293,7 +314,8 @@ where
                 let node = content.layout(
                     tree,
                     renderer,
-                    &layout::Limits::new(size, size),
+                    &layout::Limits::new(size, size)
+                        .with_direction(limits.direction()),
                 );
 
                 Some(node.move_to(Point::new(region.x, region.y)))
@@ -1160,6 +1182,26 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`PaneGrid`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// A [`PaneGrid`] has no status, so its [`Classes`] are registered with
+/// a `()` status; e.g. `|theme, ()| style(theme)`.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class) -> Option<Appearance> {
+        self.resolve_class(class, ())
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self) -> Appearance {
         *self
@@ -3,7 +3,12 @@ use crate::pane_grid::Axis;
 /// The arrangement of a [`PaneGrid`].
 ///
 /// [`PaneGrid`]: super::PaneGrid
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
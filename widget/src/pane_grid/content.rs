@@ -172,7 +172,8 @@ where
             let title_bar_layout = title_bar.layout(
                 &mut tree.children[1],
                 renderer,
-                &layout::Limits::new(Size::ZERO, max_size),
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
             );
 
             let title_bar_size = title_bar_layout.size();
@@ -186,7 +187,8 @@ where
                         max_size.width,
                         max_size.height - title_bar_size.height,
                     ),
-                ),
+                )
+                .with_direction(limits.direction()),
             );
 
             layout::Node::with_children(
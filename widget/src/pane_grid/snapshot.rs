@@ -0,0 +1,89 @@
+use crate::pane_grid::Configuration;
+
+/// A description of the layout of a [`PaneGrid`] that can be saved and
+/// restored later on, even across restarts.
+///
+/// Since [`Pane`] identifiers are only meaningful to the [`State`] that
+/// produced them, a [`Snapshot`] identifies every pane with a key of your
+/// choice instead; like the name of the file it displays.
+///
+/// With the `serde` feature enabled, a [`Snapshot`] can be serialized as long
+/// as its keys can. Every split is written as a map with its `axis`, its
+/// `ratio` and its `a` and `b` halves, while every pane is written as its
+/// key.
+///
+/// [`PaneGrid`]: super::PaneGrid
+/// [`Pane`]: super::Pane
+/// [`State`]: super::State
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot<K> {
+    /// The arrangement of the panes, with the axis and ratio of every split.
+    pub configuration: Configuration<K>,
+
+    /// The key of the maximized pane, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub maximized: Option<K>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pane_grid::{Axis, Configuration, Snapshot, State};
+
+    #[test]
+    fn snapshots_round_trip() {
+        let (mut state, editor) = State::new("editor");
+        let (terminal, split) =
+            state.split(Axis::Horizontal, editor, "terminal").unwrap();
+        let _ = state.split(Axis::Vertical, terminal, "logs");
+
+        state.resize(split, 0.7);
+        state.maximize(terminal);
+
+        let snapshot = state.snapshot(|_pane, name| name.to_string());
+
+        assert_eq!(snapshot.maximized.as_deref(), Some("terminal"));
+
+        let restored = State::from_snapshot(snapshot.clone(), |name| name);
+
+        assert_eq!(restored.len(), 3);
+        assert_eq!(
+            restored.maximized().and_then(|pane| restored.get(pane)),
+            Some(&"terminal".to_string())
+        );
+        assert_eq!(restored.snapshot(|_pane, name| name.clone()), snapshot);
+    }
+
+    #[test]
+    fn invalid_ratios_are_clamped() {
+        let split = |ratio| Configuration::Split {
+            axis: Axis::Vertical,
+            ratio,
+            a: Box::new(Configuration::Pane("a")),
+            b: Box::new(Configuration::Pane("b")),
+        };
+
+        let ratio = |ratio| {
+            let state = State::from_snapshot(
+                Snapshot {
+                    configuration: split(ratio),
+                    maximized: None,
+                },
+                |name| name,
+            );
+
+            match state.snapshot(|_pane, name| *name).configuration {
+                Configuration::Split { ratio, .. } => ratio,
+                Configuration::Pane(_) => unreachable!(),
+            }
+        };
+
+        assert_eq!(ratio(0.3), 0.3);
+        assert_eq!(ratio(3.0), 1.0);
+        assert_eq!(ratio(-1.0), 0.0);
+        assert_eq!(ratio(f32::NAN), 0.5);
+    }
+}
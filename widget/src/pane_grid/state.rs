@@ -3,7 +3,8 @@
 //! [`PaneGrid`]: super::PaneGrid
 use crate::core::{Point, Size};
 use crate::pane_grid::{
-    Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target,
+    Axis, Configuration, Direction, Edge, Node, Pane, Region, Snapshot, Split,
+    Target,
 };
 
 use std::collections::HashMap;
@@ -64,6 +65,99 @@ impl<T> State<T> {
         }
     }
 
+    /// Creates a new [`State`] from a [`Snapshot`], producing the internal
+    /// state of every [`Pane`] from its key.
+    ///
+    /// The panes are arranged like [`State::with_configuration`] would, and
+    /// the maximized pane of the [`Snapshot`], if any, is maximized again.
+    ///
+    /// A [`Snapshot`] may come from anywhere, so any split ratio outside of
+    /// `[0.0, 1.0]` is clamped, and any ratio that is not a number splits
+    /// the space in half.
+    pub fn from_snapshot<K: PartialEq>(
+        snapshot: Snapshot<K>,
+        mut restore: impl FnMut(K) -> T,
+    ) -> Self {
+        fn validate<K>(configuration: Configuration<K>) -> Configuration<K> {
+            match configuration {
+                Configuration::Split { axis, ratio, a, b } => {
+                    Configuration::Split {
+                        axis,
+                        ratio: if ratio.is_nan() {
+                            0.5
+                        } else {
+                            ratio.clamp(0.0, 1.0)
+                        },
+                        a: Box::new(validate(*a)),
+                        b: Box::new(validate(*b)),
+                    }
+                }
+                Configuration::Pane(key) => Configuration::Pane(key),
+            }
+        }
+
+        let mut keys = HashMap::new();
+
+        let internal = Internal::from_configuration(
+            &mut keys,
+            validate(snapshot.configuration),
+            0,
+        );
+
+        let maximized = snapshot.maximized.and_then(|maximized| {
+            keys.iter()
+                .find(|(_, key)| **key == maximized)
+                .map(|(pane, _)| *pane)
+        });
+
+        let mut keys: Vec<_> = keys.into_iter().collect();
+        keys.sort_by_key(|(pane, _)| *pane);
+
+        State {
+            panes: keys
+                .into_iter()
+                .map(|(pane, key)| (pane, restore(key)))
+                .collect(),
+            internal,
+            maximized,
+        }
+    }
+
+    /// Returns a [`Snapshot`] of the layout of the [`State`], identifying
+    /// every [`Pane`] with the key produced by the given function.
+    pub fn snapshot<K>(&self, key: impl Fn(Pane, &T) -> K) -> Snapshot<K> {
+        fn configuration<T, K>(
+            node: &Node,
+            panes: &HashMap<Pane, T>,
+            key: &impl Fn(Pane, &T) -> K,
+        ) -> Configuration<K> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(configuration(a, panes, key)),
+                    b: Box::new(configuration(b, panes, key)),
+                },
+                Node::Pane(pane) => {
+                    Configuration::Pane(key(*pane, &panes[pane]))
+                }
+            }
+        }
+
+        Snapshot {
+            configuration: configuration(
+                &self.internal.layout,
+                &self.panes,
+                &key,
+            ),
+            maximized: self
+                .maximized
+                .and_then(|pane| Some(key(pane, self.panes.get(&pane)?))),
+        }
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -234,7 +234,8 @@ where
         let title_layout = self.content.as_widget().layout(
             &mut tree.children[0],
             renderer,
-            &layout::Limits::new(Size::ZERO, max_size),
+            &layout::Limits::new(Size::ZERO, max_size)
+                .with_direction(limits.direction()),
         );
 
         let title_size = title_layout.size();
@@ -243,7 +244,8 @@ where
             let controls_layout = controls.as_widget().layout(
                 &mut tree.children[1],
                 renderer,
-                &layout::Limits::new(Size::ZERO, max_size),
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
             );
 
             let controls_size = controls_layout.size();
@@ -2,13 +2,17 @@
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::theme::{self, Class};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -34,6 +38,7 @@ pub struct PickList<
     V: Borrow<T> + 'a,
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
@@ -70,6 +75,7 @@ where
         Theme: DefaultStyle,
     {
         Self {
+            id: None,
             on_select: Box::new(on_select),
             on_open: None,
             on_close: None,
@@ -87,6 +93,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -155,6 +167,65 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the style [`Class`] of the [`PickList`] and its [`Menu`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog + menu::Catalog,
+    {
+        let class = class.into();
+
+        self.style =
+            Style {
+                field: Box::new({
+                    let class = class.clone();
+
+                    move |theme: &Theme, status| {
+                        Catalog::resolve(theme, &class, status).unwrap_or_else(
+                        || (<Theme as DefaultStyle>::default_style().field)(
+                            theme, status,
+                        ),
+                    )
+                    }
+                }),
+                menu: menu::DefaultStyle::default_style().class(class),
+            };
+        self
+    }
+
+    fn open(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let selected = self.selected.as_ref().map(Borrow::borrow);
+
+        state.is_open = true;
+        state.hovered_option = self
+            .options
+            .borrow()
+            .iter()
+            .position(|option| Some(option) == selected);
+
+        if let Some(on_open) = &self.on_open {
+            shell.publish(on_open.clone());
+        }
+    }
+
+    fn close(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.is_open = false;
+
+        if let Some(on_close) = &self.on_close {
+            shell.publish(on_close.clone());
+        }
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -277,29 +348,16 @@ where
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+                state.is_focused = false;
+
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
-                    state.is_open = false;
-
-                    if let Some(on_close) = &self.on_close {
-                        shell.publish(on_close.clone());
-                    }
+                    self.close(state, shell);
 
                     event::Status::Captured
                 } else if cursor.is_over(layout.bounds()) {
-                    let selected = self.selected.as_ref().map(Borrow::borrow);
-
-                    state.is_open = true;
-                    state.hovered_option = self
-                        .options
-                        .borrow()
-                        .iter()
-                        .position(|option| Some(option) == selected);
-
-                    if let Some(on_open) = &self.on_open {
-                        shell.publish(on_open.clone());
-                    }
+                    self.open(state, shell);
 
                     event::Status::Captured
                 } else {
@@ -316,15 +374,6 @@ where
                     && cursor.is_over(layout.bounds())
                     && !state.is_open
                 {
-                    fn find_next<'a, T: PartialEq>(
-                        selected: &'a T,
-                        mut options: impl Iterator<Item = &'a T>,
-                    ) -> Option<&'a T> {
-                        let _ = options.find(|&option| option == selected);
-
-                        options.next()
-                    }
-
                     let options = self.options.borrow();
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
@@ -353,6 +402,82 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let options = self.options.borrow();
+
+                if state.is_open {
+                    let last = options.len().saturating_sub(1);
+
+                    match key {
+                        key::Named::ArrowDown => {
+                            state.hovered_option = Some(
+                                state
+                                    .hovered_option
+                                    .map_or(0, |index| (index + 1).min(last)),
+                            );
+                        }
+                        key::Named::ArrowUp => {
+                            state.hovered_option = Some(
+                                state.hovered_option.map_or(last, |index| {
+                                    index.saturating_sub(1)
+                                }),
+                            );
+                        }
+                        key::Named::Enter | key::Named::Space => {
+                            if let Some(option) = state
+                                .hovered_option
+                                .and_then(|index| options.get(index))
+                            {
+                                shell.publish((self.on_select)(option.clone()));
+                            }
+
+                            self.close(state, shell);
+                        }
+                        key::Named::Escape => {
+                            self.close(state, shell);
+                        }
+                        _ => return event::Status::Ignored,
+                    }
+
+                    event::Status::Captured
+                } else if state.is_focused {
+                    let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                    let next_option = match key {
+                        key::Named::Enter | key::Named::Space => {
+                            self.open(state, shell);
+
+                            return event::Status::Captured;
+                        }
+                        key::Named::ArrowDown => match selected {
+                            Some(selected) => {
+                                find_next(selected, options.iter())
+                            }
+                            None => options.first(),
+                        },
+                        key::Named::ArrowUp => match selected {
+                            Some(selected) => {
+                                find_next(selected, options.iter().rev())
+                            }
+                            None => options.last(),
+                        },
+                        _ => return event::Status::Ignored,
+                    };
+
+                    if let Some(next_option) = next_option {
+                        shell.publish((self.on_select)(next_option.clone()));
+                    }
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
@@ -365,6 +490,20 @@ where
         }
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
     fn mouse_interaction(
         &self,
         _tree: &Tree,
@@ -403,6 +542,8 @@ where
 
         let status = if state.is_open {
             Status::Opened
+        } else if state.is_focused {
+            Status::Focused
         } else if is_mouse_over {
             Status::Hovered
         } else {
@@ -575,16 +716,40 @@ where
     }
 }
 
+fn find_next<'a, T: PartialEq>(
+    selected: &'a T,
+    mut options: impl Iterator<Item = &'a T>,
+) -> Option<&'a T> {
+    let _ = options.find(|&option| option == selected);
+
+    options.next()
+}
+
 #[derive(Debug)]
 struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     options: Vec<P>,
     placeholder: P,
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<P: text::Paragraph> State<P> {
     /// Creates a new [`State`] for a [`PickList`].
     fn new() -> Self {
@@ -592,6 +757,7 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: P::default(),
@@ -658,6 +824,8 @@ pub enum Status {
     Hovered,
     /// The [`PickList`] is open.
     Opened,
+    /// The [`PickList`] has keyboard focus.
+    Focused,
 }
 
 /// The appearance of a pick list.
@@ -700,6 +868,24 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of the field of a [`PickList`] that can be referred to by
+/// [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
 /// The default style of the field of a [`PickList`].
 pub fn default(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
@@ -716,7 +902,7 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         },
     };
 
-    match status {
+    let appearance = match status {
         Status::Active => active,
         Status::Hovered | Status::Opened => Appearance {
             border: Border {
@@ -725,5 +911,41 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
             },
             ..active
         },
+        Status::Focused => Appearance {
+            border: Border {
+                color: palette.background.base.text,
+                width: 2.0,
+                ..active.border
+            },
+            ..active
+        },
+    };
+
+    theme.overridden(appearance, status)
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => theme::Status::Active,
+            Status::Hovered => theme::Status::Hovered,
+            Status::Opened => theme::Status::Pressed,
+            Status::Focused => theme::Status::Focused,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.pick_list.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            text_color: properties.text_color(self.text_color),
+            background: properties.background(self.background),
+            border: properties.border(self.border),
+            ..self
+        }
     }
 }
@@ -0,0 +1,239 @@
+//! Share the field, panel and style of the date, time and color pickers.
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::{
+    Background, Border, Color, Length, Padding, Point, Rectangle, Size, Theme,
+};
+use crate::menu::Metrics;
+
+/// The handle drawn at the end of the field of a picker.
+const HANDLE: &str = "▾";
+
+/// Lays out the field of a picker, fitting the widest of the given texts.
+pub(crate) fn layout_field<Renderer>(
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    metrics: &Metrics<Renderer::Font>,
+    texts: &[&str],
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let content = texts
+        .iter()
+        .map(|text| metrics.measure::<Renderer::Paragraph>(text))
+        .fold(0.0, f32::max);
+
+    let handle = metrics.measure::<Renderer::Paragraph>(HANDLE);
+
+    let intrinsic = Size::new(
+        content + handle + padding.horizontal() + padding.right,
+        metrics.line_height + padding.vertical(),
+    );
+
+    layout::Node::new(limits.resolve(width, Length::Shrink, intrinsic))
+}
+
+/// Draws the field of a picker with the given text.
+pub(crate) fn draw_field<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+    padding: Padding,
+    metrics: &Metrics<Renderer::Font>,
+    content: &str,
+    is_placeholder: bool,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: appearance.border,
+            ..renderer::Quad::default()
+        },
+        appearance.background,
+    );
+
+    metrics.draw(
+        renderer,
+        content,
+        Point::new(bounds.x + padding.left, bounds.center_y()),
+        alignment::Horizontal::Left,
+        if is_placeholder {
+            appearance.placeholder_color
+        } else {
+            appearance.text_color
+        },
+        bounds,
+    );
+
+    metrics.draw(
+        renderer,
+        HANDLE,
+        Point::new(bounds.x + bounds.width - padding.right, bounds.center_y()),
+        alignment::Horizontal::Right,
+        appearance.text_color,
+        bounds,
+    );
+}
+
+/// Draws the background of the panel of a picker.
+pub(crate) fn draw_panel<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: appearance.panel_border,
+            ..renderer::Quad::default()
+        },
+        appearance.panel_background,
+    );
+}
+
+/// Returns the position of a panel of the given size, placed below the
+/// field of its picker or above it if there is not enough space.
+pub(crate) fn panel_position(
+    field: Rectangle,
+    panel: Size,
+    bounds: Size,
+) -> Point {
+    let below = field.y + field.height;
+
+    let y = if below + panel.height > bounds.height
+        && field.y - panel.height >= 0.0
+    {
+        field.y - panel.height
+    } else {
+        below
+    };
+
+    let x = field.x.min(bounds.width - panel.width).max(0.0);
+
+    Point::new(x, y)
+}
+
+/// The possible status of the field of a picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The picker can be interacted with.
+    Active,
+    /// The field of the picker is being hovered.
+    Hovered,
+    /// The field of the picker has keyboard focus.
+    Focused,
+    /// The panel of the picker is open.
+    Opened,
+}
+
+/// The appearance of a picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the field.
+    pub background: Background,
+    /// The [`Border`] of the field.
+    pub border: Border,
+    /// The text [`Color`] of the field and the panel.
+    pub text_color: Color,
+    /// The text [`Color`] of the placeholder of the field.
+    pub placeholder_color: Color,
+    /// The [`Background`] of the panel.
+    pub panel_background: Background,
+    /// The [`Border`] of the panel.
+    pub panel_border: Border,
+    /// The text [`Color`] of a disabled entry of the panel.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered or focused entry of the panel.
+    pub hovered_background: Background,
+    /// The text [`Color`] of the selected entry of the panel.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the selected entry of the panel.
+    pub selected_background: Background,
+    /// The [`Color`] of the border of a field with invalid text.
+    pub invalid_color: Color,
+}
+
+/// The style of a picker.
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The default style of a picker.
+pub trait DefaultStyle {
+    /// Returns the default style of a picker.
+    fn default_style(&self, status: Status) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self, status: Status) -> Appearance {
+        default(self, status)
+    }
+}
+
+/// The styles of a picker that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self, _status: Status) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of a picker.
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let active = Appearance {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        text_color: palette.background.weak.text,
+        placeholder_color: palette.background.strong.color,
+        panel_background: palette.background.base.color.into(),
+        panel_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        disabled_text_color: palette.background.strong.color,
+        hovered_background: palette.background.weak.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        invalid_color: palette.danger.base.color,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Focused | Status::Opened => Appearance {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
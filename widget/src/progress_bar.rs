@@ -2,6 +2,7 @@
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::theme::Class;
 use crate::core::widget::Tree;
 use crate::core::{
     Background, Border, Element, Layout, Length, Rectangle, Size, Theme, Widget,
@@ -69,6 +70,24 @@ impl<'a, Theme> ProgressBar<'a, Theme> {
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`ProgressBar`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme| {
+            theme
+                .resolve(&class)
+                .unwrap_or_else(|| theme.default_style())
+        });
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -183,6 +202,38 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`ProgressBar`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `primary`, `secondary`, `success` and
+/// `danger` classes, on top of any [`Classes`] registered in it or in scope.
+///
+/// A [`ProgressBar`] has no status, so its [`Classes`] are registered with
+/// a `()` status; e.g. `|theme, ()| style(theme)`.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, ()) {
+            return Some(appearance);
+        }
+
+        let style: fn(&Theme) -> Appearance = match class.as_str() {
+            "primary" => primary,
+            "secondary" => secondary,
+            "success" => success,
+            "danger" => danger,
+            _ => return None,
+        };
+
+        Some(style(self))
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self) -> Appearance {
         *self
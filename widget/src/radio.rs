@@ -1,13 +1,19 @@
 //! Create choices using radio buttons.
 use crate::core::alignment;
+use crate::core::animation::{self, Interpolate, StatusTransition};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::theme::{self, Class};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
@@ -71,6 +77,7 @@ pub struct Radio<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     is_selected: bool,
     on_click: Message,
     label: String,
@@ -115,6 +122,7 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: None,
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: label.into(),
@@ -129,6 +137,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Radio`] button.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the size of the [`Radio`] button.
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = size.into().0;
@@ -182,6 +196,62 @@ where
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`Radio`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn status<P: text::Paragraph>(
+        &self,
+        state: &State<P>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_selected = self.is_selected;
+
+        if state.is_focused {
+            Status::Focused { is_selected }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_selected }
+        } else {
+            Status::Active { is_selected }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+    transition: StatusTransition<Status>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -191,11 +261,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -216,12 +286,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -238,9 +307,23 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -249,15 +332,30 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.status(state, layout, cursor);
+
+        state.transition.update(&event, status, shell);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if cursor.is_over(layout.bounds()) {
                     shell.publish(self.on_click.clone());
 
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -289,18 +387,16 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let is_mouse_over = cursor.is_over(layout.bounds());
-        let is_selected = self.is_selected;
-
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let mut children = layout.children();
 
-        let status = if is_mouse_over {
-            Status::Hovered { is_selected }
-        } else {
-            Status::Active { is_selected }
-        };
+        let status = self.status(state, layout, cursor);
 
-        let appearance = (self.style)(theme, status);
+        let appearance = state.transition.appearance(
+            status,
+            |status| (self.style)(theme, status),
+            |appearance| appearance.transition,
+        );
 
         {
             let layout = children.next().unwrap();
@@ -346,7 +442,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance {
                     color: appearance.text_color,
                 },
@@ -383,6 +479,11 @@ pub enum Status {
         /// Indicates whether the [`Radio`] button is currently selected.
         is_selected: bool,
     },
+    /// The [`Radio`] button has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Radio`] button is currently selected.
+        is_selected: bool,
+    },
 }
 
 /// The appearance of a radio button.
@@ -398,6 +499,26 @@ pub struct Appearance {
     pub border_color: Color,
     /// The text [`Color`] of the radio button.
     pub text_color: Option<Color>,
+    /// The [`Transition`] used to animate into this [`Appearance`], if any.
+    ///
+    /// [`Transition`]: animation::Transition
+    pub transition: Option<animation::Transition>,
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            dot_color: self.dot_color.interpolate(&other.dot_color, t),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, t)
+                .max(0.0),
+            border_color: self.border_color.interpolate(&other.border_color, t),
+            text_color: self.text_color.interpolate(&other.text_color, t),
+            transition: other.transition,
+        }
+    }
 }
 
 /// The style of a [`Radio`] button.
@@ -415,6 +536,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Radio`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -431,14 +576,50 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         border_width: 1.0,
         border_color: palette.primary.strong.color,
         text_color: None,
+        transition: None,
     };
 
-    match status {
+    let hovered = Appearance {
+        dot_color: palette.primary.strong.color,
+        background: palette.primary.weak.color.into(),
+        ..active
+    };
+
+    let appearance = match status {
         Status::Active { .. } => active,
-        Status::Hovered { .. } => Appearance {
-            dot_color: palette.primary.strong.color,
-            background: palette.primary.weak.color.into(),
-            ..active
+        Status::Hovered { .. } => hovered,
+        Status::Focused { .. } => Appearance {
+            border_width: 2.0,
+            border_color: palette.background.base.text,
+            ..hovered
         },
+    };
+
+    theme.overridden(appearance, status)
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => theme::Status::Active,
+            Status::Hovered { .. } => theme::Status::Hovered,
+            Status::Focused { .. } => theme::Status::Focused,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.radio.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            background: properties.background(self.background),
+            border_width: properties.border_width.unwrap_or(self.border_width),
+            border_color: properties.border_color.unwrap_or(self.border_color),
+            text_color: properties.text_color.or(self.text_color),
+            ..self
+        }
     }
 }
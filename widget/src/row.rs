@@ -1,4 +1,5 @@
 //! Distribute content horizontally.
+use crate::core::alignment::Justify;
 use crate::core::event::{self, Event};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -134,6 +135,18 @@ where
     ) -> Self {
         children.into_iter().fold(self, Self::push)
     }
+
+    /// Turns the [`Row`] into a [`Wrapping`] row.
+    ///
+    /// The contents of a [`Wrapping`] row will be moved to a new line
+    /// when they run out of horizontal space.
+    pub fn wrap(self) -> Wrapping<'a, Message, Theme, Renderer> {
+        Wrapping {
+            row: self,
+            line_spacing: None,
+            justify: Justify::Start,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer>
@@ -318,3 +331,164 @@ where
         Self::new(row)
     }
 }
+
+/// A [`Row`] that moves its contents to a new line when they run out of
+/// horizontal space.
+///
+/// The items of every line are aligned vertically with the
+/// [`Row::align_items`] of the original [`Row`]. Unless its height is
+/// fixed, a [`Wrapping`] row is as tall as its lines, which makes it
+/// a good fit for a vertical [`Scrollable`](crate::Scrollable).
+#[allow(missing_debug_implementations)]
+pub struct Wrapping<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    row: Row<'a, Message, Theme, Renderer>,
+    line_spacing: Option<f32>,
+    justify: Justify,
+}
+
+impl<'a, Message, Theme, Renderer> Wrapping<'a, Message, Theme, Renderer> {
+    /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the [`Row::spacing`].
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets the [`Justify`] strategy used to distribute the free space of
+    /// each line.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    fn height(&self) -> Length {
+        // Lines are stacked vertically as needed, so the height of a wrapping
+        // row is determined by its contents unless it is fixed.
+        match self.row.height {
+            Length::Fixed(_) => self.row.height,
+            _ => Length::Shrink,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.row.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.row.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.row.width,
+            height: self.height(),
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::wrap::resolve(
+            renderer,
+            limits,
+            self.row.width,
+            self.height(),
+            self.row.padding,
+            self.row.spacing,
+            self.line_spacing.unwrap_or(self.row.spacing),
+            self.row.align_items,
+            self.justify,
+            &self.row.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.row.operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.row.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.row
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.row
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.row.overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wrapping<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(row: Wrapping<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(row)
+    }
+}
@@ -3,6 +3,7 @@ use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::theme::Class;
 use crate::core::widget::Tree;
 use crate::core::{
     Color, Element, Layout, Length, Pixels, Rectangle, Size, Theme, Widget,
@@ -49,6 +50,24 @@ impl<'a, Theme> Rule<'a, Theme> {
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`Rule`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme| {
+            theme
+                .resolve(&class)
+                .unwrap_or_else(|| theme.default_style())
+        });
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -231,6 +250,26 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Rule`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// A [`Rule`] has no status, so its [`Classes`] are registered with
+/// a `()` status; e.g. `|theme, ()| style(theme)`.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class) -> Option<Appearance> {
+        self.resolve_class(class, ())
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self) -> Appearance {
         *self
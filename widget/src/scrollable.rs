@@ -1,23 +1,29 @@
 //! Navigate an endless amount of content with a scrollbar.
 // use crate::container;
 use crate::container;
+use crate::core::animation::Transition;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::theme::Class;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::runtime::Command;
 
-pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
+use std::cell::Cell;
+
+pub use operation::scrollable::{AbsoluteOffset, Placement, RelativeOffset};
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -36,6 +42,9 @@ pub struct Scrollable<
     direction: Direction,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    smooth_scrolling: Option<Transition>,
+    friction: Option<f32>,
+    overscroll: bool,
     style: Style<'a, Theme>,
 }
 
@@ -91,6 +100,9 @@ where
             direction,
             content,
             on_scroll: None,
+            smooth_scrolling: None,
+            friction: None,
+            overscroll: false,
             style: Box::new(style),
         }
     }
@@ -121,6 +133,30 @@ where
         self
     }
 
+    /// Animates the scrolling of the mouse wheel with the given
+    /// [`Transition`], instead of jumping by whole lines.
+    pub fn smooth_scrolling(mut self, transition: Transition) -> Self {
+        self.smooth_scrolling = Some(transition);
+        self
+    }
+
+    /// Enables kinetic scrolling with the given friction.
+    ///
+    /// The contents keep moving after a touch or touchpad swipe is released,
+    /// and their velocity decays exponentially at the given rate per second.
+    /// A friction of about `4.0` feels natural; higher values stop sooner.
+    pub fn kinetic(mut self, friction: f32) -> Self {
+        self.friction = Some(friction.max(0.0));
+        self
+    }
+
+    /// Sets whether the contents of the [`Scrollable`] can be dragged past
+    /// their edges by touch or touchpad, bouncing back when released.
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     pub fn style(
         mut self,
@@ -129,6 +165,24 @@ where
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`Scrollable`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
 }
 
 /// The direction of [`Scrollable`].
@@ -268,29 +322,103 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::contained(limits, self.width, self.height, |limits| {
-            let child_limits = layout::Limits::new(
-                Size::new(limits.min().width, limits.min().height),
-                Size::new(
-                    if self.direction.horizontal().is_some() {
-                        f32::INFINITY
-                    } else {
-                        limits.max().width
-                    },
-                    if self.direction.vertical().is_some() {
-                        f32::MAX
-                    } else {
-                        limits.max().height
-                    },
-                ),
+        let direction = limits.direction();
+
+        tree.state.downcast_mut::<State>().layout_direction = direction;
+
+        // The contents are not mirrored, so that scrolling offsets always
+        // start at the left edge
+        let (node, contents) = Contents::track(|| {
+            layout::contained(
+                &limits.with_direction(layout::Direction::LeftToRight),
+                self.width,
+                self.height,
+                |limits| {
+                    let child_limits = layout::Limits::new(
+                        Size::new(limits.min().width, limits.min().height),
+                        Size::new(
+                            if self.direction.horizontal().is_some() {
+                                f32::INFINITY
+                            } else {
+                                limits.max().width
+                            },
+                            if self.direction.vertical().is_some() {
+                                f32::MAX
+                            } else {
+                                limits.max().height
+                            },
+                        ),
+                    )
+                    .with_direction(direction);
+
+                    self.content.as_widget().layout(
+                        &mut tree.children[0],
+                        renderer,
+                        &child_limits,
+                    )
+                },
+            )
+        });
+
+        let content = &node.children()[0];
+
+        // Compensate any shift of the contents above the viewport, so
+        // that they stay in place
+        if contents.anchors {
+            let mut anchors = Anchors::default();
+
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                Layout::new(content),
+                renderer,
+                &mut anchors,
             );
 
-            self.content.as_widget().layout(
+            if anchors.shift != 0.0
+                && self.direction.vertical().is_some_and(|vertical| {
+                    vertical.alignment == Alignment::Start
+                })
+            {
+                tree.state.downcast_mut::<State>().scroll(
+                    Vector::new(0.0, -anchors.shift),
+                    self.direction,
+                    Rectangle::with_size(node.size()),
+                    content.bounds(),
+                );
+            }
+        }
+
+        // Compute how far the sticky contents can be pinned, now that their
+        // positions are known
+        let mut pinned = Vec::new();
+
+        if contents.pins {
+            let mut pins = Pins::new(content.bounds());
+
+            self.content.as_widget().operate(
                 &mut tree.children[0],
+                Layout::new(content),
                 renderer,
-                &child_limits,
-            )
-        })
+                &mut pins,
+            );
+
+            if !pins.pins.is_empty() {
+                pins.reach();
+
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    Layout::new(content),
+                    renderer,
+                    &mut pins,
+                );
+
+                pinned = pins.pinned;
+            }
+        }
+
+        tree.state.downcast_mut::<State>().pins = pinned;
+
+        node
     }
 
     fn operate(
@@ -308,6 +436,8 @@ where
         let translation =
             state.translation(self.direction, bounds, content_bounds);
 
+        state.viewport = Some((self.direction, bounds, content_bounds));
+
         operation.scrollable(
             state,
             self.id.as_ref().map(|id| &id.0),
@@ -354,25 +484,22 @@ where
             scrollbars.is_mouse_over(cursor);
 
         let mut event_status = {
+            let translation =
+                state.translation(self.direction, bounds, content_bounds);
+
             let cursor = match cursor_over_scrollable {
                 Some(cursor_position)
                     if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
                 {
-                    mouse::Cursor::Available(
-                        cursor_position
-                            + state.translation(
-                                self.direction,
-                                bounds,
-                                content_bounds,
-                            ),
+                    state.unpin(
+                        cursor_position + translation,
+                        bounds,
+                        translation,
                     )
                 }
                 _ => mouse::Cursor::Unavailable,
             };
 
-            let translation =
-                state.translation(self.direction, bounds, content_bounds);
-
             self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
                 event.clone(),
@@ -397,7 +524,14 @@ where
                         | touch::Event::FingerLost { .. }
                 )
         ) {
-            state.scroll_area_touched_at = None;
+            if state.scroll_area_touched_at.take().is_some() {
+                state.release(self.friction, Instant::now());
+
+                if state.is_moving() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+
             state.x_scroller_grabbed_at = None;
             state.y_scroller_grabbed_at = None;
 
@@ -422,21 +556,64 @@ where
                     return event::Status::Ignored;
                 }
 
-                let delta = match delta {
+                state.momentum = None;
+
+                match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
-                        // TODO: Configurable speed/friction (?)
+                        // TODO: Configurable speed (?)
                         let movement = if state.keyboard_modifiers.shift() {
-                            Vector::new(y, x)
+                            Vector::new(y, x) * 60.0
                         } else {
-                            Vector::new(x, y)
+                            Vector::new(x, y) * 60.0
                         };
 
-                        movement * 60.0
+                        if let Some(transition) = self.smooth_scrolling {
+                            state.animate_by(
+                                movement,
+                                transition,
+                                self.direction,
+                                bounds,
+                                content_bounds,
+                            );
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        } else {
+                            state.animation = None;
+                            state.scroll(
+                                movement,
+                                self.direction,
+                                bounds,
+                                content_bounds,
+                            );
+                        }
                     }
-                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
-                };
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        let delta = Vector::new(x, y);
+
+                        state.animation = None;
+
+                        // Touchpads do not report when the fingers are
+                        // lifted; the gesture ends when the deltas stop
+                        if self.friction.is_some() || self.overscroll {
+                            state.is_wheel_gesture = true;
+                            state.track(delta, Instant::now());
 
-                state.scroll(delta, self.direction, bounds, content_bounds);
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+
+                        state.drag(
+                            delta,
+                            self.direction,
+                            bounds,
+                            content_bounds,
+                            self.overscroll,
+                        );
+                    }
+                }
 
                 notify_on_scroll(
                     state,
@@ -458,7 +635,7 @@ where
                             return event::Status::Ignored;
                         };
 
-                        state.scroll_area_touched_at = Some(cursor_position);
+                        state.touch(cursor_position, Instant::now());
                     }
                     touch::Event::FingerMoved { .. } => {
                         if let Some(scroll_box_touched_at) =
@@ -474,11 +651,13 @@ where
                                 cursor_position.y - scroll_box_touched_at.y,
                             );
 
-                            state.scroll(
+                            state.track(delta, Instant::now());
+                            state.drag(
                                 delta,
                                 self.direction,
                                 bounds,
                                 content_bounds,
+                                self.overscroll,
                             );
 
                             state.scroll_area_touched_at =
@@ -498,6 +677,30 @@ where
 
                 event_status = event::Status::Captured;
             }
+            Event::Window(_, window::Event::RedrawRequested(now))
+                if state.is_moving() =>
+            {
+                state.tick(
+                    now,
+                    self.direction,
+                    bounds,
+                    content_bounds,
+                    self.friction,
+                    self.overscroll,
+                );
+
+                notify_on_scroll(
+                    state,
+                    &self.on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                if state.is_moving() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
             _ => {}
         }
 
@@ -676,7 +879,7 @@ where
             Some(cursor_position)
                 if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
             {
-                mouse::Cursor::Available(cursor_position + translation)
+                state.unpin(cursor_position + translation, bounds, translation)
             }
             _ => mouse::Cursor::Unavailable,
         };
@@ -866,7 +1069,11 @@ where
                 Some(cursor_position)
                     if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
                 {
-                    mouse::Cursor::Available(cursor_position + translation)
+                    state.unpin(
+                        cursor_position + translation,
+                        bounds,
+                        translation,
+                    )
                 }
                 _ => mouse::Cursor::Unavailable,
             };
@@ -902,12 +1109,20 @@ where
             content_bounds,
         );
 
-        self.content.as_widget_mut().overlay(
-            &mut tree.children[0],
-            layout.children().next().unwrap(),
-            renderer,
-            translation - offset,
-        )
+        let viewport = Rectangle {
+            x: bounds.x + offset.x,
+            y: bounds.y + offset.y,
+            ..bounds
+        };
+
+        Pin::within(viewport, || {
+            self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                translation - offset,
+            )
+        })
     }
 }
 
@@ -968,6 +1183,47 @@ pub fn scroll_to<Message: 'static>(
     Command::widget(operation::scrollable::scroll_to(id.0, offset))
 }
 
+/// Produces a [`Command`] that animates the [`Scrollable`] with the given
+/// [`Id`] towards the provided `percentage` with the given [`Transition`].
+pub fn snap_to_animated<Message: 'static>(
+    id: Id,
+    offset: RelativeOffset,
+    transition: Transition,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to_animated(
+        id.0, offset, transition,
+    ))
+}
+
+/// Produces a [`Command`] that animates the [`Scrollable`] with the given
+/// [`Id`] towards the provided [`AbsoluteOffset`] with the given
+/// [`Transition`].
+pub fn scroll_to_animated<Message: 'static>(
+    id: Id,
+    offset: AbsoluteOffset,
+    transition: Transition,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to_animated(
+        id.0, offset, transition,
+    ))
+}
+
+/// Produces a [`Command`] that scrolls every [`Scrollable`] enclosing the
+/// widget with the given [`widget::Id`] so it becomes visible with the given
+/// [`Placement`].
+///
+/// The widget does not need to be a direct child of a [`Scrollable`]; nested
+/// scrollables are scrolled from the innermost to the outermost.
+pub fn scroll_into_view<Message: 'static>(
+    id: impl Into<widget::Id>,
+    placement: Placement,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_into_view(
+        id.into(),
+        placement,
+    ))
+}
+
 fn notify_on_scroll<Message>(
     state: &mut State,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
@@ -1015,7 +1271,7 @@ fn notify_on_scroll<Message>(
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 struct State {
     scroll_area_touched_at: Option<Point>,
     offset_y: Offset,
@@ -1024,6 +1280,16 @@ struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    layout_direction: layout::Direction,
+    viewport: Option<(Direction, Rectangle, Rectangle)>,
+    animation: Option<Animation>,
+    momentum: Option<Vector>,
+    velocity: Vector,
+    stretch: Vector,
+    last_moved_at: Option<Instant>,
+    last_frame_at: Option<Instant>,
+    is_wheel_gesture: bool,
+    pins: Vec<Pin>,
 }
 
 impl Default for State {
@@ -1036,6 +1302,16 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            layout_direction: layout::Direction::default(),
+            viewport: None,
+            animation: None,
+            momentum: None,
+            velocity: Vector::ZERO,
+            stretch: Vector::ZERO,
+            last_moved_at: None,
+            last_frame_at: None,
+            is_wheel_gesture: false,
+            pins: Vec::new(),
         }
     }
 }
@@ -1048,110 +1324,472 @@ impl operation::Scrollable for State {
     fn scroll_to(&mut self, offset: AbsoluteOffset) {
         State::scroll_to(self, offset);
     }
+
+    fn snap_to_animated(
+        &mut self,
+        offset: RelativeOffset,
+        transition: Transition,
+    ) {
+        self.animate_to(
+            Offset::Relative(offset.x.clamp(0.0, 1.0)),
+            Offset::Relative(offset.y.clamp(0.0, 1.0)),
+            transition,
+        );
+    }
+
+    fn scroll_to_animated(
+        &mut self,
+        offset: AbsoluteOffset,
+        transition: Transition,
+    ) {
+        self.animate_to(
+            Offset::Absolute(offset.x.max(0.0)),
+            Offset::Absolute(offset.y.max(0.0)),
+            transition,
+        );
+    }
+
+    fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        placement: Placement,
+    ) -> Option<Rectangle> {
+        let (direction, bounds, content_bounds) = self.viewport?;
+
+        Some(State::scroll_into_view(
+            self,
+            target,
+            placement,
+            direction,
+            bounds,
+            content_bounds,
+        ))
+    }
 }
 
+/// An animation of the offsets of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
-enum Offset {
-    Absolute(f32),
-    Relative(f32),
+struct Animation {
+    source: (Offset, Offset),
+    target: (Offset, Offset),
+    started_at: Instant,
+    transition: Transition,
 }
 
-impl Offset {
-    fn absolute(self, viewport: f32, content: f32) -> f32 {
-        match self {
-            Offset::Absolute(absolute) => {
-                absolute.min((content - viewport).max(0.0))
-            }
-            Offset::Relative(percentage) => {
-                ((content - viewport) * percentage).max(0.0)
-            }
-        }
-    }
+/// The minimum speed, in pixels per second, of kinetic scrolling.
+const MIN_VELOCITY: f32 = 30.0;
 
-    fn translation(
-        self,
-        viewport: f32,
-        content: f32,
-        alignment: Alignment,
-    ) -> f32 {
-        let offset = self.absolute(viewport, content);
+/// The maximum speed, in pixels per second, of kinetic scrolling.
+const MAX_VELOCITY: f32 = 8_000.0;
 
-        match alignment {
-            Alignment::Start => offset,
-            Alignment::End => ((content - viewport).max(0.0) - offset).max(0.0),
-        }
+/// The rate at which the velocity decays while overscrolling.
+const OVERSCROLL_FRICTION: f32 = 25.0;
+
+/// The rate at which overscrolled contents settle back into place.
+const OVERSCROLL_SPRING: f32 = 12.0;
+
+/// Returns the visible distance of contents stretched past an edge by the
+/// given amount, which diminishes as they are stretched further.
+fn rubber_band(stretch: f32, dimension: f32) -> f32 {
+    const RESISTANCE: f32 = 0.55;
+
+    if dimension <= 0.0 {
+        return 0.0;
     }
-}
 
-/// The current [`Viewport`] of the [`Scrollable`].
-#[derive(Debug, Clone, Copy)]
-pub struct Viewport {
-    offset_x: Offset,
-    offset_y: Offset,
-    bounds: Rectangle,
-    content_bounds: Rectangle,
+    let distance = (1.0 - 1.0 / (stretch.abs() * RESISTANCE / dimension + 1.0))
+        * dimension;
+
+    distance.copysign(stretch)
 }
 
-impl Viewport {
-    /// Returns the [`AbsoluteOffset`] of the current [`Viewport`].
-    pub fn absolute_offset(&self) -> AbsoluteOffset {
-        let x = self
-            .offset_x
-            .absolute(self.bounds.width, self.content_bounds.width);
-        let y = self
-            .offset_y
-            .absolute(self.bounds.height, self.content_bounds.height);
+/// The vertical shift of some contents of a [`Scrollable`] that should be
+/// compensated by scrolling, in order to keep them in place.
+///
+/// A widget can expose it as custom state while operating, and the
+/// [`Scrollable`] containing it will take it after every layout.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Anchor {
+    pub(crate) shift: f32,
+}
 
-        AbsoluteOffset { x, y }
+impl Anchor {
+    /// Lets the [`Scrollable`] being laid out know that some of its
+    /// contents have an [`Anchor`] with a shift to take.
+    pub(crate) fn register() {
+        CONTENTS.with(|contents| {
+            contents.set(Contents {
+                anchors: true,
+                ..contents.get()
+            });
+        });
     }
+}
 
-    /// Returns the [`AbsoluteOffset`] of the current [`Viewport`], but with its
-    /// alignment reversed.
+thread_local! {
+    /// The [`Contents`] registered during the layout of the innermost
+    /// [`Scrollable`].
+    static CONTENTS: Cell<Contents> = const {
+        Cell::new(Contents {
+            anchors: false,
+            pins: false,
+        })
+    };
+
+    /// The viewport of the innermost [`Scrollable`] creating the overlays
+    /// of its contents.
+    static VIEWPORT: Cell<Option<Rectangle>> = const { Cell::new(None) };
+}
+
+/// The kinds of contents of a [`Scrollable`] that need to be operated on
+/// after its layout.
+///
+/// Widgets register them while being laid out, so the [`Scrollable`] can
+/// skip the operations when none of its contents need them.
+#[derive(Debug, Clone, Copy, Default)]
+struct Contents {
+    anchors: bool,
+    pins: bool,
+}
+
+impl Contents {
+    /// Runs the given layout, returning the [`Contents`] registered during
+    /// it.
     ///
-    /// This method can be useful to switch the alignment of a [`Scrollable`]
-    /// while maintaining its scrolling position.
-    pub fn absolute_offset_reversed(&self) -> AbsoluteOffset {
-        let AbsoluteOffset { x, y } = self.absolute_offset();
+    /// The contents of a nested [`Scrollable`] are only registered to it.
+    fn track<T>(layout: impl FnOnce() -> T) -> (T, Self) {
+        let outer = CONTENTS.with(|contents| contents.replace(Self::default()));
+        let result = layout();
+        let contents = CONTENTS.with(|contents| contents.replace(outer));
 
-        AbsoluteOffset {
-            x: (self.content_bounds.width - self.bounds.width).max(0.0) - x,
-            y: (self.content_bounds.height - self.bounds.height).max(0.0) - y,
-        }
+        (result, contents)
     }
+}
 
-    /// Returns the [`RelativeOffset`] of the current [`Viewport`].
-    pub fn relative_offset(&self) -> RelativeOffset {
-        let AbsoluteOffset { x, y } = self.absolute_offset();
+/// Collects the [`Anchor`] shifts of the contents of a [`Scrollable`],
+/// excluding the ones of any nested [`Scrollable`].
+#[derive(Debug, Default)]
+struct Anchors {
+    shift: f32,
+    is_nested: bool,
+}
 
-        let x = x / (self.content_bounds.width - self.bounds.width);
-        let y = y / (self.content_bounds.height - self.bounds.height);
+impl<T> Operation<T> for Anchors {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        // A nested scrollable operates on itself right before its contents
+        if std::mem::take(&mut self.is_nested) {
+            return;
+        }
 
-        RelativeOffset { x, y }
+        operate_on_children(self);
     }
 
-    /// Returns the bounds of the current [`Viewport`].
-    pub fn bounds(&self) -> Rectangle {
-        self.bounds
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.is_nested = true;
     }
 
-    /// Returns the content bounds of the current [`Viewport`].
-    pub fn content_bounds(&self) -> Rectangle {
-        self.content_bounds
+    fn custom(
+        &mut self,
+        state: &mut dyn std::any::Any,
+        _id: Option<&widget::Id>,
+    ) {
+        if let Some(anchor) = state.downcast_mut::<Anchor>() {
+            self.shift += std::mem::take(&mut anchor.shift);
+        }
     }
 }
 
-impl State {
-    /// Creates a new [`State`] with the scrollbar(s) at the beginning.
-    pub fn new() -> Self {
-        State::default()
+/// The pinning of some sticky contents of a [`Scrollable`].
+///
+/// A widget can expose it as custom state while operating, and the
+/// [`Scrollable`] containing it will fill in its reach after every layout.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Pin {
+    /// The bounds of the sticky contents during the last layout.
+    pub(crate) bounds: Rectangle,
+    /// How far the sticky contents can move along each axis, or `None` if
+    /// they are not inside of a [`Scrollable`].
+    pub(crate) reach: Option<Vector>,
+}
+
+impl Pin {
+    /// Lets the [`Scrollable`] being laid out know that some of its
+    /// contents have a [`Pin`] to fill in.
+    pub(crate) fn register() {
+        CONTENTS.with(|contents| {
+            contents.set(Contents {
+                pins: true,
+                ..contents.get()
+            });
+        });
     }
 
-    /// Apply a scrolling offset to the current [`State`], given the bounds of
-    /// the [`Scrollable`] and its contents.
-    pub fn scroll(
-        &mut self,
-        delta: Vector<f32>,
-        direction: Direction,
+    /// Returns the offset that keeps the sticky contents with the given
+    /// bounds inside of the visible viewport, as far as they can reach.
+    pub(crate) fn offset(
+        &self,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Vector {
+        let Some(reach) = self.reach else {
+            return Vector::ZERO;
+        };
+
+        Vector::new(
+            (viewport.x - bounds.x).min(reach.x).max(0.0),
+            (viewport.y - bounds.y).min(reach.y).max(0.0),
+        )
+    }
+
+    /// Returns the offset of the sticky contents with the given bounds
+    /// while the [`Scrollable`] containing them creates their overlays.
+    pub(crate) fn overlay_offset(&self, bounds: Rectangle) -> Vector {
+        VIEWPORT
+            .with(Cell::get)
+            .map_or(Vector::ZERO, |viewport| self.offset(bounds, &viewport))
+    }
+
+    /// Creates some overlays with the given viewport of a [`Scrollable`],
+    /// so the sticky contents can find their [`Pin::overlay_offset`].
+    fn within<T>(viewport: Rectangle, overlay: impl FnOnce() -> T) -> T {
+        let outer = VIEWPORT.with(|cell| cell.replace(Some(viewport)));
+        let result = overlay();
+        VIEWPORT.with(|cell| cell.set(outer));
+
+        result
+    }
+}
+
+/// Collects the [`Pin`] bounds of the contents of a [`Scrollable`] and then
+/// fills in their reach, excluding the ones of any nested [`Scrollable`].
+///
+/// A [`Pin`] can move up to the end of its parent, or up to the next [`Pin`]
+/// in its way.
+#[derive(Debug)]
+struct Pins {
+    pins: Vec<(Rectangle, Rectangle)>,
+    parents: Vec<Rectangle>,
+    reaches: Option<std::vec::IntoIter<Vector>>,
+    pinned: Vec<Pin>,
+    is_nested: bool,
+}
+
+impl Pins {
+    fn new(content_bounds: Rectangle) -> Self {
+        Self {
+            pins: Vec::new(),
+            parents: vec![content_bounds],
+            reaches: None,
+            pinned: Vec::new(),
+            is_nested: false,
+        }
+    }
+
+    /// Computes the reach of the collected pins, so they are filled in the
+    /// next time the [`Pins`] operate.
+    fn reach(&mut self) {
+        let reaches = self
+            .pins
+            .iter()
+            .enumerate()
+            .map(|(i, (bounds, parent))| {
+                let mut end = Point::new(
+                    parent.x + parent.width,
+                    parent.y + parent.height,
+                );
+
+                for (next, _) in &self.pins[i + 1..] {
+                    let overlaps_x = next.x < bounds.x + bounds.width
+                        && bounds.x < next.x + next.width;
+                    let overlaps_y = next.y < bounds.y + bounds.height
+                        && bounds.y < next.y + next.height;
+
+                    if next.y > bounds.y && overlaps_x {
+                        end.y = end.y.min(next.y);
+                    }
+
+                    if next.x > bounds.x && overlaps_y {
+                        end.x = end.x.min(next.x);
+                    }
+                }
+
+                Vector::new(
+                    (end.x - bounds.x - bounds.width).max(0.0),
+                    (end.y - bounds.y - bounds.height).max(0.0),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.reaches = Some(reaches.into_iter());
+    }
+}
+
+impl<T> Operation<T> for Pins {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        // A nested scrollable operates on itself right before its contents
+        if std::mem::take(&mut self.is_nested) {
+            return;
+        }
+
+        self.parents.push(bounds);
+
+        operate_on_children(self);
+
+        let _ = self.parents.pop();
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.is_nested = true;
+    }
+
+    fn custom(
+        &mut self,
+        state: &mut dyn std::any::Any,
+        _id: Option<&widget::Id>,
+    ) {
+        let Some(pin) = state.downcast_mut::<Pin>() else {
+            return;
+        };
+
+        match &mut self.reaches {
+            Some(reaches) => {
+                pin.reach = reaches.next();
+
+                self.pinned.push(*pin);
+            }
+            None => {
+                let parent = self.parents.last().copied().unwrap_or(pin.bounds);
+
+                self.pins.push((pin.bounds, parent));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Offset {
+    Absolute(f32),
+    Relative(f32),
+}
+
+impl Offset {
+    fn absolute(self, viewport: f32, content: f32) -> f32 {
+        match self {
+            Offset::Absolute(absolute) => {
+                absolute.min((content - viewport).max(0.0))
+            }
+            Offset::Relative(percentage) => {
+                ((content - viewport) * percentage).max(0.0)
+            }
+        }
+    }
+
+    fn translation(
+        self,
+        viewport: f32,
+        content: f32,
+        alignment: Alignment,
+    ) -> f32 {
+        let offset = self.absolute(viewport, content);
+
+        match alignment {
+            Alignment::Start => offset,
+            Alignment::End => ((content - viewport).max(0.0) - offset).max(0.0),
+        }
+    }
+}
+
+/// The current [`Viewport`] of the [`Scrollable`].
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport {
+    offset_x: Offset,
+    offset_y: Offset,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Returns the [`AbsoluteOffset`] of the current [`Viewport`].
+    pub fn absolute_offset(&self) -> AbsoluteOffset {
+        let x = self
+            .offset_x
+            .absolute(self.bounds.width, self.content_bounds.width);
+        let y = self
+            .offset_y
+            .absolute(self.bounds.height, self.content_bounds.height);
+
+        AbsoluteOffset { x, y }
+    }
+
+    /// Returns the [`AbsoluteOffset`] of the current [`Viewport`], but with its
+    /// alignment reversed.
+    ///
+    /// This method can be useful to switch the alignment of a [`Scrollable`]
+    /// while maintaining its scrolling position.
+    pub fn absolute_offset_reversed(&self) -> AbsoluteOffset {
+        let AbsoluteOffset { x, y } = self.absolute_offset();
+
+        AbsoluteOffset {
+            x: (self.content_bounds.width - self.bounds.width).max(0.0) - x,
+            y: (self.content_bounds.height - self.bounds.height).max(0.0) - y,
+        }
+    }
+
+    /// Returns the [`RelativeOffset`] of the current [`Viewport`].
+    pub fn relative_offset(&self) -> RelativeOffset {
+        let AbsoluteOffset { x, y } = self.absolute_offset();
+
+        let x = x / (self.content_bounds.width - self.bounds.width);
+        let y = y / (self.content_bounds.height - self.bounds.height);
+
+        RelativeOffset { x, y }
+    }
+
+    /// Returns the bounds of the current [`Viewport`].
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the content bounds of the current [`Viewport`].
+    pub fn content_bounds(&self) -> Rectangle {
+        self.content_bounds
+    }
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar(s) at the beginning.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Apply a scrolling offset to the current [`State`], given the bounds of
+    /// the [`Scrollable`] and its contents.
+    pub fn scroll(
+        &mut self,
+        delta: Vector<f32>,
+        direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
@@ -1202,6 +1840,7 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
+        self.stop();
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
@@ -1216,22 +1855,416 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
+        self.stop();
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
 
     /// Snaps the scroll position to a [`RelativeOffset`].
     pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.stop();
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
 
     /// Scroll to the provided [`AbsoluteOffset`].
     pub fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.stop();
         self.offset_x = Offset::Absolute(offset.x.max(0.0));
         self.offset_y = Offset::Absolute(offset.y.max(0.0));
     }
 
+    /// Scrolls the given `target`, in the coordinates of the contents, into
+    /// view with the given [`Placement`].
+    ///
+    /// It returns the visible part of the `target` afterwards, in the
+    /// coordinates of the [`Scrollable`].
+    fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        placement: Placement,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Rectangle {
+        let current =
+            self.clamped_translation(direction, bounds, content_bounds);
+
+        let reveal = |current: f32,
+                      start: f32,
+                      length: f32,
+                      viewport_start: f32,
+                      viewport_length: f32,
+                      content_length: f32| {
+            let to_start = start - viewport_start;
+            let to_end = to_start + length - viewport_length;
+
+            let translation = match placement {
+                Placement::Start => to_start,
+                Placement::Center => (to_start + to_end) / 2.0,
+                Placement::End => to_end,
+                Placement::Nearest => {
+                    let (min, max) = if to_start < to_end {
+                        (to_start, to_end)
+                    } else {
+                        (to_end, to_start)
+                    };
+
+                    current.clamp(min, max)
+                }
+            };
+
+            translation.clamp(0.0, (content_length - viewport_length).max(0.0))
+        };
+
+        let offset = |translation: f32,
+                      viewport_length: f32,
+                      content_length: f32,
+                      alignment: Alignment| {
+            Offset::Absolute(match alignment {
+                Alignment::Start => translation,
+                Alignment::End => {
+                    (content_length - viewport_length).max(0.0) - translation
+                }
+            })
+        };
+
+        self.stop();
+        self.stretch = Vector::ZERO;
+
+        let mut translation = Vector::ZERO;
+
+        if let Some(horizontal) = direction.horizontal() {
+            translation.x = reveal(
+                current.x,
+                target.x,
+                target.width,
+                bounds.x,
+                bounds.width,
+                content_bounds.width,
+            );
+
+            self.offset_x = offset(
+                translation.x,
+                bounds.width,
+                content_bounds.width,
+                horizontal.alignment,
+            );
+        }
+
+        if let Some(vertical) = direction.vertical() {
+            translation.y = reveal(
+                current.y,
+                target.y,
+                target.height,
+                bounds.y,
+                bounds.height,
+                content_bounds.height,
+            );
+
+            self.offset_y = offset(
+                translation.y,
+                bounds.height,
+                content_bounds.height,
+                vertical.alignment,
+            );
+        }
+
+        let visible = target - translation;
+
+        visible.intersection(&bounds).unwrap_or(visible)
+    }
+
+    /// Returns whether the [`State`] is animating, gliding or bouncing back,
+    /// and needs to be updated on every frame.
+    fn is_moving(&self) -> bool {
+        self.animation.is_some()
+            || self.momentum.is_some()
+            || self.stretch != Vector::ZERO
+            || self.is_wheel_gesture
+    }
+
+    /// Stops any animation or kinetic scrolling.
+    fn stop(&mut self) {
+        self.animation = None;
+        self.momentum = None;
+    }
+
+    /// Starts an animation from the current offsets to the given ones.
+    fn animate_to(&mut self, x: Offset, y: Offset, transition: Transition) {
+        self.momentum = None;
+        self.animation = Some(Animation {
+            source: (self.offset_x, self.offset_y),
+            target: (x, y),
+            started_at: Instant::now(),
+            transition,
+        });
+        self.last_frame_at = None;
+    }
+
+    /// Animates the offsets by the given delta, continuing from the target
+    /// of any running animation.
+    fn animate_by(
+        &mut self,
+        delta: Vector,
+        transition: Transition,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let (offset_x, offset_y) = self
+            .animation
+            .map_or((self.offset_x, self.offset_y), |animation| {
+                animation.target
+            });
+
+        let mut target = State {
+            offset_x,
+            offset_y,
+            ..State::default()
+        };
+
+        target.scroll(delta, direction, bounds, content_bounds);
+
+        self.animate_to(target.offset_x, target.offset_y, transition);
+    }
+
+    /// Starts tracking a touch at the given position, catching any moving
+    /// contents.
+    fn touch(&mut self, position: Point, now: Instant) {
+        self.stop();
+        self.scroll_area_touched_at = Some(position);
+        self.velocity = Vector::ZERO;
+        self.last_moved_at = Some(now);
+    }
+
+    /// Tracks the velocity of a touch or touchpad drag.
+    fn track(&mut self, delta: Vector, now: Instant) {
+        if let Some(last_moved_at) = self.last_moved_at {
+            let elapsed = now.saturating_duration_since(last_moved_at);
+
+            if !elapsed.is_zero() {
+                let velocity = delta * (1.0 / elapsed.as_secs_f32());
+
+                self.velocity = Vector::new(
+                    velocity.x.clamp(-MAX_VELOCITY, MAX_VELOCITY),
+                    velocity.y.clamp(-MAX_VELOCITY, MAX_VELOCITY),
+                ) * 0.8
+                    + self.velocity * 0.2;
+            }
+        }
+
+        self.last_moved_at = Some(now);
+    }
+
+    /// Ends a touch or touchpad drag, starting kinetic scrolling with the
+    /// given friction if the contents were flung.
+    fn release(&mut self, friction: Option<f32>, now: Instant) {
+        let is_recent = self.last_moved_at.is_some_and(|last_moved_at| {
+            now.saturating_duration_since(last_moved_at).as_millis() < 100
+        });
+
+        if friction.is_some()
+            && is_recent
+            && self.velocity.x.abs().max(self.velocity.y.abs()) > MIN_VELOCITY
+        {
+            self.momentum = Some(self.velocity);
+        }
+
+        self.velocity = Vector::ZERO;
+        self.last_moved_at = None;
+        self.last_frame_at = Some(now);
+        self.is_wheel_gesture = false;
+    }
+
+    /// Moves the contents by the given delta, stretching them past their
+    /// edges if `overscroll` is enabled.
+    fn drag(
+        &mut self,
+        delta: Vector,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        overscroll: bool,
+    ) {
+        if !overscroll {
+            self.scroll(delta, direction, bounds, content_bounds);
+            return;
+        }
+
+        let drag = |properties: Option<&Properties>,
+                    offset: &mut Offset,
+                    stretch: &mut f32,
+                    delta: f32,
+                    viewport: f32,
+                    content: f32| {
+            let Some(properties) = properties else {
+                return;
+            };
+
+            let max = content - viewport;
+
+            if max <= 0.0 {
+                return;
+            }
+
+            let translation =
+                offset.translation(viewport, content, properties.alignment);
+            let position = translation + *stretch - delta;
+            let clamped = position.clamp(0.0, max);
+
+            *stretch = position - clamped;
+            *offset = Offset::Absolute(match properties.alignment {
+                Alignment::Start => clamped,
+                Alignment::End => max - clamped,
+            });
+        };
+
+        drag(
+            direction.horizontal(),
+            &mut self.offset_x,
+            &mut self.stretch.x,
+            delta.x,
+            bounds.width,
+            content_bounds.width,
+        );
+
+        drag(
+            direction.vertical(),
+            &mut self.offset_y,
+            &mut self.stretch.y,
+            delta.y,
+            bounds.height,
+            content_bounds.height,
+        );
+    }
+
+    /// Advances the animations and kinetic scrolling of the [`State`] to
+    /// the given [`Instant`].
+    fn tick(
+        &mut self,
+        now: Instant,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        friction: Option<f32>,
+        overscroll: bool,
+    ) {
+        let elapsed = self
+            .last_frame_at
+            .map(|last_frame_at| now.saturating_duration_since(last_frame_at))
+            .unwrap_or_default()
+            .as_secs_f32()
+            .min(0.05);
+
+        self.last_frame_at = Some(now);
+
+        if let Some(animation) = self.animation {
+            let elapsed = now.saturating_duration_since(animation.started_at);
+
+            if elapsed >= animation.transition.duration() {
+                (self.offset_x, self.offset_y) = animation.target;
+                self.animation = None;
+            } else {
+                let progress = animation.transition.progress(elapsed);
+
+                let interpolate =
+                    |source: Offset,
+                     target: Offset,
+                     viewport: f32,
+                     content: f32| {
+                        let source = source.absolute(viewport, content);
+                        let target = target.absolute(viewport, content);
+
+                        Offset::Absolute(
+                            (source + (target - source) * progress).max(0.0),
+                        )
+                    };
+
+                self.offset_x = interpolate(
+                    animation.source.0,
+                    animation.target.0,
+                    bounds.width,
+                    content_bounds.width,
+                );
+
+                self.offset_y = interpolate(
+                    animation.source.1,
+                    animation.target.1,
+                    bounds.height,
+                    content_bounds.height,
+                );
+            }
+        }
+
+        if self.is_wheel_gesture
+            && self.last_moved_at.is_none_or(|last_moved_at| {
+                now.saturating_duration_since(last_moved_at).as_millis() > 50
+            })
+        {
+            self.release(friction, now);
+        }
+
+        if let Some(velocity) = self.momentum.filter(|_| elapsed > 0.0) {
+            let friction = friction.unwrap_or(f32::INFINITY);
+            let before =
+                self.clamped_translation(direction, bounds, content_bounds);
+
+            self.drag(
+                velocity * elapsed,
+                direction,
+                bounds,
+                content_bounds,
+                overscroll,
+            );
+
+            let after =
+                self.clamped_translation(direction, bounds, content_bounds);
+
+            let decay = |velocity: f32, moved: bool, stretch: f32| {
+                if stretch != 0.0 {
+                    velocity * (-OVERSCROLL_FRICTION * elapsed).exp()
+                } else if moved {
+                    velocity * (-friction * elapsed).exp()
+                } else {
+                    0.0
+                }
+            };
+
+            let velocity = Vector::new(
+                decay(velocity.x, before.x != after.x, self.stretch.x),
+                decay(velocity.y, before.y != after.y, self.stretch.y),
+            );
+
+            self.momentum = (velocity.x.abs().max(velocity.y.abs())
+                > MIN_VELOCITY)
+                .then_some(velocity);
+        }
+
+        if self.scroll_area_touched_at.is_none() && !self.is_wheel_gesture {
+            let momentum = self.momentum.unwrap_or(Vector::ZERO);
+
+            let settle = |stretch: f32, velocity: f32| {
+                if velocity.abs() > MIN_VELOCITY {
+                    return stretch;
+                }
+
+                let stretch = stretch * (-OVERSCROLL_SPRING * elapsed).exp();
+
+                if stretch.abs() < 0.5 {
+                    0.0
+                } else {
+                    stretch
+                }
+            };
+
+            self.stretch = Vector::new(
+                settle(self.stretch.x, momentum.x),
+                settle(self.stretch.y, momentum.y),
+            );
+        }
+    }
+
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
@@ -1245,11 +2278,28 @@ impl State {
 
     /// Returns the scrolling translation of the [`State`], given a [`Direction`],
     /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// It includes any overscroll of the contents past their edges.
     fn translation(
         &self,
         direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
+    ) -> Vector {
+        self.clamped_translation(direction, bounds, content_bounds)
+            + Vector::new(
+                rubber_band(self.stretch.x, bounds.width),
+                rubber_band(self.stretch.y, bounds.height),
+            )
+    }
+
+    /// Returns the scrolling translation of the [`State`] without any
+    /// overscroll.
+    fn clamped_translation(
+        &self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
     ) -> Vector {
         Vector::new(
             if let Some(horizontal) = direction.horizontal() {
@@ -1273,6 +2323,31 @@ impl State {
         )
     }
 
+    /// Returns the cursor at the given position of the contents, moved back
+    /// to where any pinned contents under it were laid out.
+    ///
+    /// Pinned contents are drawn over the ones that follow them, which
+    /// must not get the cursor.
+    fn unpin(
+        &self,
+        position: Point,
+        bounds: Rectangle,
+        translation: Vector,
+    ) -> mouse::Cursor {
+        let origin = Vector::new(bounds.x, bounds.y);
+        let viewport = bounds + translation;
+
+        let offset = self.pins.iter().rev().find_map(|pin| {
+            let pin_bounds = pin.bounds + origin;
+            let offset = pin.offset(pin_bounds, &viewport);
+
+            (offset != Vector::ZERO && (pin_bounds + offset).contains(position))
+                .then_some(offset)
+        });
+
+        mouse::Cursor::Available(position - offset.unwrap_or(Vector::ZERO))
+    }
+
     /// Returns whether any scroller is currently grabbed or not.
     pub fn scrollers_grabbed(&self) -> bool {
         self.x_scroller_grabbed_at.is_some()
@@ -1295,7 +2370,8 @@ impl Scrollbars {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) -> Self {
-        let translation = state.translation(direction, bounds, content_bounds);
+        let translation =
+            state.clamped_translation(direction, bounds, content_bounds);
 
         let show_scrollbar_x = direction
             .horizontal()
@@ -1354,11 +2430,26 @@ impl Scrollbars {
                 height: scroller_height,
             };
 
+            // The vertical scrollbar is placed on the left side of a
+            // right-to-left layout
+            let mirror = |rectangle: Rectangle| {
+                if state.layout_direction.is_rtl() {
+                    Rectangle {
+                        x: 2.0 * bounds.x + bounds.width
+                            - rectangle.x
+                            - rectangle.width,
+                        ..rectangle
+                    }
+                } else {
+                    rectangle
+                }
+            };
+
             Some(internals::Scrollbar {
-                total_bounds: total_scrollbar_bounds,
-                bounds: scrollbar_bounds,
+                total_bounds: mirror(total_scrollbar_bounds),
+                bounds: mirror(scrollbar_bounds),
                 scroller: internals::Scroller {
-                    bounds: scroller_bounds,
+                    bounds: mirror(scroller_bounds),
                 },
                 alignment: vertical.alignment,
             })
@@ -1382,9 +2473,15 @@ impl Scrollbars {
             let total_scrollbar_height =
                 width.max(scroller_width) + 2.0 * margin;
 
+            let x = if state.layout_direction.is_rtl() {
+                bounds.x + scrollbar_y_width
+            } else {
+                bounds.x
+            };
+
             // Total bounds of the scrollbar + margin + scroller width
             let total_scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
@@ -1392,7 +2489,7 @@ impl Scrollbars {
 
             // Bounds of just the scrollbar
             let scrollbar_bounds = Rectangle {
-                x: bounds.x,
+                x,
                 y: bounds.y + bounds.height
                     - total_scrollbar_height / 2.0
                     - width / 2.0,
@@ -1616,6 +2713,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Scrollable`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -1698,3 +2819,222 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::animation::Easing;
+    use crate::core::time::Duration;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 1000.0,
+    };
+
+    fn run(state: &mut State, start: Instant, friction: Option<f32>) {
+        for frame in 1..=600 {
+            if !state.is_moving() {
+                break;
+            }
+
+            state.tick(
+                start + Duration::from_millis(frame * 16),
+                Direction::default(),
+                BOUNDS,
+                CONTENT,
+                friction,
+                true,
+            );
+        }
+    }
+
+    fn offset_y(state: &State) -> f32 {
+        state.translation(Direction::default(), BOUNDS, CONTENT).y
+    }
+
+    #[test]
+    fn flung_contents_glide_and_stop() {
+        let start = Instant::now();
+        let mut state = State::new();
+
+        state.touch(Point::new(50.0, 80.0), start);
+        state.track(Vector::new(0.0, -20.0), start + Duration::from_millis(16));
+        state.drag(
+            Vector::new(0.0, -20.0),
+            Direction::default(),
+            BOUNDS,
+            CONTENT,
+            true,
+        );
+        state.release(Some(4.0), start + Duration::from_millis(20));
+
+        run(&mut state, start, Some(4.0));
+
+        assert!(!state.is_moving());
+        assert!(offset_y(&state) > 100.0);
+        assert!(offset_y(&state) <= 900.0);
+    }
+
+    #[test]
+    fn overscrolled_contents_bounce_back() {
+        let start = Instant::now();
+        let mut state = State::new();
+
+        state.touch(Point::new(50.0, 10.0), start);
+        state.drag(
+            Vector::new(0.0, 60.0),
+            Direction::default(),
+            BOUNDS,
+            CONTENT,
+            true,
+        );
+
+        assert!(offset_y(&state) < 0.0);
+        assert!(offset_y(&state) > -60.0);
+
+        state.scroll_area_touched_at = None;
+        state.release(None, start);
+
+        run(&mut state, start, None);
+
+        assert_eq!(offset_y(&state), 0.0);
+    }
+
+    #[test]
+    fn animated_scrolling_reaches_its_target() {
+        let mut state = State::new();
+
+        operation::Scrollable::scroll_to_animated(
+            &mut state,
+            AbsoluteOffset { x: 0.0, y: 400.0 },
+            Transition::easing(Easing::EaseOut, Duration::from_millis(200)),
+        );
+
+        assert_eq!(offset_y(&state), 0.0);
+
+        run(&mut state, Instant::now(), None);
+
+        assert_eq!(offset_y(&state), 400.0);
+    }
+
+    #[test]
+    fn scrolling_into_view_follows_the_placement() {
+        let mut state = State::new();
+        let item =
+            Rectangle::new(Point::new(0.0, 500.0), Size::new(100.0, 20.0));
+
+        let reveal = |state: &mut State, placement| {
+            state.scroll_into_view(
+                item,
+                placement,
+                Direction::default(),
+                BOUNDS,
+                CONTENT,
+            )
+        };
+
+        let visible = reveal(&mut state, Placement::Nearest);
+
+        assert_eq!(offset_y(&state), 420.0);
+        assert_eq!(visible.y, 80.0);
+
+        let _ = reveal(&mut state, Placement::Nearest);
+        assert_eq!(offset_y(&state), 420.0);
+
+        let _ = reveal(&mut state, Placement::Center);
+        assert_eq!(offset_y(&state), 460.0);
+
+        let _ = reveal(&mut state, Placement::Start);
+        assert_eq!(offset_y(&state), 500.0);
+
+        let _ = reveal(&mut state, Placement::End);
+        assert_eq!(offset_y(&state), 420.0);
+    }
+
+    #[test]
+    fn sticky_contents_are_pushed_off_by_the_next_ones() {
+        let header =
+            |y| Rectangle::new(Point::new(0.0, y), Size::new(100.0, 20.0));
+
+        let mut pins = Pins::new(CONTENT);
+        pins.pins = vec![(header(0.0), CONTENT), (header(300.0), CONTENT)];
+        pins.reach();
+
+        let reaches: Vec<_> = pins.reaches.unwrap().collect();
+
+        assert_eq!(reaches, [Vector::new(0.0, 280.0), Vector::new(0.0, 680.0)]);
+
+        let pin = Pin {
+            bounds: header(0.0),
+            reach: Some(reaches[0]),
+        };
+
+        let viewport = |y| BOUNDS + Vector::new(0.0, y);
+
+        assert_eq!(pin.offset(header(0.0), &viewport(0.0)).y, 0.0);
+        assert_eq!(pin.offset(header(0.0), &viewport(150.0)).y, 150.0);
+        assert_eq!(pin.offset(header(0.0), &viewport(500.0)).y, 280.0);
+    }
+
+    #[test]
+    fn sticky_overlays_are_offset_like_their_contents() {
+        let header = Rectangle::new(Point::ORIGIN, Size::new(100.0, 20.0));
+
+        let pin = Pin {
+            bounds: header,
+            reach: Some(Vector::new(0.0, 280.0)),
+        };
+
+        let viewport = |y| BOUNDS + Vector::new(0.0, y);
+
+        assert_eq!(pin.overlay_offset(header), Vector::ZERO);
+
+        for y in [0.0, 150.0, 500.0] {
+            assert_eq!(
+                Pin::within(viewport(y), || pin.overlay_offset(header)),
+                pin.offset(header, &viewport(y))
+            );
+        }
+
+        assert_eq!(pin.overlay_offset(header), Vector::ZERO);
+    }
+
+    #[test]
+    fn pinned_contents_hide_the_cursor_from_the_ones_beneath() {
+        let header = Rectangle::new(Point::ORIGIN, Size::new(100.0, 20.0));
+
+        let state = State {
+            pins: vec![Pin {
+                bounds: header,
+                reach: Some(Vector::new(0.0, 280.0)),
+            }],
+            ..State::new()
+        };
+
+        let translation = Vector::new(0.0, 150.0);
+
+        assert_eq!(
+            state.unpin(Point::new(50.0, 160.0), BOUNDS, translation),
+            mouse::Cursor::Available(Point::new(50.0, 10.0))
+        );
+
+        assert_eq!(
+            state.unpin(Point::new(50.0, 180.0), BOUNDS, translation),
+            mouse::Cursor::Available(Point::new(50.0, 180.0))
+        );
+
+        assert_eq!(
+            state.unpin(Point::new(50.0, 10.0), BOUNDS, Vector::ZERO),
+            mouse::Cursor::Available(Point::new(50.0, 10.0))
+        );
+    }
+}
@@ -6,8 +6,11 @@ use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::theme::Class;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Border, Clipboard, Color, Element, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Theme, Widget,
@@ -40,6 +43,7 @@ use std::ops::RangeInclusive;
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Slider<'a, T, Message, Theme = crate::Theme> {
+    id: Option<Id>,
     range: RangeInclusive<T>,
     step: T,
     shift_step: Option<T>,
@@ -86,6 +90,7 @@ where
         };
 
         Slider {
+            id: None,
             value,
             default: None,
             range,
@@ -99,6 +104,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Slider`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the optional default value for the [`Slider`].
     ///
     /// If set, the [`Slider`] will reset to this value when ctrl-clicked or command-clicked.
@@ -139,6 +150,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`Slider`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
     /// Sets the step size of the [`Slider`].
     pub fn step(mut self, step: impl Into<T>) -> Self {
         self.step = step.into();
@@ -169,6 +198,20 @@ where
         tree::State::new(State::default())
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -277,6 +320,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if let Some(cursor_position) =
                     cursor.position_over(layout.bounds())
                 {
@@ -311,20 +356,27 @@ where
                     return event::Status::Captured;
                 }
             }
-            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if cursor.position_over(layout.bounds()).is_some() {
-                    match key {
-                        Key::Named(key::Named::ArrowUp) => {
-                            let _ = increment(current_value).map(change);
-                        }
-                        Key::Named(key::Named::ArrowDown) => {
-                            let _ = decrement(current_value).map(change);
-                        }
-                        _ => (),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(key),
+                ..
+            }) if state.is_focused
+                || cursor.position_over(layout.bounds()).is_some() =>
+            {
+                let new_value = match key {
+                    key::Named::ArrowUp | key::Named::ArrowRight => {
+                        increment(current_value)
                     }
+                    key::Named::ArrowDown | key::Named::ArrowLeft => {
+                        decrement(current_value)
+                    }
+                    key::Named::Home => Some(*self.range.start()),
+                    key::Named::End => Some(*self.range.end()),
+                    _ => return event::Status::Ignored,
+                };
 
-                    return event::Status::Captured;
-                }
+                let _ = new_value.map(change);
+
+                return event::Status::Captured;
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = modifiers;
@@ -353,6 +405,8 @@ where
             theme,
             if state.is_dragging {
                 Status::Dragged
+            } else if state.is_focused {
+                Status::Focused
             } else if is_mouse_over {
                 Status::Hovered
             } else {
@@ -474,9 +528,24 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Slider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -486,6 +555,8 @@ pub enum Status {
     Hovered,
     /// The [`Slider`] is being dragged.
     Dragged,
+    /// The [`Slider`] has keyboard focus.
+    Focused,
 }
 
 /// The appearance of a slider.
@@ -564,6 +635,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Slider`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -576,10 +671,17 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
 
     let color = match status {
         Status::Active => palette.primary.strong.color,
-        Status::Hovered => palette.primary.base.color,
+        Status::Hovered | Status::Focused => palette.primary.base.color,
         Status::Dragged => palette.primary.strong.color,
     };
 
+    let (border_width, border_color) = match status {
+        Status::Focused => (2.0, palette.background.base.text),
+        Status::Active | Status::Hovered | Status::Dragged => {
+            (0.0, Color::TRANSPARENT)
+        }
+    };
+
     Appearance {
         rail: Rail {
             colors: (color, palette.secondary.base.color),
@@ -589,8 +691,8 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         handle: Handle {
             shape: HandleShape::Circle { radius: 7.0 },
             color,
-            border_color: Color::TRANSPARENT,
-            border_width: 0.0,
+            border_color,
+            border_width,
         },
     }
 }
@@ -0,0 +1,235 @@
+//! Keep some contents of a scrollable in view while the rest scrolls.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::scrollable::Pin;
+
+/// Some contents that stick to the start of the viewport of the
+/// [`Scrollable`] containing them, on both axes, instead of scrolling out
+/// of view.
+///
+/// The contents stay pinned until the end of their parent, or until the next
+/// [`Sticky`] in their way pushes them off; this makes them a good fit for
+/// the section headers of a grouped list.
+///
+/// Pinned contents are drawn on top of the rest, so they should have a
+/// background. The [`Scrollable`] moves the cursor over them back to where
+/// they were laid out, so the contents beneath them do not get it. Outside
+/// of a [`Scrollable`], a [`Sticky`] does nothing.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct Sticky<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Sticky<'a, Message, Theme, Renderer> {
+    /// Creates a [`Sticky`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Sticky {
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Pin>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Pin::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Pin::register();
+
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let pin = tree.state.downcast_mut::<Pin>();
+        pin.bounds = layout.bounds();
+
+        operation.custom(pin, None);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let offset = tree
+            .state
+            .downcast_ref::<Pin>()
+            .offset(layout.bounds(), viewport);
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &(*viewport - offset),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let offset = tree
+            .state
+            .downcast_ref::<Pin>()
+            .offset(layout.bounds(), viewport);
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            &(*viewport - offset),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let offset = tree
+            .state
+            .downcast_ref::<Pin>()
+            .offset(layout.bounds(), viewport);
+
+        if offset == Vector::ZERO {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        // A new layer draws the pinned contents on top of whatever follows
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.with_translation(offset, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    &(*viewport - offset),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = tree
+            .state
+            .downcast_ref::<Pin>()
+            .overlay_offset(layout.bounds());
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation + offset,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sticky<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        sticky: Sticky<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(sticky)
+    }
+}
@@ -0,0 +1,1244 @@
+//! Switch between documents with a bar of tabs.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::menu::Metrics;
+
+/// A horizontal bar of tabs.
+///
+/// A [`TabBar`] only displays the labels of the tabs. Use [`Tabs`] to bind
+/// it to a content area, or place it in the [`TitleBar`] of a [`PaneGrid`]
+/// to host tabs in a pane.
+///
+/// The tabs of a [`TabBar`] can optionally be closed and reordered by
+/// dragging them. When the tabs do not fit, the bar can be scrolled with
+/// the mouse wheel and the active tab is always scrolled into view.
+///
+/// Once a [`TabBar`] has been clicked or focused, `Ctrl+Tab` and
+/// `Ctrl+Shift+Tab` switch to the next and previous tab, respectively.
+///
+/// [`TitleBar`]: crate::pane_grid::TitleBar
+/// [`PaneGrid`]: crate::PaneGrid
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::tabs::Reorder;
+/// use iced::widget::{tab_bar, tabs, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+///     Reorder(Reorder),
+/// }
+///
+/// struct Document {
+///     id: usize,
+///     name: String,
+///     contents: String,
+/// }
+///
+/// fn view<'a>(documents: &'a [Document], active: usize) -> Element<'a, Message> {
+///     let bar = documents
+///         .iter()
+///         .fold(tab_bar(active, Message::Select), |bar, document| {
+///             bar.push(document.id, &document.name)
+///         })
+///         .on_close(Message::Close)
+///         .on_reorder(Message::Reorder);
+///
+///     let contents = documents
+///         .iter()
+///         .find(|document| document.id == active)
+///         .map(|document| document.contents.as_str())
+///         .unwrap_or_default();
+///
+///     tabs(bar, text(contents)).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TabBar<
+    'a,
+    Id,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    tabs: Vec<(Id, String)>,
+    active: Id,
+    on_select: Box<dyn Fn(Id) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<'a, Theme>,
+}
+
+impl<'a, Id, Message, Theme, Renderer> TabBar<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the tabs of a [`TabBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// The distance the cursor needs to travel before a tab is dragged.
+    const DRAG_DEADBAND: f32 = 5.0;
+
+    /// Creates a new empty [`TabBar`] with the identifier of the active tab
+    /// and a closure that produces a message when a tab is selected.
+    pub fn new(active: Id, on_select: impl Fn(Id) -> Message + 'a) -> Self
+    where
+        Theme: DefaultStyle + 'a,
+    {
+        Self {
+            id: None,
+            tabs: Vec::new(),
+            active,
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TabBar`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Adds a tab with the given identifier and label to the [`TabBar`].
+    pub fn push(mut self, id: Id, label: impl Into<String>) -> Self {
+        self.tabs.push((id, label.into()));
+        self
+    }
+
+    /// Extends the [`TabBar`] with the given tabs.
+    pub fn extend(
+        self,
+        tabs: impl IntoIterator<Item = (Id, impl Into<String>)>,
+    ) -> Self {
+        tabs.into_iter()
+            .fold(self, |bar, (id, label)| bar.push(id, label))
+    }
+
+    /// Sets the message that should be produced when the close button of a
+    /// tab is pressed.
+    ///
+    /// The tabs will not display a close button if this is not set.
+    pub fn on_close(mut self, on_close: impl Fn(Id) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is dragged to a
+    /// new position.
+    ///
+    /// The tabs cannot be reordered if this is not set.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(Reorder) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`TabBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the tabs of the [`TabBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`TabBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`TabBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TabBar`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`TabBar`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn active_index(&self) -> Option<usize> {
+        self.tabs.iter().position(|(id, _)| *id == self.active)
+    }
+
+    fn close_bounds(
+        &self,
+        tab: Rectangle,
+        metrics: &Metrics<Renderer::Font>,
+    ) -> Rectangle {
+        let size = metrics.size.0;
+
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right - size,
+            y: tab.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    fn layout_bar(
+        &self,
+        state: &mut State,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let height = metrics.line_height + self.padding.vertical();
+
+        let close_width = if self.on_close.is_some() {
+            metrics.size.0 + self.spacing.max(metrics.size.0 / 2.0)
+        } else {
+            0.0
+        };
+
+        let widths: Vec<f32> = self
+            .tabs
+            .iter()
+            .map(|(_, label)| {
+                metrics.measure::<Renderer::Paragraph>(label)
+                    + self.padding.horizontal()
+                    + close_width
+            })
+            .collect();
+
+        let total = widths.iter().sum::<f32>()
+            + self.spacing * widths.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(total, height),
+        );
+
+        // Scroll the active tab into view whenever it changes
+        let active = self.active_index();
+
+        if let Some(index) = active.filter(|_| active != state.active) {
+            let start = widths[..index].iter().sum::<f32>()
+                + self.spacing * index as f32;
+            let end = start + widths[index];
+
+            if start < state.offset {
+                state.offset = start;
+            } else if end > state.offset + size.width {
+                state.offset = end - size.width;
+            }
+        }
+
+        state.active = active;
+        state.offset = state.offset.min(total - size.width).max(0.0);
+
+        let mut x = -state.offset;
+
+        let tabs = widths
+            .into_iter()
+            .map(|width| {
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    fn update(
+        &mut self,
+        state: &mut State,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let hovered = || {
+            if !cursor.is_over(bounds) {
+                return None;
+            }
+
+            layout
+                .children()
+                .position(|tab| cursor.is_over(tab.bounds()))
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(bounds);
+
+                let (Some(index), Some(position)) =
+                    (hovered(), cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let id = self.tabs[index].0.clone();
+
+                if let Some(on_close) = &self.on_close {
+                    let metrics =
+                        Metrics::new(renderer, self.text_size, self.font);
+                    let tab = layout.children().nth(index).unwrap().bounds();
+
+                    if self.close_bounds(tab, &metrics).contains(position) {
+                        shell.publish(on_close(id));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if id != self.active {
+                    shell.publish((self.on_select)(id));
+                }
+
+                if self.on_reorder.is_some() {
+                    state.drag = Some(Drag {
+                        index,
+                        origin: position.x,
+                        position: position.x,
+                        is_dragging: false,
+                    });
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.position = position.x;
+
+                    if (drag.position - drag.origin).abs() > Self::DRAG_DEADBAND
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    if !drag.is_dragging {
+                        return event::Status::Ignored;
+                    }
+
+                    let target = reorder_target(layout, &drag);
+
+                    if target != drag.index {
+                        if let Some(on_reorder) = &self.on_reorder {
+                            shell.publish(on_reorder(Reorder {
+                                from: drag.index,
+                                to: target,
+                            }));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(bounds) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x == 0.0 { y } else { x }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                let total = layout
+                    .children()
+                    .last()
+                    .map(|tab| tab.bounds().x + tab.bounds().width)
+                    .unwrap_or(bounds.x)
+                    - bounds.x
+                    + state.offset;
+
+                if total > bounds.width {
+                    state.offset = (state.offset - delta)
+                        .min(total - bounds.width)
+                        .max(0.0);
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if state.is_focused && modifiers.control() => {
+                return self.switch(modifiers, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Selects the next tab, or the previous one if `Shift` is pressed.
+    fn switch(
+        &self,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let count = self.tabs.len();
+
+        if count == 0 {
+            return event::Status::Ignored;
+        }
+
+        let next = match self.active_index() {
+            Some(index) if modifiers.shift() => (index + count - 1) % count,
+            Some(index) => (index + 1) % count,
+            None => 0,
+        };
+
+        shell.publish((self.on_select)(self.tabs[next].0.clone()));
+
+        event::Status::Captured
+    }
+
+    fn interaction(
+        &self,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.drag.as_ref().is_some_and(|drag| drag.is_dragging) {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds())
+            && layout.children().any(|tab| cursor.is_over(tab.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw_bar(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(clip_bounds) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+        let drag = state.drag.as_ref().filter(|drag| drag.is_dragging);
+
+        let cursor = if drag.is_some() || !cursor.is_over(clip_bounds) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            for (index, ((id, label), layout)) in
+                self.tabs.iter().zip(layout.children()).enumerate()
+            {
+                if drag.is_some_and(|drag| drag.index == index) {
+                    continue;
+                }
+
+                self.draw_tab(
+                    renderer,
+                    theme,
+                    &metrics,
+                    state,
+                    id,
+                    label,
+                    layout.bounds(),
+                    cursor,
+                    clip_bounds,
+                );
+            }
+        });
+
+        if let Some(drag) = drag {
+            let Some(((id, label), layout)) =
+                self.tabs.iter().zip(layout.children()).nth(drag.index)
+            else {
+                return;
+            };
+
+            let bounds = layout.bounds();
+            let translation = (drag.position - drag.origin)
+                .max(clip_bounds.x - bounds.x)
+                .min(
+                    clip_bounds.x + clip_bounds.width - bounds.x - bounds.width,
+                );
+
+            renderer.with_layer(clip_bounds, |renderer| {
+                self.draw_tab(
+                    renderer,
+                    theme,
+                    &metrics,
+                    state,
+                    id,
+                    label,
+                    bounds + Vector::new(translation, 0.0),
+                    mouse::Cursor::Unavailable,
+                    clip_bounds,
+                );
+            });
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_tab(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        metrics: &Metrics<Renderer::Font>,
+        state: &State,
+        id: &Id,
+        label: &str,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        clip_bounds: Rectangle,
+    ) {
+        let status = if *id == self.active {
+            if state.is_focused {
+                Status::Focused
+            } else {
+                Status::Selected
+            }
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+
+        if appearance.background.is_some() || appearance.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        metrics.draw(
+            renderer,
+            label,
+            Point::new(bounds.x + self.padding.left, bounds.center_y()),
+            alignment::Horizontal::Left,
+            appearance.text_color,
+            clip_bounds,
+        );
+
+        if self.on_close.is_some() {
+            let close = self.close_bounds(bounds, metrics);
+
+            let color = if cursor.is_over(close) {
+                appearance.text_color
+            } else {
+                appearance.close_color
+            };
+
+            metrics.draw(
+                renderer,
+                "×",
+                close.center(),
+                alignment::Horizontal::Center,
+                color,
+                clip_bounds,
+            );
+        }
+    }
+}
+
+/// Returns the index where the dragged tab would be placed once the
+/// other tabs have been shifted.
+fn reorder_target(layout: Layout<'_>, drag: &Drag) -> usize {
+    let Some(dragged) = layout.children().nth(drag.index) else {
+        return drag.index;
+    };
+
+    let center = dragged.bounds().center_x() + drag.position - drag.origin;
+
+    layout
+        .children()
+        .enumerate()
+        .filter(|(index, tab)| {
+            *index != drag.index && tab.bounds().center_x() < center
+        })
+        .count()
+}
+
+impl<'a, Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.layout_bar(tree.state.downcast_mut::<State>(), renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        self.update(
+            tree.state.downcast_mut::<State>(),
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.interaction(tree.state.downcast_ref::<State>(), layout, cursor)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.draw_bar(
+            tree.state.downcast_ref::<State>(),
+            renderer,
+            theme,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer>
+    From<TabBar<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tab_bar: TabBar<'a, Id, Message, Theme, Renderer>) -> Self {
+        Self::new(tab_bar)
+    }
+}
+
+/// A [`TabBar`] bound to the content of its active tab.
+///
+/// The content is displayed below the [`TabBar`]. `Ctrl+Tab` switches tabs
+/// whenever the content does not capture it.
+#[allow(missing_debug_implementations)]
+pub struct Tabs<
+    'a,
+    Id,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    bar: TabBar<'a, Id, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Id, Message, Theme, Renderer> Tabs<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    /// Creates new [`Tabs`] with the given [`TabBar`] and the content of
+    /// its active tab.
+    pub fn new(
+        bar: TabBar<'a, Id, Message, Theme, Renderer>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+
+        Self {
+            bar,
+            content,
+            width: Length::Shrink.enclose(size.width),
+            height: Length::Shrink.enclose(size.height),
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let bar = self.bar.layout_bar(
+            tree.state.downcast_mut::<State>(),
+            renderer,
+            &limits,
+        );
+        let bar_height = bar.size().height;
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(Size::new(0.0, bar_height)),
+            )
+            .move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                bar.size().width.max(content.size().width),
+                bar_height + content.size().height,
+            ),
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.bar.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.bar.id.as_ref());
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().nth(1).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let status = self.bar.update(
+            tree.state.downcast_mut::<State>(),
+            event.clone(),
+            bar_layout,
+            cursor,
+            renderer,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if modifiers.control() => self.bar.switch(modifiers, shell),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let interaction = self.bar.interaction(
+            tree.state.downcast_ref::<State>(),
+            bar_layout,
+            cursor,
+        );
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let bar_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        self.bar.draw_bar(
+            tree.state.downcast_ref::<State>(),
+            renderer,
+            theme,
+            bar_layout,
+            cursor,
+            viewport,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer>
+    From<Tabs<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Id, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// A tab that has been dragged to a new position.
+///
+/// The tab should be removed from index `from` and then inserted at index
+/// `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The original index of the tab.
+    pub from: usize,
+    /// The new index of the tab.
+    pub to: usize,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offset: f32,
+    active: Option<usize>,
+    is_focused: bool,
+    drag: Option<Drag>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+#[derive(Debug)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+    is_dragging: bool,
+}
+
+/// The possible status of a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The tab is not selected.
+    Active,
+    /// The tab is not selected and it is being hovered.
+    Hovered,
+    /// The tab is selected.
+    Selected,
+    /// The tab is selected and its [`TabBar`] has keyboard focus.
+    Focused,
+}
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+}
+
+/// The style of a [`TabBar`].
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The default style of a [`TabBar`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`TabBar`].
+    fn default_style(&self, status: Status) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self, status: Status) -> Appearance {
+        default(self, status)
+    }
+}
+
+/// The styles of a [`TabBar`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self, _status: Status) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of a [`TabBar`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let base = Appearance {
+        background: None,
+        border: Border::rounded(2),
+        text_color: palette.background.base.text,
+        close_color: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Appearance {
+            background: Some(palette.background.weak.color.into()),
+            ..base
+        },
+        Status::Selected => Appearance {
+            background: Some(palette.primary.weak.color.into()),
+            text_color: palette.primary.weak.text,
+            close_color: palette.primary.weak.text.scale_alpha(0.7),
+            ..base
+        },
+        Status::Focused => Appearance {
+            background: Some(palette.primary.weak.color.into()),
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 1.0,
+                ..base.border
+            },
+            text_color: palette.primary.weak.text,
+            close_color: palette.primary.weak.text.scale_alpha(0.7),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorder_target_follows_dragged_center() {
+        let tabs = (0..3)
+            .map(|i| {
+                layout::Node::new(Size::new(100.0, 30.0))
+                    .move_to(Point::new(i as f32 * 100.0, 0.0))
+            })
+            .collect();
+
+        let node = layout::Node::with_children(Size::new(300.0, 30.0), tabs);
+        let layout = Layout::new(&node);
+
+        let drag = |index, delta| Drag {
+            index,
+            origin: 0.0,
+            position: delta,
+            is_dragging: true,
+        };
+
+        assert_eq!(reorder_target(layout, &drag(0, 10.0)), 0);
+        assert_eq!(reorder_target(layout, &drag(0, 120.0)), 1);
+        assert_eq!(reorder_target(layout, &drag(0, 220.0)), 2);
+        assert_eq!(reorder_target(layout, &drag(2, -120.0)), 1);
+        assert_eq!(reorder_target(layout, &drag(2, -250.0)), 0);
+    }
+}
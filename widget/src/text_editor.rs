@@ -9,6 +9,8 @@ use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{self, LineHeight};
+use crate::core::theme::Class;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::{self, Widget};
 use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Rectangle,
@@ -34,6 +36,7 @@ pub struct TextEditor<
     Highlighter: text::Highlighter,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     content: &'a Content<Renderer>,
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
@@ -61,6 +64,7 @@ where
         Theme: DefaultStyle + 'a,
     {
         Self {
+            id: None,
             content,
             font: None,
             text_size: None,
@@ -84,6 +88,12 @@ where
     Highlighter: text::Highlighter,
     Renderer: text::Renderer,
 {
+    /// Sets the [`Id`](widget::Id) of the [`TextEditor`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the height of the [`TextEditor`].
     pub fn height(mut self, height: impl Into<Length>) -> Self {
         self.height = height.into();
@@ -127,6 +137,7 @@ where
         ) -> highlighter::Format<Renderer::Font>,
     ) -> TextEditor<'a, H, Message, Theme, Renderer> {
         TextEditor {
+            id: self.id,
             content: self.content,
             font: self.font,
             text_size: self.text_size,
@@ -149,6 +160,24 @@ where
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`TextEditor`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
 }
 
 /// The content of a [`TextEditor`].
@@ -305,6 +334,22 @@ struct State<Highlighter: text::Highlighter> {
     highlighter_format_address: usize,
 }
 
+impl<Highlighter: text::Highlighter> operation::Focusable
+    for State<Highlighter>
+{
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Highlighter, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for TextEditor<'a, Highlighter, Message, Theme, Renderer>
 where
@@ -336,6 +381,23 @@ where
         }
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        if self.on_edit.is_some() {
+            operation.bounds(self.id.as_ref(), layout.bounds());
+            operation.focusable(state, self.id.as_ref());
+        } else {
+            state.is_focused = false;
+        }
+    }
+
     fn layout(
         &self,
         tree: &mut widget::Tree,
@@ -826,6 +888,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`TextEditor`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -20,6 +20,7 @@ use crate::core::layout;
 use crate::core::mouse::{self, click};
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::theme::{self, Class};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
@@ -211,6 +212,44 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`TextInput`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+
+    /// Applies an [`Operation`] of any output type to the state of the
+    /// [`TextInput`].
+    pub(crate) fn operate_state<T>(
+        &self,
+        tree: &mut Tree,
+        bounds: Rectangle,
+        operation: &mut dyn Operation<T>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.bounds(self.id.as_ref().map(|id| &id.0), bounds);
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -224,6 +263,8 @@ where
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let value = value.unwrap_or(&self.value);
 
+        state.direction = limits.direction();
+
         let font = self.font.unwrap_or_else(|| renderer.default_font());
         let text_size = self.size.unwrap_or_else(|| renderer.default_size());
         let padding = self.padding.fit(Size::ZERO, limits.max());
@@ -295,15 +336,28 @@ where
                 layout::Node::new(Size::new(icon_width, text_bounds.height))
                     .move_to(icon_position);
 
-            layout::Node::with_children(
-                text_bounds.expand(padding),
-                vec![text_node, icon_node],
-            )
+            let size = text_bounds.expand(padding);
+
+            let (text_node, icon_node) = if state.direction.is_rtl() {
+                (text_node.mirror(size.width), icon_node.mirror(size.width))
+            } else {
+                (text_node, icon_node)
+            };
+
+            layout::Node::with_children(size, vec![text_node, icon_node])
         } else {
+            let size = text_bounds.expand(padding);
+
             let text = layout::Node::new(text_bounds)
                 .move_to(Point::new(padding.left, padding.top));
 
-            layout::Node::with_children(text_bounds.expand(padding), vec![text])
+            let text = if state.direction.is_rtl() {
+                text.mirror(size.width)
+            } else {
+                text
+            };
+
+            layout::Node::with_children(size, vec![text])
         }
     }
 
@@ -381,6 +435,7 @@ where
                             &state.value,
                             text_bounds,
                             position,
+                            state.direction,
                         );
 
                     let is_cursor_visible = ((focus.now - focus.updated_at)
@@ -418,6 +473,7 @@ where
                             &state.value,
                             text_bounds,
                             left,
+                            state.direction,
                         );
 
                     let (right_position, right_offset) =
@@ -425,15 +481,18 @@ where
                             &state.value,
                             text_bounds,
                             right,
+                            state.direction,
                         );
 
-                    let width = right_position - left_position;
+                    // Right-to-left text grows towards the left
+                    let x = left_position.min(right_position);
+                    let width = (right_position - left_position).abs();
 
                     (
                         Some((
                             renderer::Quad {
                                 bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
+                                    x: text_bounds.x + x,
                                     y: text_bounds.y,
                                     width,
                                     height: text_bounds.height,
@@ -451,7 +510,16 @@ where
                 }
             }
         } else {
-            (None, 0.0)
+            (
+                None,
+                alignment_offset(&state.value, text_bounds, state.direction),
+            )
+        };
+
+        let text_offset = if text.is_empty() {
+            alignment_offset(&state.placeholder, text_bounds, state.direction)
+        } else {
+            offset
         };
 
         let draw = |renderer: &mut Renderer, viewport| {
@@ -473,7 +541,7 @@ where
                     &state.value
                 },
                 Point::new(text_bounds.x, text_bounds.center_y())
-                    - Vector::new(offset, 0.0),
+                    - Vector::new(text_offset, 0.0),
                 if text.is_empty() {
                     appearance.placeholder
                 } else {
@@ -537,14 +605,11 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
-
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        self.operate_state(tree, layout.bounds(), operation);
     }
 
     fn on_event(
@@ -873,42 +938,51 @@ where
 
                             update_cache(state, &self.value);
                         }
-                        keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        keyboard::Key::Named(
+                            named @ (key::Named::ArrowLeft
+                            | key::Named::ArrowRight),
+                        ) => {
+                            let is_rtl = is_rtl_at(
+                                &state.value,
+                                state.cursor.end(&self.value),
+                                self.value.len(),
+                            );
+
+                            let forwards =
+                                (named == key::Named::ArrowRight) != is_rtl;
+
                             if platform::is_jump_modifier_pressed(modifiers)
                                 && !self.is_secure
                             {
-                                if modifiers.shift() {
-                                    state
+                                match (modifiers.shift(), forwards) {
+                                    (true, true) => state
                                         .cursor
-                                        .select_left_by_words(&self.value);
-                                } else {
-                                    state
+                                        .select_right_by_words(&self.value),
+                                    (true, false) => state
                                         .cursor
-                                        .move_left_by_words(&self.value);
-                                }
-                            } else if modifiers.shift() {
-                                state.cursor.select_left(&self.value);
-                            } else {
-                                state.cursor.move_left(&self.value);
-                            }
-                        }
-                        keyboard::Key::Named(key::Named::ArrowRight) => {
-                            if platform::is_jump_modifier_pressed(modifiers)
-                                && !self.is_secure
-                            {
-                                if modifiers.shift() {
-                                    state
+                                        .select_left_by_words(&self.value),
+                                    (false, true) => state
                                         .cursor
-                                        .select_right_by_words(&self.value);
-                                } else {
-                                    state
+                                        .move_right_by_words(&self.value),
+                                    (false, false) => state
                                         .cursor
-                                        .move_right_by_words(&self.value);
+                                        .move_left_by_words(&self.value),
                                 }
-                            } else if modifiers.shift() {
-                                state.cursor.select_right(&self.value);
                             } else {
-                                state.cursor.move_right(&self.value);
+                                match (modifiers.shift(), forwards) {
+                                    (true, true) => {
+                                        state.cursor.select_right(&self.value);
+                                    }
+                                    (true, false) => {
+                                        state.cursor.select_left(&self.value);
+                                    }
+                                    (false, true) => {
+                                        state.cursor.move_right(&self.value);
+                                    }
+                                    (false, false) => {
+                                        state.cursor.move_left(&self.value);
+                                    }
+                                }
                             }
                         }
                         keyboard::Key::Named(key::Named::Home) => {
@@ -1160,6 +1234,7 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    direction: layout::Direction,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1194,6 +1269,7 @@ impl<P: text::Paragraph> State<P> {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            direction: layout::Direction::default(),
         }
     }
 
@@ -1307,11 +1383,12 @@ fn offset<P: text::Paragraph>(
             &state.value,
             text_bounds,
             focus_position,
+            state.direction,
         );
 
         offset
     } else {
-        0.0
+        alignment_offset(&state.value, text_bounds, state.direction)
     }
 }
 
@@ -1319,6 +1396,7 @@ fn measure_cursor_and_scroll_offset(
     paragraph: &impl text::Paragraph,
     text_bounds: Rectangle,
     cursor_index: usize,
+    direction: layout::Direction,
 ) -> (f32, f32) {
     let grapheme_position = paragraph
         .grapheme_position(0, cursor_index)
@@ -1326,9 +1404,56 @@ fn measure_cursor_and_scroll_offset(
 
     let offset = ((grapheme_position.x + 5.0) - text_bounds.width).max(0.0);
 
+    let offset = if offset > 0.0 {
+        offset
+    } else {
+        alignment_offset(paragraph, text_bounds, direction)
+    };
+
     (grapheme_position.x, offset)
 }
 
+/// Computes the offset that aligns the text of a right-to-left [`TextInput`]
+/// to the right, as long as it fits.
+fn alignment_offset(
+    paragraph: &impl text::Paragraph,
+    text_bounds: Rectangle,
+    direction: layout::Direction,
+) -> f32 {
+    if direction.is_rtl() {
+        -(text_bounds.width - paragraph.min_width() - 1.0).max(0.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns true if the text around the cursor at the given index flows from
+/// right to left.
+///
+/// Arrow keys move the cursor visually; therefore, they are swapped inside
+/// right-to-left text.
+fn is_rtl_at(
+    paragraph: &impl text::Paragraph,
+    index: usize,
+    len: usize,
+) -> bool {
+    let (before, after) = if index < len {
+        (index, index + 1)
+    } else if index > 0 {
+        (index - 1, index)
+    } else {
+        return false;
+    };
+
+    match (
+        paragraph.grapheme_position(0, before),
+        paragraph.grapheme_position(0, after),
+    ) {
+        (Some(before), Some(after)) => after.x < before.x,
+        _ => false,
+    }
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1430,6 +1555,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`TextInput`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -1453,7 +1602,7 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         selection: palette.primary.weak.color,
     };
 
-    match status {
+    let appearance = match status {
         Status::Active => active,
         Status::Hovered => Appearance {
             border: Border {
@@ -1474,5 +1623,33 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
             value: active.placeholder,
             ..active
         },
+    };
+
+    theme.overridden(appearance, status)
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active => theme::Status::Active,
+            Status::Hovered => theme::Status::Hovered,
+            Status::Focused => theme::Status::Focused,
+            Status::Disabled => theme::Status::Disabled,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.text_input.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            background: properties.background(self.background),
+            border: properties.border(self.border),
+            value: properties.text_color(self.value),
+            ..self
+        }
     }
 }
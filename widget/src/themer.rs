@@ -198,8 +198,9 @@ where
                 &mut self,
                 renderer: &Renderer,
                 bounds: Size,
+                direction: layout::Direction,
             ) -> layout::Node {
-                self.content.layout(renderer, bounds)
+                self.content.layout(renderer, bounds, direction)
             }
 
             fn draw(
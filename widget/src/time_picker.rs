@@ -0,0 +1,763 @@
+//! Pick a time of the day.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::menu::Metrics;
+use crate::picker::{self, Status};
+
+use std::fmt;
+
+/// A time of the day, with minute precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// Creates a new [`Time`] from the given hour and minute.
+    ///
+    /// Returns `None` if the hour or the minute are out of range.
+    pub fn from_hm(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A field that opens a panel to pick a [`Time`] when pressed.
+///
+/// The panel shows the hour and the minute of the [`Time`], which can be
+/// changed with their buttons or the mouse wheel. Every change produces a
+/// message.
+///
+/// While open, the left and right arrow keys switch between the hour and
+/// the minute, the up and down arrow keys change them, and `Enter` or
+/// `Escape` close the panel. Once focused, the panel can be opened with
+/// `Down`, `Enter` or `Space`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AlarmChanged(Time),
+/// }
+///
+/// fn view<'a>(alarm: Option<Time>) -> Element<'a, Message> {
+///     time_picker(alarm, Message::AlarmChanged)
+///         .placeholder("Alarm")
+///         .minute_step(5)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    selected: Option<Time>,
+    on_select: Box<dyn Fn(Time) -> Message + 'a>,
+    placeholder: String,
+    minute_step: u32,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: picker::Style<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the field of a [`TimePicker`].
+    pub const DEFAULT_PADDING: Padding = crate::button::DEFAULT_PADDING;
+
+    /// Creates a new [`TimePicker`] with the selected [`Time`], if any,
+    /// and the function that produces a message when the [`Time`] changes.
+    pub fn new(
+        selected: Option<Time>,
+        on_select: impl Fn(Time) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: picker::DefaultStyle + 'a,
+    {
+        Self {
+            id: None,
+            selected,
+            on_select: Box::new(on_select),
+            placeholder: String::new(),
+            minute_step: 1,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TimePicker`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the placeholder of the [`TimePicker`], displayed when no
+    /// [`Time`] is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the amount of minutes changed by every step of the
+    /// [`TimePicker`].
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    /// Sets the width of the field of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the field of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> picker::Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`TimePicker`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: picker::Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            picker::Catalog::resolve(theme, &class, status).unwrap_or_else(
+                || picker::DefaultStyle::default_style(theme, status),
+            )
+        });
+        self
+    }
+
+    /// Opens the panel, starting from the selected [`Time`].
+    fn open(&self, state: &mut State) {
+        state.is_open = true;
+        state.time = self.selected.unwrap_or_default();
+        state.column = Column::Hour;
+    }
+}
+
+/// A column of the panel of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Column {
+    #[default]
+    Hour,
+    Minute,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_open: bool,
+    is_focused: bool,
+    time: Time,
+    column: Column,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+
+        picker::layout_field::<Renderer>(
+            limits,
+            self.width,
+            self.padding,
+            &metrics,
+            &["00:00", &self.placeholder],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Presses over the panel leave the cursor unavailable and
+                // keep the focus
+                if let Some(position) = cursor.position() {
+                    state.is_focused = layout.bounds().contains(position);
+                }
+
+                if cursor.is_over(layout.bounds()) {
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        self.open(state);
+                    }
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        key::Named::ArrowDown
+                        | key::Named::Enter
+                        | key::Named::Space,
+                    ),
+                ..
+            }) if state.is_focused && !state.is_open => {
+                self.open(state);
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.is_open {
+            Status::Opened
+        } else if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let selected = self.selected.map(|time| time.to_string());
+
+        picker::draw_field(
+            renderer,
+            &(self.style)(theme, status),
+            bounds,
+            self.padding,
+            &Metrics::new(renderer, self.text_size, self.font),
+            selected.as_deref().unwrap_or(&self.placeholder),
+            selected.is_none(),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Clock {
+            picker: self,
+            state,
+            field: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+/// The padding of the panel of a [`TimePicker`].
+const PANEL_PADDING: f32 = 6.0;
+
+/// The panel of an open [`TimePicker`].
+struct Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    picker: &'b TimePicker<'a, Message, Theme, Renderer>,
+    state: &'b mut State,
+    field: Rectangle,
+}
+
+/// A part of a [`Clock`] that can be pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Increment(Column),
+    Value(Column),
+    Decrement(Column),
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        Metrics::new(renderer, self.picker.text_size, self.picker.font)
+    }
+
+    /// Returns the size of a cell of the panel.
+    fn cell(metrics: &Metrics<Renderer::Font>) -> f32 {
+        (metrics.line_height * 1.75).round()
+    }
+
+    /// Returns the bounds of the given column of the panel.
+    fn column_bounds(panel: Rectangle, cell: f32, column: Column) -> Rectangle {
+        let offset = match column {
+            Column::Hour => 0.0,
+            Column::Minute => 1.5 * cell,
+        };
+
+        Rectangle {
+            x: panel.x + PANEL_PADDING + offset,
+            y: panel.y + PANEL_PADDING,
+            width: cell,
+            height: 3.0 * cell,
+        }
+    }
+
+    /// Returns the bounds of the given row of a column.
+    fn row_bounds(column: Rectangle, row: usize) -> Rectangle {
+        let height = column.height / 3.0;
+
+        Rectangle {
+            y: column.y + row as f32 * height,
+            height,
+            ..column
+        }
+    }
+
+    fn target(
+        &self,
+        panel: Rectangle,
+        cell: f32,
+        cursor: mouse::Cursor,
+    ) -> Option<Target> {
+        [Column::Hour, Column::Minute]
+            .into_iter()
+            .find_map(|column| {
+                let bounds = Self::column_bounds(panel, cell, column);
+                let position = cursor.position_over(bounds)?;
+
+                Some(match ((position.y - bounds.y) / cell) as usize {
+                    0 => Target::Increment(column),
+                    1 => Target::Value(column),
+                    _ => Target::Decrement(column),
+                })
+            })
+    }
+
+    /// Changes the given column by a step in the given direction and
+    /// publishes the resulting [`Time`].
+    fn change(
+        &mut self,
+        column: Column,
+        direction: i64,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let time = &mut self.state.time;
+
+        match column {
+            Column::Hour => {
+                time.hour =
+                    (i64::from(time.hour) + direction).rem_euclid(24) as u32;
+            }
+            Column::Minute => {
+                let step = self.picker.minute_step;
+                let steps = i64::from(60_u32.div_ceil(step));
+                let index = i64::from(time.minute / step) + direction;
+
+                time.minute = index.rem_euclid(steps) as u32 * step;
+            }
+        }
+
+        self.state.column = column;
+        shell.publish((self.picker.on_select)(self.state.time));
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Clock<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _direction: layout::Direction,
+    ) -> layout::Node {
+        let cell = Self::cell(&self.metrics(renderer));
+
+        let size = Size::new(
+            2.5 * cell + 2.0 * PANEL_PADDING,
+            3.0 * cell + 2.0 * PANEL_PADDING,
+        );
+
+        layout::Node::new(size)
+            .move_to(picker::panel_position(self.field, size, bounds))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let panel = layout.bounds();
+        let cell = Self::cell(&self.metrics(renderer));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(panel) {
+                    if cursor.is_over(self.field) {
+                        return event::Status::Ignored;
+                    }
+
+                    self.state.is_open = false;
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+
+                match self.target(panel, cell, cursor) {
+                    Some(Target::Increment(column)) => {
+                        self.change(column, 1, shell);
+                    }
+                    Some(Target::Decrement(column)) => {
+                        self.change(column, -1, shell);
+                    }
+                    Some(Target::Value(column)) => {
+                        self.state.column = column;
+                    }
+                    None => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(panel) =>
+            {
+                let (mouse::ScrollDelta::Lines { y, .. }
+                | mouse::ScrollDelta::Pixels { y, .. }) = delta;
+
+                if let Some(
+                    Target::Increment(column)
+                    | Target::Value(column)
+                    | Target::Decrement(column),
+                ) = self.target(panel, cell, cursor)
+                {
+                    if y != 0.0 {
+                        self.change(column, y.signum() as i64, shell);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                let column = self.state.column;
+
+                match key {
+                    key::Named::ArrowLeft => {
+                        self.state.column = Column::Hour;
+                    }
+                    key::Named::ArrowRight => {
+                        self.state.column = Column::Minute;
+                    }
+                    key::Named::ArrowUp => self.change(column, 1, shell),
+                    key::Named::ArrowDown => self.change(column, -1, shell),
+                    key::Named::Enter | key::Named::Escape => {
+                        self.state.is_open = false;
+                        shell.invalidate_layout();
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if cursor.is_over(panel) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cell = Self::cell(&self.metrics(renderer));
+
+        if self.target(layout.bounds(), cell, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = (self.picker.style)(theme, Status::Opened);
+        let metrics = self.metrics(renderer);
+        let cell = Self::cell(&metrics);
+        let panel = layout.bounds();
+        let hovered = self.target(panel, cell, cursor);
+
+        picker::draw_panel(renderer, &appearance, panel);
+
+        for (column, value) in [
+            (Column::Hour, self.state.time.hour),
+            (Column::Minute, self.state.time.minute),
+        ] {
+            let bounds = Self::column_bounds(panel, cell, column);
+
+            for (row, (target, content)) in [
+                (Target::Increment(column), String::from("▴")),
+                (Target::Value(column), format!("{value:02}")),
+                (Target::Decrement(column), String::from("▾")),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let bounds = Self::row_bounds(bounds, row);
+
+                let is_focused = target == Target::Value(self.state.column);
+
+                let (background, color) = if is_focused {
+                    (
+                        Some(appearance.selected_background),
+                        appearance.selected_text_color,
+                    )
+                } else if hovered == Some(target) {
+                    (Some(appearance.hovered_background), appearance.text_color)
+                } else {
+                    (None, appearance.text_color)
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border::rounded(appearance.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+
+                metrics.draw(
+                    renderer,
+                    &content,
+                    bounds.center(),
+                    alignment::Horizontal::Center,
+                    color,
+                    panel,
+                );
+            }
+        }
+
+        metrics.draw(
+            renderer,
+            ":",
+            Point::new(panel.x + PANEL_PADDING + 1.25 * cell, panel.center_y()),
+            alignment::Horizontal::Center,
+            appearance.text_color,
+            panel,
+        );
+    }
+}
@@ -1,13 +1,19 @@
 //! Show toggle controls using togglers.
 use crate::core::alignment;
+use crate::core::animation::{self, Interpolate, StatusTransition};
 use crate::core::event;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::theme::{self, Class};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
@@ -37,6 +43,7 @@ pub struct Toggler<
 > where
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     is_toggled: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: Option<String>,
@@ -76,6 +83,7 @@ where
         F: 'a + Fn(bool) -> Message,
     {
         Toggler {
+            id: None,
             is_toggled,
             on_toggle: Box::new(f),
             label: label.into(),
@@ -91,6 +99,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Toggler`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the size of the [`Toggler`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = size.into().0;
@@ -152,6 +166,62 @@ where
         self.style = Box::new(style);
         self
     }
+
+    /// Sets the style [`Class`] of the [`Toggler`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn status<P: text::Paragraph>(
+        &self,
+        state: &State<P>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_toggled = self.is_toggled;
+
+        if state.is_focused {
+            Status::Focused { is_toggled }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_toggled }
+        } else {
+            Status::Active { is_toggled }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+    transition: StatusTransition<Status>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -160,11 +230,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -188,12 +258,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -213,9 +282,23 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -224,9 +307,16 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.status(state, layout, cursor);
+
+        state.transition.update(&event, status, shell);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over {
@@ -237,6 +327,14 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter | key::Named::Space),
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_toggled));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -273,6 +371,7 @@ where
         /// between the background Quad and foreground Quad.
         const SPACE_RATIO: f32 = 0.05;
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
@@ -283,26 +382,20 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance::default(),
                 viewport,
             );
         }
 
         let bounds = toggler_layout.bounds();
-        let is_mouse_over = cursor.is_over(layout.bounds());
-
-        let status = if is_mouse_over {
-            Status::Hovered {
-                is_toggled: self.is_toggled,
-            }
-        } else {
-            Status::Active {
-                is_toggled: self.is_toggled,
-            }
-        };
+        let status = self.status(state, layout, cursor);
 
-        let appearance = (self.style)(theme, status);
+        let appearance = state.transition.appearance(
+            status,
+            |status| (self.style)(theme, status),
+            |appearance| appearance.transition,
+        );
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height;
@@ -381,6 +474,11 @@ pub enum Status {
         /// Indicates whether the [`Toggler`] is toggled.
         is_toggled: bool,
     },
+    /// The [`Toggler`] has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Toggler`] is toggled.
+        is_toggled: bool,
+    },
 }
 
 /// The appearance of a toggler.
@@ -398,6 +496,34 @@ pub struct Appearance {
     pub foreground_border_width: f32,
     /// The [`Color`] of the foreground border of the toggler.
     pub foreground_border_color: Color,
+    /// The [`Transition`] used to animate into this [`Appearance`], if any.
+    ///
+    /// [`Transition`]: animation::Transition
+    pub transition: Option<animation::Transition>,
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            background_border_width: self
+                .background_border_width
+                .interpolate(&other.background_border_width, t)
+                .max(0.0),
+            background_border_color: self
+                .background_border_color
+                .interpolate(&other.background_border_color, t),
+            foreground: self.foreground.interpolate(&other.foreground, t),
+            foreground_border_width: self
+                .foreground_border_width
+                .interpolate(&other.foreground_border_width, t)
+                .max(0.0),
+            foreground_border_color: self
+                .foreground_border_color
+                .interpolate(&other.foreground_border_color, t),
+            transition: other.transition,
+        }
+    }
 }
 
 /// The style of a [`Toggler`].
@@ -415,6 +541,30 @@ impl DefaultStyle for Theme {
     }
 }
 
+/// The styles of a [`Toggler`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] knows about the `default` class, on top of any [`Classes`]
+/// registered in it.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        if let Some(appearance) = self.resolve_class(class, status) {
+            return Some(appearance);
+        }
+
+        match class.as_str() {
+            "default" => Some(default(self, status)),
+            _ => None,
+        }
+    }
+}
+
 impl DefaultStyle for Appearance {
     fn default_style(&self, _status: Status) -> Appearance {
         *self
@@ -426,7 +576,9 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
     let palette = theme.extended_palette();
 
     let background = match status {
-        Status::Active { is_toggled } | Status::Hovered { is_toggled } => {
+        Status::Active { is_toggled }
+        | Status::Hovered { is_toggled }
+        | Status::Focused { is_toggled } => {
             if is_toggled {
                 palette.primary.strong.color
             } else {
@@ -443,7 +595,7 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
                 palette.background.base.color
             }
         }
-        Status::Hovered { is_toggled } => {
+        Status::Hovered { is_toggled } | Status::Focused { is_toggled } => {
             if is_toggled {
                 Color {
                     a: 0.5,
@@ -455,12 +607,51 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
         }
     };
 
-    Appearance {
+    let (background_border_width, background_border_color) = match status {
+        Status::Focused { .. } => (2.0, palette.background.base.text),
+        Status::Active { .. } | Status::Hovered { .. } => {
+            (0.0, Color::TRANSPARENT)
+        }
+    };
+
+    let appearance = Appearance {
         background,
         foreground,
         foreground_border_width: 0.0,
         foreground_border_color: Color::TRANSPARENT,
-        background_border_width: 0.0,
-        background_border_color: Color::TRANSPARENT,
+        background_border_width,
+        background_border_color,
+        transition: None,
+    };
+
+    theme.overridden(appearance, status)
+}
+
+impl From<Status> for theme::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Active { .. } => theme::Status::Active,
+            Status::Hovered { .. } => theme::Status::Hovered,
+            Status::Focused { .. } => theme::Status::Focused,
+        }
+    }
+}
+
+impl theme::Overridable for Appearance {
+    fn select(overrides: &theme::Overrides) -> Option<&theme::Override> {
+        overrides.toggler.as_ref()
+    }
+
+    fn apply(self, properties: theme::Properties) -> Self {
+        Appearance {
+            background: properties.background.unwrap_or(self.background),
+            background_border_width: properties
+                .border_width
+                .unwrap_or(self.background_border_width),
+            background_border_color: properties
+                .border_color
+                .unwrap_or(self.background_border_color),
+            ..self
+        }
     }
 }
@@ -320,7 +320,12 @@ impl<'a, 'b, Message, Theme, Renderer>
 where
     Renderer: text::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        direction: layout::Direction,
+    ) -> layout::Node {
         let viewport = Rectangle::with_size(bounds);
 
         let tooltip_layout = self.tooltip.as_widget().layout(
@@ -332,7 +337,8 @@ where
                     .then(|| viewport.size())
                     .unwrap_or(Size::INFINITY),
             )
-            .shrink(Padding::new(self.padding)),
+            .shrink(Padding::new(self.padding))
+            .with_direction(direction),
         );
 
         let text_bounds = tooltip_layout.bounds();
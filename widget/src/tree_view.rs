@@ -0,0 +1,1244 @@
+//! Display hierarchical data with nodes that can be expanded and selected.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::theme::Class;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::menu::Metrics;
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<
+    'a,
+    Id,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    id: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    children: Vec<Node<'a, Id, Message, Theme, Renderer>>,
+    has_children: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Id, Message, Theme, Renderer> Node<'a, Id, Message, Theme, Renderer> {
+    /// Creates a new [`Node`] with the given identifier and content.
+    pub fn new(
+        id: Id,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            id,
+            content: content.into(),
+            children: Vec::new(),
+            has_children: false,
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self.has_children = true;
+        self
+    }
+
+    /// Extends the [`Node`] with the given children.
+    pub fn extend(self, children: impl IntoIterator<Item = Self>) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Sets whether the [`Node`] has children, even if they have not been
+    /// provided.
+    ///
+    /// This can be used to load the children of a [`Node`] lazily, once it
+    /// is expanded.
+    pub fn has_children(mut self, has_children: bool) -> Self {
+        self.has_children = has_children || !self.children.is_empty();
+        self
+    }
+
+    /// Sets whether the children of the [`Node`] are displayed.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] is selected.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+}
+
+/// How the nodes of a [`TreeView`] can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// A single node can be selected.
+    #[default]
+    Single,
+    /// Many nodes can be selected by holding `Ctrl` or `Shift`.
+    Multiple,
+}
+
+/// A hierarchy of nodes that can be expanded, collapsed and selected.
+///
+/// The expansion and the selection of the nodes are owned by the
+/// application. A [`TreeView`] produces a message when a node is toggled
+/// and when the selection changes, and the application decides what to
+/// display next. A node can declare that it [has children] without
+/// providing them, which allows loading them lazily when it is expanded.
+///
+/// Once a [`TreeView`] has been clicked or focused, the arrow keys move the selection
+/// up and down, expand a node or move to its first child (`Right`), and
+/// collapse a node or move to its parent (`Left`).
+///
+/// [has children]: Node::has_children
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::renderer::Renderer>;
+/// use iced::widget::tree_view::Node;
+/// use iced::widget::{text, tree_view};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(u32, bool),
+///     Selected(Vec<u32>),
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     tree_view([
+///         Node::new(0, text("src"))
+///             .expanded(true)
+///             .push(Node::new(1, text("main.rs")).selected(true))
+///             .push(Node::new(2, text("lib.rs"))),
+///         Node::new(3, text("target")).has_children(true),
+///     ])
+///     .on_toggle(Message::Toggled)
+///     .on_select(Message::Selected)
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<
+    'a,
+    Id,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    entries: Vec<Entry<'a, Id, Message, Theme, Renderer>>,
+    rows: Vec<usize>,
+    on_toggle: Option<Box<dyn Fn(Id, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<Id>) -> Message + 'a>>,
+    selection_mode: SelectionMode,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: Style<'a, Theme>,
+}
+
+/// A [`Node`] of a [`TreeView`] in pre-order.
+struct Entry<'a, Id, Message, Theme, Renderer> {
+    id: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    has_children: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Id, Message, Theme, Renderer>
+    TreeView<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the rows of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        bottom: 2.0,
+        right: 5.0,
+        left: 5.0,
+    };
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(
+        roots: impl IntoIterator<Item = Node<'a, Id, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Theme: DefaultStyle + 'a,
+    {
+        let mut entries = Vec::new();
+
+        for root in roots {
+            flatten(root, 0, None, &mut entries);
+        }
+
+        let mut visible = vec![false; entries.len()];
+        let mut rows = Vec::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            visible[index] = match entry.parent {
+                Some(parent) => visible[parent] && entries[parent].is_expanded,
+                None => true,
+            };
+
+            if visible[index] {
+                rows.push(index);
+            }
+        }
+
+        Self {
+            id: None,
+            entries,
+            rows,
+            on_toggle: None,
+            on_select: None,
+            selection_mode: SelectionMode::default(),
+            width: Length::Fill,
+            indent: 16.0,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Box::new(Theme::default_style),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TreeView`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a node is expanded or
+    /// collapsed.
+    ///
+    /// The nodes cannot be toggled if this is not set.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(Id, bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that should be produced when the selection changes.
+    ///
+    /// The closure receives the identifiers of all the selected nodes. The
+    /// nodes cannot be selected if this is not set.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Vec<Id>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the [`SelectionMode`] of the [`TreeView`].
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the rows of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the toggles of the [`TreeView`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the toggles of the [`TreeView`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> Appearance + 'a,
+    ) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Sets the style [`Class`] of the [`TreeView`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
+    fn toggle_bounds(&self, entry: usize, row: Rectangle) -> Rectangle {
+        Rectangle {
+            x: row.x
+                + self.padding.left
+                + self.entries[entry].depth as f32 * self.indent,
+            width: self.indent,
+            ..row
+        }
+    }
+
+    fn toggle(&self, entry: usize, shell: &mut Shell<'_, Message>) -> bool {
+        let entry = &self.entries[entry];
+
+        match &self.on_toggle {
+            Some(on_toggle) if entry.has_children => {
+                shell.publish(on_toggle(entry.id.clone(), !entry.is_expanded));
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn select(
+        &self,
+        state: &mut State<Id>,
+        row: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let entry = self.rows[row];
+        let id = self.entries[entry].id.clone();
+
+        let selection = match self.selection_mode {
+            SelectionMode::Multiple if state.modifiers.shift() => {
+                let anchor = state
+                    .anchor
+                    .as_ref()
+                    .and_then(|anchor| self.row_of(anchor))
+                    .unwrap_or(row);
+
+                let range = anchor.min(row)..=anchor.max(row);
+
+                self.rows[range]
+                    .iter()
+                    .map(|&entry| self.entries[entry].id.clone())
+                    .collect()
+            }
+            SelectionMode::Multiple if state.modifiers.command() => {
+                state.anchor = Some(id.clone());
+
+                let mut selection: Vec<Id> = self
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.is_selected && entry.id != id)
+                    .map(|entry| entry.id.clone())
+                    .collect();
+
+                if !self.entries[entry].is_selected {
+                    selection.push(id.clone());
+                }
+
+                selection
+            }
+            _ => {
+                state.anchor = Some(id.clone());
+
+                vec![id.clone()]
+            }
+        };
+
+        state.focused = Some(id);
+        shell.publish(on_select(selection));
+    }
+
+    fn row_of(&self, id: &Id) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|&entry| self.entries[entry].id == *id)
+    }
+
+    fn focused_row(&self, state: &State<Id>) -> Option<usize> {
+        state
+            .focused
+            .as_ref()
+            .and_then(|id| self.row_of(id))
+            .or_else(|| {
+                self.rows
+                    .iter()
+                    .position(|&entry| self.entries[entry].is_selected)
+            })
+    }
+
+    fn on_key_press(
+        &self,
+        state: &mut State<Id>,
+        key: &keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if self.rows.is_empty() {
+            return false;
+        }
+
+        let Some(row) = self.focused_row(state) else {
+            self.select(state, 0, shell);
+
+            return true;
+        };
+
+        let entry = self.rows[row];
+
+        match key {
+            keyboard::Key::Named(key::Named::ArrowUp) => {
+                self.select(state, row.saturating_sub(1), shell);
+            }
+            keyboard::Key::Named(key::Named::ArrowDown) => {
+                self.select(state, (row + 1).min(self.rows.len() - 1), shell);
+            }
+            keyboard::Key::Named(key::Named::Home) => {
+                self.select(state, 0, shell);
+            }
+            keyboard::Key::Named(key::Named::End) => {
+                self.select(state, self.rows.len() - 1, shell);
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                if self.entries[entry].is_expanded {
+                    if self.rows.get(row + 1).is_some_and(|&next| {
+                        self.entries[next].parent == Some(entry)
+                    }) {
+                        self.select(state, row + 1, shell);
+                    }
+                } else {
+                    let _ = self.toggle(entry, shell);
+                }
+            }
+            keyboard::Key::Named(key::Named::ArrowLeft) => {
+                if self.entries[entry].is_expanded {
+                    let _ = self.toggle(entry, shell);
+                } else if let Some(parent) = self.entries[entry]
+                    .parent
+                    .and_then(|parent| self.row_of(&self.entries[parent].id))
+                {
+                    self.select(state, parent, shell);
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+fn flatten<'a, Id, Message, Theme, Renderer>(
+    node: Node<'a, Id, Message, Theme, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    entries: &mut Vec<Entry<'a, Id, Message, Theme, Renderer>>,
+) {
+    let index = entries.len();
+
+    entries.push(Entry {
+        id: node.id,
+        content: node.content,
+        depth,
+        parent,
+        has_children: node.has_children,
+        is_expanded: node.is_expanded,
+        is_selected: node.is_selected,
+    });
+
+    for child in node.children {
+        flatten(child, depth + 1, Some(index), entries);
+    }
+}
+
+#[derive(Debug)]
+struct State<Id> {
+    /// The identifiers of the nodes, matching the children of the tree.
+    ids: Vec<Id>,
+    /// The node where keyboard navigation starts.
+    focused: Option<Id>,
+    /// The node where range selections start.
+    anchor: Option<Id>,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<Id> Default for State<Id> {
+    fn default() -> Self {
+        Self {
+            ids: Vec::new(),
+            focused: None,
+            anchor: None,
+            is_focused: false,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl<Id> operation::Focusable for State<Id> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'a, Id, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Id> {
+            ids: self.entries.iter().map(|entry| entry.id.clone()).collect(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.entries
+            .iter()
+            .map(|entry| Tree::new(&entry.content))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        let mut old: Vec<Option<(Id, Tree)>> = std::mem::take(&mut state.ids)
+            .into_iter()
+            .zip(std::mem::take(&mut tree.children))
+            .map(Some)
+            .collect();
+
+        // Reuse the state of every node with the same identifier
+        tree.children = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let matches = |old: &Option<(Id, Tree)>| {
+                    old.as_ref().is_some_and(|(id, _)| *id == entry.id)
+                };
+
+                let reused = if old.get(index).is_some_and(matches) {
+                    old[index].take()
+                } else {
+                    old.iter_mut()
+                        .find(|old| matches(old))
+                        .and_then(Option::take)
+                };
+
+                match reused {
+                    Some((_, mut tree)) => {
+                        tree.diff(&entry.content);
+                        tree
+                    }
+                    None => Tree::new(&entry.content),
+                }
+            })
+            .collect();
+
+        state.ids = self.entries.iter().map(|entry| entry.id.clone()).collect();
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|&index| {
+                let entry = &self.entries[index];
+                let offset =
+                    self.padding.left + (entry.depth + 1) as f32 * self.indent;
+
+                let content_limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (max_width - offset - self.padding.right).max(0.0),
+                        f32::INFINITY,
+                    ),
+                )
+                .with_direction(limits.direction());
+
+                let content = entry
+                    .content
+                    .as_widget()
+                    .layout(
+                        &mut tree.children[index],
+                        renderer,
+                        &content_limits,
+                    )
+                    .move_to(Point::new(offset, self.padding.top));
+
+                let size = content.size();
+                let height = size.height + self.padding.vertical();
+
+                width = width.max(offset + size.width + self.padding.right);
+
+                let row = layout::Node::with_children(
+                    Size::new(max_width, height),
+                    vec![content],
+                )
+                .move_to(Point::new(0.0, y));
+
+                y += height;
+
+                row
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(width, y));
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+        operation.focusable(state, self.id.as_ref());
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for (&index, row) in self.rows.iter().zip(layout.children()) {
+                self.entries[index].content.as_widget().operate(
+                    &mut tree.children[index],
+                    row.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+
+        for (&index, row) in self.rows.iter().zip(layout.children()) {
+            status = status.merge(
+                self.entries[index].content.as_widget_mut().on_event(
+                    &mut tree.children[index],
+                    event.clone(),
+                    row.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ),
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                let Some((row, bounds)) = layout
+                    .children()
+                    .enumerate()
+                    .map(|(row, layout)| (row, layout.bounds()))
+                    .find(|(_, bounds)| cursor.is_over(*bounds))
+                else {
+                    return status;
+                };
+
+                let entry = self.rows[row];
+
+                if cursor.is_over(self.toggle_bounds(entry, bounds))
+                    && self.toggle(entry, shell)
+                {
+                    state.focused = Some(self.entries[entry].id.clone());
+
+                    return event::Status::Captured;
+                }
+
+                self.select(state, row, shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if state.is_focused
+                    && status == event::Status::Ignored
+                    && self.on_key_press(state, &key, shell) =>
+            {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(layout.children())
+            .map(|(&index, row)| {
+                let entry = &self.entries[index];
+
+                if entry.has_children
+                    && self.on_toggle.is_some()
+                    && cursor.is_over(self.toggle_bounds(index, row.bounds()))
+                {
+                    return mouse::Interaction::Pointer;
+                }
+
+                entry.content.as_widget().mouse_interaction(
+                    &tree.children[index],
+                    row.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Id>>();
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
+        let appearance = (self.style)(theme, status);
+        let metrics = Metrics::new(renderer, self.text_size, self.font);
+
+        for (&index, row) in self.rows.iter().zip(layout.children()) {
+            let bounds = row.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let entry = &self.entries[index];
+
+            let background = if entry.is_selected {
+                Some(appearance.selected_background)
+            } else if cursor.is_over(bounds) {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(appearance.border_radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            // Indentation guides of every ancestor level
+            for depth in 0..entry.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (bounds.x
+                                + self.padding.left
+                                + (depth as f32 + 0.5) * self.indent)
+                                .round(),
+                            y: bounds.y,
+                            width: 1.0,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    appearance.guide,
+                );
+            }
+
+            if entry.has_children {
+                metrics.draw(
+                    renderer,
+                    if entry.is_expanded { "▾" } else { "▸" },
+                    self.toggle_bounds(index, bounds).center(),
+                    alignment::Horizontal::Center,
+                    appearance.toggle,
+                    *viewport,
+                );
+            }
+
+            let style = if entry.is_selected {
+                renderer::Style {
+                    text_color: appearance.selected_text_color,
+                }
+            } else {
+                *style
+            };
+
+            entry.content.as_widget().draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                &style,
+                row.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let rows = &self.rows;
+        let mut rows_layout = layout.children();
+
+        let children = self
+            .entries
+            .iter_mut()
+            .zip(&mut tree.children)
+            .enumerate()
+            .filter(|(index, _)| rows.binary_search(index).is_ok())
+            .filter_map(|(_, (entry, tree))| {
+                let row = rows_layout.next()?;
+
+                entry.content.as_widget_mut().overlay(
+                    tree,
+                    row.children().next().unwrap(),
+                    renderer,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer>
+    From<TreeView<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Id, Message, Theme, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// The possible status of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TreeView`] can be interacted with.
+    Active,
+    /// The [`TreeView`] has keyboard focus.
+    Focused,
+}
+
+/// The appearance of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Background`] of a selected row.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected row.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a hovered row, if any.
+    pub hovered_background: Option<Background>,
+    /// The border radius of the rows.
+    pub border_radius: f32,
+    /// The [`Color`] of the expand and collapse toggles.
+    pub toggle: Color,
+    /// The [`Color`] of the indentation guides.
+    pub guide: Color,
+}
+
+/// The style of a [`TreeView`].
+pub type Style<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The default style of a [`TreeView`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`TreeView`].
+    fn default_style(&self, status: Status) -> Appearance;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style(&self, status: Status) -> Appearance {
+        default(self, status)
+    }
+}
+
+/// The styles of a [`TreeView`] that can be referred to by [`Class`].
+///
+/// The [`Theme`] only knows about the [`Classes`] registered in it or in
+/// scope.
+///
+/// [`Classes`]: crate::core::theme::Classes
+pub trait Catalog: DefaultStyle {
+    /// Resolves the given [`Class`] to an [`Appearance`], if known.
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance>;
+}
+
+impl Catalog for Theme {
+    fn resolve(&self, class: &Class, status: Status) -> Option<Appearance> {
+        self.resolve_class(class, status)
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style(&self, _status: Status) -> Appearance {
+        *self
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let base = Appearance {
+        selected_background: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+        hovered_background: Some(palette.background.weak.color.into()),
+        border_radius: 2.0,
+        toggle: palette.background.base.text,
+        guide: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Focused => Appearance {
+            selected_background: palette.primary.base.color.into(),
+            selected_text_color: palette.primary.base.text,
+            ..base
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::Space;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggle(u32, bool),
+        Select(Vec<u32>),
+    }
+
+    type Node<'a> = super::Node<'a, u32, Message, Theme, Null>;
+    type TreeView<'a> = super::TreeView<'a, u32, Message, Theme, Null>;
+
+    fn node<'a>(id: u32) -> Node<'a> {
+        Node::new(id, Space::with_height(10))
+    }
+
+    /// A tree with the rows `1`, `2`, `3`, `4` and `5`, where `1` is
+    /// expanded with `2` and `3` as children, and `4` is collapsed with its
+    /// children not loaded yet.
+    fn tree_view<'a>(selected: &[u32]) -> TreeView<'a> {
+        let node = |id| node(id).selected(selected.contains(&id));
+
+        TreeView::new([
+            node(1).push(node(2)).push(node(3)).expanded(true),
+            node(4).has_children(true),
+            node(5),
+        ])
+        .on_toggle(Message::Toggle)
+        .on_select(Message::Select)
+        .selection_mode(SelectionMode::Multiple)
+    }
+
+    fn select(
+        tree_view: &TreeView<'_>,
+        state: &mut State<u32>,
+        row: usize,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        tree_view.select(state, row, &mut shell);
+
+        messages
+    }
+
+    fn press(
+        tree_view: &TreeView<'_>,
+        state: &mut State<u32>,
+        key: key::Named,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        assert!(tree_view.on_key_press(
+            state,
+            &keyboard::Key::Named(key),
+            &mut shell
+        ));
+
+        messages
+    }
+
+    #[test]
+    fn shift_selects_a_range_of_rows() {
+        let tree_view = tree_view(&[]);
+        let mut state = State::default();
+
+        assert_eq!(
+            select(&tree_view, &mut state, 1),
+            [Message::Select(vec![2])]
+        );
+
+        state.modifiers = keyboard::Modifiers::SHIFT;
+
+        assert_eq!(
+            select(&tree_view, &mut state, 3),
+            [Message::Select(vec![2, 3, 4])]
+        );
+        assert_eq!(
+            select(&tree_view, &mut state, 0),
+            [Message::Select(vec![1, 2])]
+        );
+    }
+
+    #[test]
+    fn command_toggles_the_selection_of_a_row() {
+        let tree_view = self::tree_view(&[2]);
+        let mut state = State {
+            modifiers: keyboard::Modifiers::COMMAND,
+            ..State::default()
+        };
+
+        assert_eq!(
+            select(&tree_view, &mut state, 4),
+            [Message::Select(vec![2, 5])]
+        );
+
+        let tree_view = self::tree_view(&[2, 5]);
+
+        assert_eq!(
+            select(&tree_view, &mut state, 1),
+            [Message::Select(vec![5])]
+        );
+    }
+
+    #[test]
+    fn arrows_expand_and_collapse_nodes() {
+        let tree_view = tree_view(&[1]);
+        let mut state = State::default();
+
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowLeft),
+            [Message::Toggle(1, false)]
+        );
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowRight),
+            [Message::Select(vec![2])]
+        );
+
+        let tree_view = self::tree_view(&[4]);
+        let mut state = State::default();
+
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowRight),
+            [Message::Toggle(4, true)]
+        );
+    }
+
+    #[test]
+    fn arrows_move_to_the_parent_and_between_rows() {
+        let tree_view = tree_view(&[3]);
+        let mut state = State::default();
+
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowLeft),
+            [Message::Select(vec![1])]
+        );
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowUp),
+            [Message::Select(vec![1])]
+        );
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::End),
+            [Message::Select(vec![5])]
+        );
+        assert_eq!(
+            press(&tree_view, &mut state, key::Named::ArrowDown),
+            [Message::Select(vec![5])]
+        );
+    }
+
+    #[test]
+    fn diff_keeps_the_state_of_every_node() {
+        let tree_view = TreeView::new([node(1), node(2), node(3)]);
+        let mut tree = Tree::new(&tree_view as &dyn Widget<_, _, _>);
+
+        for (child, id) in tree.children.iter_mut().zip(1u32..) {
+            child.state = tree::State::new(id);
+        }
+
+        let tree_view =
+            TreeView::new([node(3), node(1).push(node(4)), node(2)]);
+
+        tree_view.diff(&mut tree);
+
+        let states: Vec<Option<u32>> = tree
+            .children
+            .iter()
+            .map(|child| match &child.state {
+                tree::State::Some(_) => Some(*child.state.downcast_ref()),
+                tree::State::None => None,
+            })
+            .collect();
+
+        assert_eq!(states, [Some(3), Some(1), None, Some(2)]);
+        assert_eq!(tree.state.downcast_ref::<State<u32>>().ids, [3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn focus_operations_reach_the_tree_view() {
+        let id = widget::Id::new("tree");
+        let tree_view = tree_view(&[]).id(id.clone());
+        let mut tree = Tree::new(&tree_view as &dyn Widget<_, _, _>);
+
+        let node = tree_view.layout(
+            &mut tree,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        tree_view.operate(
+            &mut tree,
+            Layout::new(&node),
+            &Null,
+            &mut operation::focusable::focus(id),
+        );
+
+        assert!(tree.state.downcast_ref::<State<u32>>().is_focused);
+    }
+}
@@ -2,9 +2,12 @@
 use std::ops::RangeInclusive;
 
 pub use crate::slider::{
-    default, Appearance, DefaultStyle, Handle, HandleShape, Status, Style,
+    default, Appearance, Catalog, DefaultStyle, Handle, HandleShape, Status,
+    Style,
 };
 
+use crate::core::theme::Class;
+
 use crate::core;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -13,7 +16,9 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Border, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
@@ -42,6 +47,7 @@ use crate::core::{
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct VerticalSlider<'a, T, Message, Theme = crate::Theme> {
+    id: Option<Id>,
     range: RangeInclusive<T>,
     step: T,
     shift_step: Option<T>,
@@ -88,6 +94,7 @@ where
         };
 
         VerticalSlider {
+            id: None,
             value,
             default: None,
             range,
@@ -101,6 +108,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`VerticalSlider`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the optional default value for the [`VerticalSlider`].
     ///
     /// If set, the [`VerticalSlider`] will reset to this value when ctrl-clicked or command-clicked.
@@ -141,6 +154,24 @@ where
         self
     }
 
+    /// Sets the style [`Class`] of the [`VerticalSlider`].
+    ///
+    /// The class is resolved by the current theme, falling back to the
+    /// default style if the theme does not know about it.
+    pub fn class(mut self, class: impl Into<Class>) -> Self
+    where
+        Theme: Catalog,
+    {
+        let class = class.into();
+
+        self.style = Box::new(move |theme: &Theme, status| {
+            theme
+                .resolve(&class, status)
+                .unwrap_or_else(|| theme.default_style(status))
+        });
+        self
+    }
+
     /// Sets the step size of the [`VerticalSlider`].
     pub fn step(mut self, step: T) -> Self {
         self.step = step;
@@ -171,6 +202,20 @@ where
         tree::State::new(State::default())
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.bounds(self.id.as_ref(), layout.bounds());
+
+        operation.focusable(state, self.id.as_ref());
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: Length::Shrink,
@@ -280,6 +325,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if let Some(cursor_position) =
                     cursor.position_over(layout.bounds())
                 {
@@ -316,20 +363,27 @@ where
                     return event::Status::Captured;
                 }
             }
-            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if cursor.position_over(layout.bounds()).is_some() {
-                    match key {
-                        Key::Named(key::Named::ArrowUp) => {
-                            let _ = increment(current_value).map(change);
-                        }
-                        Key::Named(key::Named::ArrowDown) => {
-                            let _ = decrement(current_value).map(change);
-                        }
-                        _ => (),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(key),
+                ..
+            }) if state.is_focused
+                || cursor.position_over(layout.bounds()).is_some() =>
+            {
+                let new_value = match key {
+                    key::Named::ArrowUp | key::Named::ArrowRight => {
+                        increment(current_value)
+                    }
+                    key::Named::ArrowDown | key::Named::ArrowLeft => {
+                        decrement(current_value)
                     }
+                    key::Named::Home => Some(*self.range.start()),
+                    key::Named::End => Some(*self.range.end()),
+                    _ => return event::Status::Ignored,
+                };
 
-                    return event::Status::Captured;
-                }
+                let _ = new_value.map(change);
+
+                return event::Status::Captured;
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = modifiers;
@@ -358,6 +412,8 @@ where
             theme,
             if state.is_dragging {
                 Status::Dragged
+            } else if state.is_focused {
+                Status::Focused
             } else if is_mouse_over {
                 Status::Hovered
             } else {
@@ -480,5 +536,20 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -5,6 +5,7 @@ pub use state::State;
 
 use crate::conversion;
 use crate::core;
+use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::time::Instant;
@@ -15,10 +16,12 @@ use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics::compositor::{self, Compositor};
 use crate::runtime::clipboard;
+use crate::runtime::command::Continuation;
+use crate::runtime::i18n;
 use crate::runtime::program::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Command, Debug};
-use crate::{Clipboard, Error, Proxy, Settings};
+use crate::{Clipboard, Error, Proxy, Settings, UserEvent};
 
 use futures::channel::mpsc;
 
@@ -36,6 +39,12 @@ use std::sync::Arc;
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
 /// can be toggled by pressing `F12`.
+///
+/// When using an [`Application`] with the `time-travel` feature enabled, its
+/// [`Timeline`] can be traveled while the debug view is shown by pressing `F9`
+/// and `F10`, and exported to the current directory by pressing `F8`.
+///
+/// [`Timeline`]: crate::runtime::recorder::Timeline
 pub trait Application: Program
 where
     Self::Theme: DefaultStyle,
@@ -92,6 +101,32 @@ where
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns the layout [`Direction`] of the [`Application`].
+    ///
+    /// Right-to-left layouts mirror rows, alignments, paddings and
+    /// scrollbars.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    ///
+    /// [`Direction`]: layout::Direction
+    /// [`Direction::LeftToRight`]: layout::Direction::LeftToRight
+    fn layout_direction(&self) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
+
+    /// Returns the [`Timeline`] of recorded messages of the [`Application`],
+    /// if it supports time-travel debugging.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Timeline`]: crate::runtime::recorder::Timeline
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        None
+    }
 }
 
 /// The appearance of an application.
@@ -305,11 +340,15 @@ async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
     mut renderer: A::Renderer,
-    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut runtime: Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
+    mut proxy: winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
     mut event_receiver: mpsc::UnboundedReceiver<
-        winit::event::Event<A::Message>,
+        winit::event::Event<UserEvent<A::Message>>,
     >,
     mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
@@ -338,6 +377,7 @@ async fn run_instance<A, E, C>(
         physical_size.height,
     );
     let mut should_exit = false;
+    let mut catalog = i18n::Catalog::default();
 
     if should_be_visible {
         window.set_visible(true);
@@ -350,15 +390,26 @@ async fn run_instance<A, E, C>(
         &mut cache,
         &state,
         &mut renderer,
-        init_command,
+        init_command.map(UserEvent::Message),
         &mut runtime,
         &mut clipboard,
         &mut should_exit,
         &mut proxy,
         &mut debug,
         &window,
+        &mut catalog,
+    );
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEvent::Message)
+            .into_recipes(),
     );
-    runtime.track(application.subscription().into_recipes());
+
+    #[cfg(feature = "time-travel")]
+    if let Some(timeline) = application.timeline() {
+        debug.log_timeline(timeline.position(), timeline.len());
+    }
 
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &application,
@@ -366,11 +417,13 @@ async fn run_instance<A, E, C>(
         &mut renderer,
         state.logical_size(),
         &mut debug,
+        &catalog,
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut continuations = Vec::new();
     let mut redraw_pending = false;
 
     debug.startup_finished();
@@ -395,9 +448,12 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Continue(continuation)) => {
+                continuations.push(continuation);
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::RedrawRequested { .. },
                 ..
@@ -525,6 +581,43 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
+                #[cfg(feature = "time-travel")]
+                if let Some(travel) = debug
+                    .is_enabled()
+                    .then(|| {
+                        crate::time_travel::Travel::from_event(&window_event)
+                    })
+                    .flatten()
+                {
+                    let cache =
+                        ManuallyDrop::into_inner(user_interface).into_cache();
+
+                    if let Some(timeline) = application.timeline() {
+                        travel.perform(timeline);
+                        debug.log_timeline(timeline.position(), timeline.len());
+                    }
+
+                    user_interface = ManuallyDrop::new(build_user_interface(
+                        &application,
+                        cache,
+                        &mut renderer,
+                        state.logical_size(),
+                        &mut debug,
+                        &catalog,
+                    ));
+
+                    state.synchronize(&application, &window);
+                    runtime.track(
+                        application
+                            .subscription()
+                            .map(UserEvent::Message)
+                            .into_recipes(),
+                    );
+
+                    window.request_redraw();
+                    continue;
+                }
+
                 if let Some(event) = conversion::window_event(
                     window::Id::MAIN,
                     window_event,
@@ -535,7 +628,10 @@ async fn run_instance<A, E, C>(
                 }
             }
             event::Event::AboutToWait => {
-                if events.is_empty() && messages.is_empty() {
+                if events.is_empty()
+                    && messages.is_empty()
+                    && continuations.is_empty()
+                {
                     continue;
                 }
 
@@ -558,6 +654,7 @@ async fn run_instance<A, E, C>(
                 }
 
                 if !messages.is_empty()
+                    || !continuations.is_empty()
                     || matches!(
                         interface_state,
                         user_interface::State::Outdated
@@ -580,7 +677,9 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut continuations,
                         &window,
+                        &mut catalog,
                     );
 
                     user_interface = ManuallyDrop::new(build_user_interface(
@@ -589,6 +688,7 @@ async fn run_instance<A, E, C>(
                         &mut renderer,
                         state.logical_size(),
                         &mut debug,
+                        &catalog,
                     ));
 
                     if should_exit {
@@ -641,23 +741,33 @@ pub fn build_user_interface<'a, A: Application>(
     renderer: &mut A::Renderer,
     size: Size,
     debug: &mut Debug,
+    catalog: &i18n::Catalog,
 ) -> UserInterface<'a, A::Message, A::Theme, A::Renderer>
 where
     A::Theme: DefaultStyle,
 {
+    let _catalog = i18n::enter(catalog);
+
     debug.view_started();
     let view = application.view();
     debug.view_finished();
 
     debug.layout_started();
-    let user_interface = UserInterface::build(view, size, cache, renderer);
+    let user_interface = UserInterface::build_with_direction(
+        view,
+        size,
+        application.layout_direction(),
+        cache,
+        renderer,
+    );
     debug.layout_finished();
 
     user_interface
 }
 
-/// Updates an [`Application`] by feeding it the provided messages, spawning any
-/// resulting [`Command`], and tracking its [`Subscription`].
+/// Updates an [`Application`] by feeding it the provided messages, resuming the
+/// provided continuations, spawning any resulting [`Command`], and tracking
+/// its [`Subscription`].
 pub fn update<A: Application, C, E: Executor>(
     application: &mut A,
     compositor: &mut C,
@@ -665,22 +775,50 @@ pub fn update<A: Application, C, E: Executor>(
     cache: &mut user_interface::Cache,
     state: &mut State<A>,
     renderer: &mut A::Renderer,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+    continuations: &mut Vec<Continuation<UserEvent<A::Message>>>,
     window: &winit::window::Window,
+    catalog: &mut i18n::Catalog,
 ) where
     C: Compositor<Renderer = A::Renderer> + 'static,
     A::Theme: DefaultStyle,
 {
+    for continuation in continuations.drain(..) {
+        let command = runtime.enter(|| continuation.resume());
+
+        run_command(
+            application,
+            compositor,
+            surface,
+            cache,
+            state,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            should_exit,
+            proxy,
+            debug,
+            window,
+            catalog,
+        );
+    }
+
     for message in messages.drain(..) {
         debug.log_message(&message);
 
         debug.update_started();
-        let command = runtime.enter(|| application.update(message));
+        let command = runtime
+            .enter(|| i18n::scope(catalog, || application.update(message)));
         debug.update_finished();
 
         run_command(
@@ -690,23 +828,32 @@ pub fn update<A: Application, C, E: Executor>(
             cache,
             state,
             renderer,
-            command,
+            command.map(UserEvent::Message),
             runtime,
             clipboard,
             should_exit,
             proxy,
             debug,
             window,
+            catalog,
         );
     }
 
-    state.synchronize(application, window);
+    #[cfg(feature = "time-travel")]
+    if let Some(timeline) = application.timeline() {
+        debug.log_timeline(timeline.position(), timeline.len());
+    }
+
+    i18n::scope(catalog, || state.synchronize(application, window));
 
     let subscription = application.subscription();
-    runtime.track(subscription.into_recipes());
+    runtime.track(subscription.map(UserEvent::Message).into_recipes());
 }
 
 /// Runs the actions of a [`Command`].
+///
+/// The first [`Command`] of any chain is run right away, while the rest of
+/// the chain is resumed through the event loop as its outputs arrive.
 pub fn run_command<A, C, E>(
     application: &A,
     compositor: &mut C,
@@ -714,13 +861,18 @@ pub fn run_command<A, C, E>(
     cache: &mut user_interface::Cache,
     state: &State<A>,
     renderer: &mut A::Renderer,
-    command: Command<A::Message>,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    command: Command<UserEvent<A::Message>>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     window: &winit::window::Window,
+    catalog: &mut i18n::Catalog,
 ) where
     A: Application,
     E: Executor,
@@ -728,11 +880,30 @@ pub fn run_command<A, C, E>(
     A::Theme: DefaultStyle,
 {
     use crate::runtime::command;
+    use crate::runtime::notification;
     use crate::runtime::system;
     use crate::runtime::window;
 
     for action in command.actions() {
         match action {
+            command::Action::Chain(chain) => {
+                run_command(
+                    application,
+                    compositor,
+                    surface,
+                    cache,
+                    state,
+                    renderer,
+                    chain.into_command().map(UserEvent::Continue),
+                    runtime,
+                    clipboard,
+                    should_exit,
+                    proxy,
+                    debug,
+                    window,
+                    catalog,
+                );
+            }
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
@@ -917,6 +1088,52 @@ pub fn run_command<A, C, E>(
                     }
                 }
             },
+            command::Action::Dialog(action) => {
+                #[cfg(feature = "dialog")]
+                runtime.spawn(crate::dialog::run(action, Some(window)));
+
+                #[cfg(not(feature = "dialog"))]
+                {
+                    let _ = action;
+
+                    log::warn!(
+                        "Native dialogs are only available with the \
+                        `dialog` feature."
+                    );
+                }
+            }
+            command::Action::Notification(action) => match action {
+                notification::Action::Show(_notification, tag) => {
+                    let proxy = proxy.clone();
+
+                    #[cfg(feature = "notification")]
+                    let _ = std::thread::spawn(move || {
+                        let result = crate::notification::show(&_notification);
+
+                        proxy
+                            .send_event(tag(result))
+                            .expect("Send message to event loop");
+                    });
+
+                    #[cfg(not(feature = "notification"))]
+                    proxy
+                        .send_event(tag(Err(notification::Error::Unsupported)))
+                        .expect("Send message to event loop");
+                }
+            },
+            command::Action::I18n(action) => match action {
+                i18n::Action::ChangeLocale(locale) => {
+                    catalog.set_locale(locale);
+                }
+                i18n::Action::AddBundle(bundle) => {
+                    catalog.add_bundle(bundle);
+                }
+                i18n::Action::FetchLocale(tag) => {
+                    proxy
+                        .send_event(tag(catalog.locale().clone()))
+                        .expect("Send message to event loop");
+                }
+            },
             command::Action::Widget(action) => {
                 let mut current_cache = std::mem::take(cache);
                 let mut current_operation = Some(action);
@@ -927,6 +1144,7 @@ pub fn run_command<A, C, E>(
                     renderer,
                     state.logical_size(),
                     debug,
+                    catalog,
                 );
 
                 while let Some(mut operation) = current_operation.take() {
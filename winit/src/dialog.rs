@@ -0,0 +1,76 @@
+//! Show native file dialogs.
+use crate::futures::futures::FutureExt;
+use crate::futures::{BoxFuture, MaybeSend};
+use crate::runtime::dialog::{Action, FileDialog};
+
+use std::path::PathBuf;
+
+/// Turns a dialog [`Action`] into a future that shows the dialog and produces
+/// the resulting output.
+///
+/// On Linux and BSDs, the dialog is requested through the XDG desktop portal
+/// over D-Bus, instead of linking GTK; so any portal implementation on the
+/// session bus, including a stub one in tests, can answer it.
+pub(crate) fn run<T>(
+    action: Action<T>,
+    parent: Option<&winit::window::Window>,
+) -> BoxFuture<T>
+where
+    T: MaybeSend + 'static,
+{
+    match action {
+        Action::OpenFile(_id, dialog, tag) => Box::pin(
+            builder(dialog, parent)
+                .pick_file()
+                .map(move |file| tag(file.map(path))),
+        ),
+        Action::OpenFiles(_id, dialog, tag) => {
+            Box::pin(builder(dialog, parent).pick_files().map(move |files| {
+                tag(files.map(|files| files.into_iter().map(path).collect()))
+            }))
+        }
+        Action::SaveFile(_id, dialog, tag) => Box::pin(
+            builder(dialog, parent)
+                .save_file()
+                .map(move |file| tag(file.map(path))),
+        ),
+        Action::PickFolder(_id, dialog, tag) => Box::pin(
+            builder(dialog, parent)
+                .pick_folder()
+                .map(move |folder| tag(folder.map(path))),
+        ),
+    }
+}
+
+fn builder(
+    dialog: FileDialog,
+    parent: Option<&winit::window::Window>,
+) -> rfd::AsyncFileDialog {
+    let mut builder = rfd::AsyncFileDialog::new();
+
+    if let Some(parent) = parent {
+        builder = builder.set_parent(parent);
+    }
+
+    if let Some(title) = dialog.title {
+        builder = builder.set_title(title);
+    }
+
+    if let Some(directory) = dialog.directory {
+        builder = builder.set_directory(directory);
+    }
+
+    if let Some(file_name) = dialog.file_name {
+        builder = builder.set_file_name(file_name);
+    }
+
+    for filter in dialog.filters {
+        builder = builder.add_filter(filter.name, &filter.extensions);
+    }
+
+    builder
+}
+
+fn path(handle: rfd::FileHandle) -> PathBuf {
+    handle.path().to_path_buf()
+}
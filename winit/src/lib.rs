@@ -44,12 +44,18 @@ pub mod settings;
 #[cfg(feature = "system")]
 pub mod system;
 
+#[cfg(feature = "dialog")]
+mod dialog;
 mod error;
+#[cfg(feature = "notification")]
+mod notification;
 mod proxy;
+#[cfg(all(feature = "application", feature = "time-travel"))]
+mod time_travel;
 
 #[cfg(feature = "application")]
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use proxy::Proxy;
+pub use proxy::{Proxy, UserEvent};
 pub use settings::Settings;
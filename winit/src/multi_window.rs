@@ -6,6 +6,7 @@ pub use state::State;
 
 use crate::conversion;
 use crate::core;
+use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::operation;
@@ -18,11 +19,12 @@ use crate::futures::futures::{Future, StreamExt};
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics::{compositor, Compositor};
 use crate::multi_window::window_manager::WindowManager;
-use crate::runtime::command::{self, Command};
+use crate::runtime::command::{self, Command, Continuation};
+use crate::runtime::i18n;
 use crate::runtime::multi_window::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
-use crate::{Clipboard, Error, Proxy, Settings};
+use crate::{Clipboard, Error, Proxy, Settings, UserEvent};
 
 pub use crate::application::{default, Appearance, DefaultStyle};
 
@@ -42,6 +44,13 @@ use std::time::Instant;
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
 /// can be toggled by pressing `F12`.
+///
+/// When using an [`Application`] with the `time-travel` feature enabled, its
+/// [`Timeline`] can be traveled while the debug view is shown by pressing `F9`
+/// and `F10` in any of its windows, and exported to the current directory by
+/// pressing `F8`.
+///
+/// [`Timeline`]: crate::runtime::recorder::Timeline
 pub trait Application: Program
 where
     Self::Theme: DefaultStyle,
@@ -99,6 +108,30 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Returns the layout [`Direction`] of the `window` of the [`Application`].
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    ///
+    /// [`Direction`]: layout::Direction
+    /// [`Direction::LeftToRight`]: layout::Direction::LeftToRight
+    #[allow(unused_variables)]
+    fn layout_direction(&self, window: window::Id) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
+
+    /// Returns the [`Timeline`] of recorded messages of the [`Application`],
+    /// if it supports time-travel debugging.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Timeline`]: crate::runtime::recorder::Timeline
+    #[cfg(feature = "time-travel")]
+    fn timeline(
+        &mut self,
+    ) -> Option<&mut dyn crate::runtime::recorder::Timeline> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -341,10 +374,14 @@ enum Control {
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
-    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut runtime: Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
+    mut proxy: winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
-    mut event_receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut event_receiver: mpsc::UnboundedReceiver<Event<UserEvent<A::Message>>>,
     mut control_sender: mpsc::UnboundedSender<Control>,
     init_command: Command<A::Message>,
     mut window_manager: WindowManager<A, C>,
@@ -380,6 +417,7 @@ async fn run_instance<A, E, C>(
         )]
     };
 
+    let mut catalog = i18n::Catalog::default();
     let mut ui_caches = HashMap::new();
     let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
         &application,
@@ -389,12 +427,13 @@ async fn run_instance<A, E, C>(
             window::Id::MAIN,
             user_interface::Cache::default(),
         )]),
+        &catalog,
     ));
 
     run_command(
         &application,
         &mut compositor,
-        init_command,
+        init_command.map(UserEvent::Message),
         &mut runtime,
         &mut clipboard,
         &mut control_sender,
@@ -402,11 +441,23 @@ async fn run_instance<A, E, C>(
         &mut debug,
         &mut window_manager,
         &mut ui_caches,
+        &mut catalog,
     );
 
-    runtime.track(application.subscription().into_recipes());
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEvent::Message)
+            .into_recipes(),
+    );
+
+    #[cfg(feature = "time-travel")]
+    if let Some(timeline) = application.timeline() {
+        debug.log_timeline(timeline.position(), timeline.len());
+    }
 
     let mut messages = Vec::new();
+    let mut continuations = Vec::new();
 
     debug.startup_finished();
 
@@ -436,6 +487,7 @@ async fn run_instance<A, E, C>(
                         logical_size,
                         &mut debug,
                         id,
+                        &catalog,
                     ),
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
@@ -479,9 +531,14 @@ async fn run_instance<A, E, C>(
                             ),
                         ));
                     }
-                    event::Event::UserEvent(message) => {
+                    event::Event::UserEvent(UserEvent::Message(message)) => {
                         messages.push(message);
                     }
+                    event::Event::UserEvent(UserEvent::Continue(
+                        continuation,
+                    )) => {
+                        continuations.push(continuation);
+                    }
                     event::Event::WindowEvent {
                         window_id: id,
                         event: event::WindowEvent::RedrawRequested,
@@ -691,6 +748,63 @@ async fn run_instance<A, E, C>(
                                 &mut debug,
                             );
 
+                            #[cfg(feature = "time-travel")]
+                            if let Some(travel) = debug
+                                .is_enabled()
+                                .then(|| {
+                                    crate::time_travel::Travel::from_event(
+                                        &window_event,
+                                    )
+                                })
+                                .flatten()
+                            {
+                                let cached_interfaces: HashMap<
+                                    window::Id,
+                                    user_interface::Cache,
+                                > = ManuallyDrop::into_inner(user_interfaces)
+                                    .drain()
+                                    .map(|(id, ui)| (id, ui.into_cache()))
+                                    .collect();
+
+                                if let Some(timeline) = application.timeline() {
+                                    travel.perform(timeline);
+                                    debug.log_timeline(
+                                        timeline.position(),
+                                        timeline.len(),
+                                    );
+                                }
+
+                                for (id, window) in window_manager.iter_mut() {
+                                    i18n::scope(&catalog, || {
+                                        window.state.synchronize(
+                                            &application,
+                                            id,
+                                            &window.raw,
+                                        );
+                                    });
+
+                                    window.raw.request_redraw();
+                                }
+
+                                user_interfaces =
+                                    ManuallyDrop::new(build_user_interfaces(
+                                        &application,
+                                        &mut debug,
+                                        &mut window_manager,
+                                        cached_interfaces,
+                                        &catalog,
+                                    ));
+
+                                runtime.track(
+                                    application
+                                        .subscription()
+                                        .map(UserEvent::Message)
+                                        .into_recipes(),
+                                );
+
+                                continue;
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 id,
                                 window_event,
@@ -702,7 +816,10 @@ async fn run_instance<A, E, C>(
                         }
                     }
                     event::Event::AboutToWait => {
-                        if events.is_empty() && messages.is_empty() {
+                        if events.is_empty()
+                            && messages.is_empty()
+                            && continuations.is_empty()
+                        {
                             continue;
                         }
 
@@ -757,7 +874,10 @@ async fn run_instance<A, E, C>(
                         debug.event_processing_finished();
 
                         // TODO mw application update returns which window IDs to update
-                        if !messages.is_empty() || uis_stale {
+                        if !messages.is_empty()
+                            || !continuations.is_empty()
+                            || uis_stale
+                        {
                             let mut cached_interfaces: HashMap<
                                 window::Id,
                                 user_interface::Cache,
@@ -776,18 +896,22 @@ async fn run_instance<A, E, C>(
                                 &mut proxy,
                                 &mut debug,
                                 &mut messages,
+                                &mut continuations,
                                 &mut window_manager,
                                 &mut cached_interfaces,
+                                &mut catalog,
                             );
 
                             // we must synchronize all window states with application state after an
                             // application update since we don't know what changed
                             for (id, window) in window_manager.iter_mut() {
-                                window.state.synchronize(
-                                    &application,
-                                    id,
-                                    &window.raw,
-                                );
+                                i18n::scope(&catalog, || {
+                                    window.state.synchronize(
+                                        &application,
+                                        id,
+                                        &window.raw,
+                                    );
+                                });
 
                                 // TODO once widgets can request to be redrawn, we can avoid always requesting a
                                 // redraw
@@ -801,6 +925,7 @@ async fn run_instance<A, E, C>(
                                     &mut debug,
                                     &mut window_manager,
                                     cached_interfaces,
+                                    &catalog,
                                 ));
                         }
                     }
@@ -821,49 +946,84 @@ fn build_user_interface<'a, A: Application>(
     size: Size,
     debug: &mut Debug,
     id: window::Id,
+    catalog: &i18n::Catalog,
 ) -> UserInterface<'a, A::Message, A::Theme, A::Renderer>
 where
     A::Theme: DefaultStyle,
 {
+    let _catalog = i18n::enter(catalog);
+
     debug.view_started();
     let view = application.view(id);
     debug.view_finished();
 
     debug.layout_started();
-    let user_interface = UserInterface::build(view, size, cache, renderer);
+    let user_interface = UserInterface::build_with_direction(
+        view,
+        size,
+        application.layout_direction(id),
+        cache,
+        renderer,
+    );
     debug.layout_finished();
 
     user_interface
 }
 
-/// Updates a multi-window [`Application`] by feeding it messages, spawning any
-/// resulting [`Command`], and tracking its [`Subscription`].
+/// Updates a multi-window [`Application`] by feeding it messages, resuming
+/// continuations, spawning any resulting [`Command`], and tracking its
+/// [`Subscription`].
 fn update<A: Application, C, E: Executor>(
     application: &mut A,
     compositor: &mut C,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     control_sender: &mut mpsc::UnboundedSender<Control>,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+    continuations: &mut Vec<Continuation<UserEvent<A::Message>>>,
     window_manager: &mut WindowManager<A, C>,
     ui_caches: &mut HashMap<window::Id, user_interface::Cache>,
+    catalog: &mut i18n::Catalog,
 ) where
     C: Compositor<Renderer = A::Renderer> + 'static,
     A::Theme: DefaultStyle,
 {
+    for continuation in continuations.drain(..) {
+        let command = runtime.enter(|| continuation.resume());
+
+        run_command(
+            application,
+            compositor,
+            command,
+            runtime,
+            clipboard,
+            control_sender,
+            proxy,
+            debug,
+            window_manager,
+            ui_caches,
+            catalog,
+        );
+    }
+
     for message in messages.drain(..) {
         debug.log_message(&message);
         debug.update_started();
 
-        let command = runtime.enter(|| application.update(message));
+        let command = runtime
+            .enter(|| i18n::scope(catalog, || application.update(message)));
         debug.update_finished();
 
         run_command(
             application,
             compositor,
-            command,
+            command.map(UserEvent::Message),
             runtime,
             clipboard,
             control_sender,
@@ -871,25 +1031,36 @@ fn update<A: Application, C, E: Executor>(
             debug,
             window_manager,
             ui_caches,
+            catalog,
         );
     }
 
+    #[cfg(feature = "time-travel")]
+    if let Some(timeline) = application.timeline() {
+        debug.log_timeline(timeline.position(), timeline.len());
+    }
+
     let subscription = application.subscription();
-    runtime.track(subscription.into_recipes());
+    runtime.track(subscription.map(UserEvent::Message).into_recipes());
 }
 
 /// Runs the actions of a [`Command`].
 fn run_command<A, C, E>(
     application: &A,
     compositor: &mut C,
-    command: Command<A::Message>,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    command: Command<UserEvent<A::Message>>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     control_sender: &mut mpsc::UnboundedSender<Control>,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     window_manager: &mut WindowManager<A, C>,
     ui_caches: &mut HashMap<window::Id, user_interface::Cache>,
+    catalog: &mut i18n::Catalog,
 ) where
     A: Application,
     E: Executor,
@@ -897,11 +1068,27 @@ fn run_command<A, C, E>(
     A::Theme: DefaultStyle,
 {
     use crate::runtime::clipboard;
+    use crate::runtime::notification;
     use crate::runtime::system;
     use crate::runtime::window;
 
     for action in command.actions() {
         match action {
+            command::Action::Chain(chain) => {
+                run_command(
+                    application,
+                    compositor,
+                    chain.into_command().map(UserEvent::Continue),
+                    runtime,
+                    clipboard,
+                    control_sender,
+                    proxy,
+                    debug,
+                    window_manager,
+                    ui_caches,
+                    catalog,
+                );
+            }
             command::Action::Future(future) => {
                 runtime.spawn(Box::pin(future));
             }
@@ -1154,6 +1341,59 @@ fn run_command<A, C, E>(
                     }
                 }
             },
+            command::Action::Dialog(action) => {
+                #[cfg(feature = "dialog")]
+                {
+                    let parent = window_manager
+                        .get_mut(action.window())
+                        .map(|window| window.raw.clone());
+
+                    runtime
+                        .spawn(crate::dialog::run(action, parent.as_deref()));
+                }
+
+                #[cfg(not(feature = "dialog"))]
+                {
+                    let _ = action;
+
+                    log::warn!(
+                        "Native dialogs are only available with the \
+                        `dialog` feature."
+                    );
+                }
+            }
+            command::Action::Notification(action) => match action {
+                notification::Action::Show(_notification, tag) => {
+                    let proxy = proxy.clone();
+
+                    #[cfg(feature = "notification")]
+                    let _ = std::thread::spawn(move || {
+                        let result = crate::notification::show(&_notification);
+
+                        proxy
+                            .send_event(tag(result))
+                            .expect("Event loop doesn't exist.");
+                    });
+
+                    #[cfg(not(feature = "notification"))]
+                    proxy
+                        .send_event(tag(Err(notification::Error::Unsupported)))
+                        .expect("Event loop doesn't exist.");
+                }
+            },
+            command::Action::I18n(action) => match action {
+                i18n::Action::ChangeLocale(locale) => {
+                    catalog.set_locale(locale);
+                }
+                i18n::Action::AddBundle(bundle) => {
+                    catalog.add_bundle(bundle);
+                }
+                i18n::Action::FetchLocale(tag) => {
+                    proxy
+                        .send_event(tag(catalog.locale().clone()))
+                        .expect("Send message to event loop");
+                }
+            },
             command::Action::Widget(action) => {
                 let mut current_operation = Some(action);
 
@@ -1162,6 +1402,7 @@ fn run_command<A, C, E>(
                     debug,
                     window_manager,
                     std::mem::take(ui_caches),
+                    catalog,
                 );
 
                 'operate: while let Some(mut operation) =
@@ -1218,6 +1459,7 @@ pub fn build_user_interfaces<'a, A: Application, C: Compositor>(
     debug: &mut Debug,
     window_manager: &mut WindowManager<A, C>,
     mut cached_user_interfaces: HashMap<window::Id, user_interface::Cache>,
+    catalog: &i18n::Catalog,
 ) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Theme, A::Renderer>>
 where
     C: Compositor<Renderer = A::Renderer>,
@@ -1237,6 +1479,7 @@ where
                     window.state.logical_size(),
                     debug,
                     id,
+                    catalog,
                 ),
             ))
         })
@@ -0,0 +1,43 @@
+//! Show desktop notifications.
+use crate::runtime::notification::{Error, Notification};
+
+/// Shows the given [`Notification`], blocking until the notification service
+/// has accepted it.
+///
+/// On Linux and BSDs, the notification is sent to the
+/// `org.freedesktop.Notifications` service over D-Bus.
+pub(crate) fn show(notification: &Notification) -> Result<(), Error> {
+    let mut native = notify_rust::Notification::new();
+
+    let _ = native.summary(&notification.summary);
+
+    if let Some(body) = &notification.body {
+        let _ = native.body(body);
+    }
+
+    if let Some(icon) = &notification.icon {
+        let _ = native.icon(icon);
+    }
+
+    if let Some(timeout) = notification.timeout {
+        let _ = native.timeout(notify_rust::Timeout::Milliseconds(
+            u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX),
+        ));
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        use crate::runtime::notification::Urgency;
+
+        let _ = native.urgency(match notification.urgency {
+            Urgency::Low => notify_rust::Urgency::Low,
+            Urgency::Normal => notify_rust::Urgency::Normal,
+            Urgency::Critical => notify_rust::Urgency::Critical,
+        });
+    }
+
+    native
+        .show()
+        .map(|_| ())
+        .map_err(|error| Error::Failed(error.to_string()))
+}
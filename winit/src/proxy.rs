@@ -3,8 +3,20 @@ use crate::futures::futures::{
     task::{Context, Poll},
     Sink,
 };
+use crate::runtime::command::Continuation;
+
 use std::pin::Pin;
 
+/// An event sent to the event loop of an application.
+#[derive(Debug)]
+pub enum UserEvent<Message: 'static> {
+    /// A message produced by some command or subscription.
+    Message(Message),
+
+    /// A [`Continuation`] of a command chain that must be resumed.
+    Continue(Continuation<UserEvent<Message>>),
+}
+
 /// An event loop proxy that implements `Sink`.
 #[derive(Debug)]
 pub struct Proxy<Message: 'static> {
@@ -0,0 +1,69 @@
+//! Travel through the recorded messages of an application.
+use crate::runtime::recorder::Timeline;
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A request to travel through the [`Timeline`] of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Travel {
+    Back,
+    Forward,
+    Export,
+}
+
+impl Travel {
+    /// Returns the [`Travel`] requested by the given window event, if any.
+    pub(crate) fn from_event(
+        event: &winit::event::WindowEvent,
+    ) -> Option<Self> {
+        use winit::event::{ElementState, KeyEvent, WindowEvent};
+        use winit::keyboard::{Key, NamedKey};
+
+        match event {
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        logical_key: Key::Named(key),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => match key {
+                NamedKey::F9 => Some(Self::Back),
+                NamedKey::F10 => Some(Self::Forward),
+                NamedKey::F8 => Some(Self::Export),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Performs the [`Travel`] on the given [`Timeline`].
+    ///
+    /// Recordings are exported to the current working directory.
+    pub(crate) fn perform(self, timeline: &mut dyn Timeline) {
+        match self {
+            Self::Back => timeline.step_back(),
+            Self::Forward => timeline.step_forward(),
+            Self::Export => {
+                let timestamp = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs())
+                    .unwrap_or_default();
+
+                let path =
+                    PathBuf::from(format!("iced-recording-{timestamp}.json"));
+
+                match timeline.export(&path) {
+                    Ok(()) => {
+                        log::info!("Recording exported to {}", path.display());
+                    }
+                    Err(error) => {
+                        log::error!("Failed to export recording: {error}");
+                    }
+                }
+            }
+        }
+    }
+}